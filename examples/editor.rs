@@ -52,7 +52,7 @@ impl Editor {
             }
             match path::Path::new(&filename).exists() {
                 true => {
-                    fs::write(&filename, self.editor.buffer().text()).unwrap();
+                    fs::write(&filename, self.editor.buffer().unwrap().text()).unwrap();
                     *saved = true;
                 }
                 false => alert("Please specify a file!"),
@@ -60,7 +60,7 @@ impl Editor {
         } else {
             match path::Path::new(&filename).exists() {
                 true => {
-                    fs::write(&filename, self.editor.buffer().text()).unwrap();
+                    fs::write(&filename, self.editor.buffer().unwrap().text()).unwrap();
                     *saved = true;
                 }
                 false => alert("Please specify a file!"),
@@ -118,10 +118,10 @@ fn main() {
         Shortcut::Ctrl + 'n',
         MenuFlag::Normal,
         Box::new(move || {
-            if editor_c.buffer().text() != "" {
+            if editor_c.buffer().unwrap().text() != "" {
                 let x = choice("File unsaved, Do you wish to continue?", "Yes", "No!", "");
                 if x == 0 {
-                    editor_c.buffer().set_text("");
+                    editor_c.buffer().unwrap().set_text("");
                 }
             }
         }),
@@ -143,7 +143,7 @@ fn main() {
                 return;
             }
             match path::Path::new(&editor_c.filename()).exists() {
-                true => editor_c.buffer().set_text(
+                true => editor_c.buffer().unwrap().set_text(
                     fs::read_to_string(&editor_c.filename())
                         .unwrap()
                         .as_str(),
@@ -0,0 +1,32 @@
+use fltk::{app, frame::*, menu, menu::*, window::*};
+
+fn main() {
+    let app = app::App::default().set_scheme(app::AppScheme::Gtk);
+    let mut wind = Window::new(100, 100, 400, 300, "Menus");
+    let mut frame = Frame::new(0, 25, 400, 275, "");
+
+    let mut frm = frame.clone();
+    let _bar = menu! {
+        MenuBar::new(0, 0, 400, 25, "") {
+            "File" {
+                "New", Shortcut::Ctrl + 'n' => move || frm.set_label("New");
+                "Open...", Shortcut::Ctrl + 'o', MenuFlag::MenuDivider => || println!("Open");
+                "Recent" {
+                    "first.txt" => || println!("first.txt");
+                    "second.txt" => || println!("second.txt");
+                }
+                "Quit", Shortcut::Ctrl + 'q' => || std::process::exit(0);
+            }
+            "View" {
+                "Word Wrap", Shortcut::None, MenuFlag::Toggle => || println!("Word wrap");
+            }
+        }
+    };
+    frame.set_label("Pick a menu item");
+
+    wind.make_resizable(true);
+    wind.end();
+    wind.show();
+
+    app.run().unwrap();
+}
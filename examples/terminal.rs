@@ -35,10 +35,10 @@ impl Term {
     }
 
     fn append(&mut self, txt: &str) {
-        self.term.buffer().append(txt);
-        self.term.set_insert_position(self.term.buffer().length());
+        self.term.buffer().unwrap().append(txt);
+        self.term.set_insert_position(self.term.buffer().unwrap().length());
         self.term.scroll(
-            self.term.count_lines(0, self.term.buffer().length(), true),
+            self.term.count_lines(0, self.term.buffer().unwrap().length(), true),
             0,
         );
     }
@@ -120,10 +120,11 @@ fn main() {
                 }
                 app::Key::BackSpace => {
                     if term.cmd.len() != 0 {
-                        let text_len = term.term.buffer().text().len() as u32;
+                        let text_len = term.term.buffer().unwrap().text().len() as u32;
                         term
                             .term
                             .buffer()
+                            .unwrap()
                             .remove(text_len - 1, text_len as u32);
                         term.cmd.pop().unwrap();
                         return true;
@@ -0,0 +1,37 @@
+use fltk::{app, button::*, frame::*, ui, window::*};
+
+fn main() {
+    let app = app::App::default().set_scheme(app::AppScheme::Gtk);
+
+    ui! {
+        wind = Window::default().with_size(160, 200).center_screen().with_label("Counter") {
+            frame = Frame(30, 80, 100, 40, "0") {
+                label_size: 20;
+            }
+            but_inc = Button(30, 30, 100, 40, "+") {
+                color: Color::from_u32(0x304FFE);
+                frame: FrameType::RoundUpBox;
+            }
+            but_dec = Button(30, 130, 100, 40, "-") {
+                color: Color::from_u32(0x2962FF);
+                frame: FrameType::RoundUpBox;
+            }
+            .make_resizable(true);
+        }
+    }
+    wind.show();
+
+    let mut frame1 = frame.clone();
+    but_inc.set_callback(Box::new(move || {
+        let label = (frame1.label().parse::<i32>().unwrap() + 1).to_string();
+        frame1.set_label(&label);
+    }));
+
+    let mut frame1 = frame.clone();
+    but_dec.set_callback(Box::new(move || {
+        let label = (frame1.label().parse::<i32>().unwrap() - 1).to_string();
+        frame1.set_label(&label);
+    }));
+
+    app.run().unwrap();
+}
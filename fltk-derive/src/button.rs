@@ -22,13 +22,13 @@ pub fn impl_button_trait(ast: &DeriveInput) -> TokenStream {
         unsafe impl ButtonExt for #name {
             fn shortcut(&self) -> Shortcut {
                 unsafe {
-                    mem::transmute(#shortcut(self._inner))
+                    Shortcut::from_i32(#shortcut(self._inner))
                 }
             }
 
             fn set_shortcut(&mut self, shortcut: Shortcut) {
                 unsafe {
-                    #set_shortcut(self._inner, shortcut as i32)
+                    #set_shortcut(self._inner, shortcut.bits())
                 }
             }
 
@@ -83,6 +83,14 @@ pub fn impl_display_trait(ast: &DeriveInput) -> TokenStream {
         format!("{}_{}", name_str, "set_style_table_entry").as_str(),
         name.span(),
     );
+    let set_style_table_entry_ext = Ident::new(
+        format!("{}_{}", name_str, "set_style_table_entry_ext").as_str(),
+        name.span(),
+    );
+    let clear_style_table = Ident::new(
+        format!("{}_{}", name_str, "clear_style_table").as_str(),
+        name.span(),
+    );
     let set_cursor_style = Ident::new(
         format!("{}_{}", name_str, "set_cursor_style").as_str(),
         name.span(),
@@ -203,15 +211,41 @@ pub fn impl_display_trait(ast: &DeriveInput) -> TokenStream {
         format!("{}_{}", name_str, "in_selection").as_str(),
         name.span(),
     );
+    let linenumber_position = Ident::new(
+        format!("{}_{}", name_str, "linenumber_position").as_str(),
+        name.span(),
+    );
+    let show_insert_position = Ident::new(
+        format!("{}_{}", name_str, "show_insert_position").as_str(),
+        name.span(),
+    );
+    let set_secondary_selection_color = Ident::new(
+        format!("{}_{}", name_str, "set_secondary_selection_color").as_str(),
+        name.span(),
+    );
+    let secondary_selection_color = Ident::new(
+        format!("{}_{}", name_str, "secondary_selection_color").as_str(),
+        name.span(),
+    );
+    let set_highlight_color = Ident::new(
+        format!("{}_{}", name_str, "set_highlight_color").as_str(),
+        name.span(),
+    );
+    let highlight_color = Ident::new(
+        format!("{}_{}", name_str, "highlight_color").as_str(),
+        name.span(),
+    );
 
     let gen = quote! {
         unsafe impl DisplayExt for #name {
-            fn buffer<'a>(&'a self) -> &'a mut TextBuffer {
+            fn buffer(&self) -> Option<TextBuffer> {
                 unsafe {
                     let buffer = #get_buffer(self._inner);
-                    assert!(!buffer.is_null());
-                    let x = Box::from(TextBuffer::from_ptr(buffer));
-                    &mut *Box::into_raw(x)
+                    if buffer.is_null() {
+                        None
+                    } else {
+                        Some(TextBuffer::from_ptr(buffer))
+                    }
                 }
             }
 
@@ -356,6 +390,33 @@ pub fn impl_display_trait(ast: &DeriveInput) -> TokenStream {
                 }
             }
 
+            fn set_style_table_entry_ext(&mut self, style_buffer: &mut TextBuffer, entries: &Vec<StyleTableEntryExt>) {
+                if entries.is_empty() {
+                    return;
+                }
+                let mut colors: Vec<u32> = vec![];
+                let mut fonts: Vec<i32> = vec![];
+                let mut sizes: Vec<i32> = vec![];
+                let mut attrs: Vec<u32> = vec![];
+                let mut bgcolors: Vec<u32> = vec![];
+                for entry in entries.iter() {
+                    colors.push(entry.color as u32);
+                    fonts.push(entry.font as i32);
+                    sizes.push(entry.size as i32);
+                    attrs.push(entry.attr as u32);
+                    bgcolors.push(entry.bgcolor as u32);
+                }
+                unsafe {
+                    #set_style_table_entry_ext(self._inner, style_buffer.as_ptr() as *mut raw::c_void, &mut colors[0], &mut fonts[0], &mut sizes[0], &mut attrs[0], &mut bgcolors[0], entries.len() as i32);
+                }
+            }
+
+            fn clear_style_table(&mut self) {
+                unsafe {
+                    #clear_style_table(self._inner)
+                }
+            }
+
             fn set_cursor_style(&mut self, style: CursorStyle) {
                 unsafe {
                     #set_cursor_style(self._inner, style as i32)
@@ -383,7 +444,7 @@ pub fn impl_display_trait(ast: &DeriveInput) -> TokenStream {
 
             fn set_scrollbar_align(&mut self, align: Align){
                 unsafe {
-                    #set_scrollbar_align(self._inner, align as i32)
+                    #set_scrollbar_align(self._inner, align.bits())
                 }
             }
 
@@ -413,7 +474,7 @@ pub fn impl_display_trait(ast: &DeriveInput) -> TokenStream {
 
             fn scrollbar_align(&self) -> Align {
                 unsafe {
-                    mem::transmute(#scrollbar_align(self._inner))
+                    Align::from_i32(#scrollbar_align(self._inner))
                 }
             }
 
@@ -548,13 +609,13 @@ pub fn impl_display_trait(ast: &DeriveInput) -> TokenStream {
 
             fn set_linenumber_align(&mut self, align: Align) {
                 unsafe {
-                    #set_linenumber_align(self._inner, align as i32)
+                    #set_linenumber_align(self._inner, align.bits())
                 }
             }
 
             fn linenumber_align(&self) -> Align {
                 unsafe {
-                    mem::transmute(#linenumber_align(self._inner))
+                    Align::from_i32(#linenumber_align(self._inner))
                 }
             }
 
@@ -566,6 +627,96 @@ pub fn impl_display_trait(ast: &DeriveInput) -> TokenStream {
                     }
                 }
             }
+
+            fn set_linenumber_callback(&mut self, cb: Box<dyn FnMut(u32)>) {
+                let mut cb = cb;
+                let wid = self.clone();
+                crate::widget::intercept_events(
+                    unsafe { self.as_widget_ptr() },
+                    "linenumber_callback",
+                    Box::new(move |ev| {
+                        if ev != Event::Push {
+                            return false;
+                        }
+                        let (x, y) = crate::app::event_coords();
+                        let pos = unsafe { #linenumber_position(wid._inner, x, y) };
+                        if pos < 0 {
+                            return false;
+                        }
+                        cb(wid.position_to_line_column(pos as u32).0);
+                        true
+                    }),
+                );
+            }
+
+            fn show_insert_position(&mut self) {
+                unsafe {
+                    #show_insert_position(self._inner)
+                }
+            }
+
+            fn set_secondary_selection_color(&mut self, color: Color) {
+                unsafe {
+                    #set_secondary_selection_color(self._inner, color as u32)
+                }
+            }
+
+            fn secondary_selection_color(&self) -> Color {
+                unsafe {
+                    mem::transmute(#secondary_selection_color(self._inner))
+                }
+            }
+
+            fn set_highlight_color(&mut self, color: Color) {
+                unsafe {
+                    #set_highlight_color(self._inner, color as u32)
+                }
+            }
+
+            fn highlight_color(&self) -> Color {
+                unsafe {
+                    mem::transmute(#highlight_color(self._inner))
+                }
+            }
+
+            fn position_to_line_column(&self, pos: u32) -> (u32, u32) {
+                let buf = match self.buffer() {
+                    Some(buf) => buf,
+                    None => return (1, 1),
+                };
+                let pos = std::cmp::min(pos, buf.length());
+                let start = buf.line_start(pos);
+                let line = buf.count_lines(0, start) + 1;
+                let col = buf.byte_to_char(pos) - buf.byte_to_char(start) + 1;
+                (line, col)
+            }
+
+            fn line_column_to_position(&self, line: u32, col: u32) -> u32 {
+                let mut buf = match self.buffer() {
+                    Some(buf) => buf,
+                    None => return 0,
+                };
+                let start = buf.skip_lines(0, line.saturating_sub(1));
+                let end = buf.line_end(start);
+                let pos = buf.char_to_byte(buf.byte_to_char(start) + col.saturating_sub(1));
+                std::cmp::min(pos, end)
+            }
+
+            fn highlight_occurrences(&mut self, text: &str, color: Color) {
+                let ranges = match self.buffer() {
+                    Some(buf) => crate::text::find_occurrences(&buf.text_slice(), text),
+                    None => return,
+                };
+                self.highlight_ranges(&ranges, color)
+            }
+
+            fn highlight_ranges(&mut self, ranges: &[(u32, u32)], color: Color) {
+                crate::text::set_occurrence_highlights(self, ranges, Some(color))
+            }
+
+            fn clear_occurrence_highlights(&mut self) {
+                crate::text::set_occurrence_highlights(self, &[], None)
+            }
         }
     };
     gen.into()
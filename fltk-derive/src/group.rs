@@ -17,6 +17,22 @@ pub fn impl_group_trait(ast: &DeriveInput) -> TokenStream {
     let clear = Ident::new(format!("{}_{}", name_str, "clear").as_str(), name.span());
     let children = Ident::new(format!("{}_{}", name_str, "children").as_str(), name.span());
     let child = Ident::new(format!("{}_{}", name_str, "child").as_str(), name.span());
+    let set_clip_children = Ident::new(
+        format!("{}_{}", name_str, "set_clip_children").as_str(),
+        name.span(),
+    );
+    let clip_children = Ident::new(
+        format!("{}_{}", name_str, "clip_children").as_str(),
+        name.span(),
+    );
+    let draw_children = Ident::new(
+        format!("{}_{}", name_str, "draw_children").as_str(),
+        name.span(),
+    );
+    let override_draw = Ident::new(
+        format!("{}_{}", name_str, "override_draw").as_str(),
+        name.span(),
+    );
     let resizable = Ident::new(
         format!("{}_{}", name_str, "resizable").as_str(),
         name.span(),
@@ -57,12 +73,26 @@ pub fn impl_group_trait(ast: &DeriveInput) -> TokenStream {
                 }
             }
 
+            fn remove_by_index(&mut self, idx: u32) {
+                if let Some(child) = self.child(idx) {
+                    self.remove(&child);
+                }
+            }
+
             fn clear(&mut self) {
                 unsafe {
                     #clear(self._inner)
                 }
             }
 
+            fn delete_child(&mut self, idx: u32) -> Result<(), FltkError> {
+                let child = self.child(idx).ok_or(FltkError::Internal(FltkErrorKind::ResourceNotFound))?;
+                self.remove(&child);
+                crate::app::delete_widget(&child);
+                self.redraw();
+                Ok(())
+            }
+
             fn children(&self) -> u32 {
                 unsafe {
                     #children(self._inner) as u32
@@ -72,6 +102,10 @@ pub fn impl_group_trait(ast: &DeriveInput) -> TokenStream {
             fn child(&self, idx: u32) -> Option<Widget> {
                 unsafe {
                     debug_assert!(idx <= std::i32::MAX as u32, "u32 entries have to be < std::i32::MAX for compatibility!");
+                    // FLTK doesn't check the index
+                    if idx >= self.children() {
+                        return None;
+                    }
                     let child_widget = #child(self._inner, idx as i32);
                     if child_widget.is_null() {
                         None
@@ -81,11 +115,55 @@ pub fn impl_group_trait(ast: &DeriveInput) -> TokenStream {
                 }
             }
 
+            fn iter(&self) -> crate::group::GroupIterator {
+                unsafe { crate::group::GroupIterator::new(self.as_widget_ptr()) }
+            }
+
             fn resizable<Widget: WidgetExt>(&self, widget: &mut Widget) {
                 unsafe {
                     #resizable(self._inner, widget.as_widget_ptr() as *mut raw::c_void)
                 }
             }
+
+            fn set_resizable<Widget: WidgetExt>(&mut self, widget: &Widget) {
+                unsafe {
+                    #resizable(self._inner, widget.as_widget_ptr() as *mut raw::c_void)
+                }
+            }
+
+            fn clear_resizable(&mut self) {
+                unsafe {
+                    #resizable(self._inner, std::ptr::null_mut())
+                }
+            }
+
+            fn set_clip_children(&mut self, flag: bool) {
+                unsafe {
+                    #set_clip_children(self._inner, flag as i32)
+                }
+            }
+
+            fn draw_children(&mut self) {
+                unsafe {
+                    #draw_children(self._inner)
+                }
+            }
+
+            fn override_draw(&mut self, cb: Box<dyn FnMut()>) {
+                unsafe {
+                    #override_draw(self._inner, 1)
+                }
+                self.draw(cb);
+            }
+
+            fn clip_children(&self) -> bool {
+                unsafe {
+                    match #clip_children(self._inner) {
+                        0 => false,
+                        _ => true,
+                    }
+                }
+            }
         }
     };
     gen.into()
@@ -14,7 +14,9 @@ mod group;
 mod image;
 mod input;
 mod menu;
+mod menu_macro;
 mod table;
+mod ui;
 mod utils;
 mod valuator;
 mod widget;
@@ -27,7 +29,9 @@ use crate::group::impl_group_trait;
 use crate::image::impl_image_trait;
 use crate::input::impl_input_trait;
 use crate::menu::impl_menu_trait;
+use crate::menu_macro::impl_menu_macro;
 use crate::table::impl_table_trait;
+use crate::ui::impl_ui_macro;
 use crate::valuator::impl_valuator_trait;
 use crate::widget::*;
 use crate::window::impl_window_trait;
@@ -104,3 +108,29 @@ pub fn image_trait_macro(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
     impl_image_trait(&ast)
 }
+
+/// Declares a widget hierarchy, expanding to the constructor calls.
+/// `id = Type(args)` binds a widget to a local variable, empty args use Type::default(),
+/// a constructor can also be called directly like `Type::default()`.
+/// Builder calls can be chained after the constructor. Within braces, `.method(args)` calls
+/// a method on the widget, `name: value;` calls set_name(value), `callback: closure;` sets
+/// the callback, and nested declarations are added to the widget as children.
+/// Groups are ended once declared, even without children.
+/// The fltk prelude needs to be in scope
+#[proc_macro]
+pub fn ui(input: TokenStream) -> TokenStream {
+    impl_ui_macro(input)
+}
+
+/// Fills a menu widget from nested entries, expanding to add() calls and evaluating to the menu.
+/// The menu expression is followed by braces holding the entries:
+/// `"label" { entries }` declares a submenu, and
+/// `"label" [, shortcut [, flags]] => handler;` declares an item whose callback is handler,
+/// the shortcut defaults to Shortcut::None and the flags to MenuFlag::Normal.
+/// Labels are taken literally, '/', '_' and '&' don't create submenus, dividers or
+/// mnemonics. The generated code uses full paths, only the names used by the shortcut,
+/// flags and handler expressions need to be in scope
+#[proc_macro]
+pub fn menu(input: TokenStream) -> TokenStream {
+    impl_menu_macro(input)
+}
@@ -39,6 +39,10 @@ pub fn impl_menu_trait(ast: &DeriveInput) -> TokenStream {
         format!("{}_{}", name_str, "add_choice").as_str(),
         name.span(),
     );
+    let add_item = Ident::new(
+        format!("{}_{}", name_str, "add_item").as_str(),
+        name.span(),
+    );
     let get_choice = Ident::new(
         format!("{}_{}", name_str, "get_choice").as_str(),
         name.span(),
@@ -59,6 +63,19 @@ pub fn impl_menu_trait(ast: &DeriveInput) -> TokenStream {
         format!("{}_{}", name_str, "clear_submenu").as_str(),
         name.span(),
     );
+    let remove = Ident::new(format!("{}_{}", name_str, "remove").as_str(), name.span());
+    let replace = Ident::new(format!("{}_{}", name_str, "replace").as_str(), name.span());
+    let size = Ident::new(format!("{}_{}", name_str, "size").as_str(), name.span());
+    let at = Ident::new(format!("{}_{}", name_str, "at").as_str(), name.span());
+    let item_pathname = Ident::new(
+        format!("{}_{}", name_str, "item_pathname").as_str(),
+        name.span(),
+    );
+    let picked = Ident::new(format!("{}_{}", name_str, "picked").as_str(), name.span());
+    let test_shortcut = Ident::new(
+        format!("{}_{}", name_str, "test_shortcut").as_str(),
+        name.span(),
+    );
 
     let gen = quote! {
         unsafe impl MenuExt for #name {
@@ -67,17 +84,37 @@ pub fn impl_menu_trait(ast: &DeriveInput) -> TokenStream {
                     self.top_window().unwrap().takes_events() && self.takes_events(), 
                     "Handling events requires that the window and widget be active!"
                 );
+                let (name, shortcut) = crate::menu::split_accelerator(name, shortcut);
                 let temp = CString::new(name).unwrap();
                 unsafe {
-                    unsafe extern "C" fn shim(_wid: *mut Fl_Widget, data: *mut raw::c_void) {
-                        let a: *mut Box<dyn FnMut()> = mem::transmute(data);
-                        let f: &mut (dyn FnMut()) = &mut **a;
-                        f();
+                    // An existing item is reused by FLTK, its closure would leak
+                    if let Some(item) = self.find_item(name) {
+                        crate::menu::free_item_callback(item._inner);
                     }
-                    let a: *mut Box<dyn FnMut()> = Box::into_raw(Box::new(cb));
-                    let data: *mut raw::c_void = mem::transmute(a);
-                    let callback: Fl_Callback = Some(shim);
-                    #add(self._inner, temp.as_ptr(), shortcut as i32, callback, data, flag as i32);
+                    let data = crate::menu::new_item_data(cb);
+                    let callback: Fl_Callback = Some(crate::menu::menu_item_shim);
+                    crate::menu::free_items_on_delete(self);
+                    #add(self._inner, temp.as_ptr(), shortcut.bits(), callback, data, flag.bits());
+                }
+            }
+
+            fn add_literal(&mut self, parent: &str, label: &str, shortcut: Shortcut, flag: MenuFlag, cb: Box<dyn FnMut()>) {
+                let label = crate::menu::escape_label(label);
+                let parent = parent.trim_end_matches('/');
+                if parent.is_empty() {
+                    self.add(&label, shortcut, flag, cb)
+                } else {
+                    self.add(&format!("{}/{}", parent, label), shortcut, flag, cb)
+                }
+            }
+
+            fn add_emit<T: 'static + Copy + Send + Sync>(&mut self, name: &str, shortcut: Shortcut, flag: MenuFlag, sender: crate::app::Sender<T>, msg: T) {
+                self.add(name, shortcut, flag, Box::new(move || sender.send(msg)))
+            }
+
+            fn add_table<T: 'static + Copy + Send + Sync>(&mut self, sender: crate::app::Sender<T>, table: &[(&str, Shortcut, MenuFlag, T)]) {
+                for (name, shortcut, flag, msg) in table.iter() {
+                    self.add_emit(name, *shortcut, *flag, sender, *msg);
                 }
             }
 
@@ -86,20 +123,24 @@ pub fn impl_menu_trait(ast: &DeriveInput) -> TokenStream {
                     self.top_window().unwrap().takes_events() && self.takes_events(), 
                     "Handling events requires that the window and widget be active!"
                 );
+                debug_assert!(idx <= std::i32::MAX as u32, "u32 entries have to be < std::i32::MAX for compatibility!");
+                let (name, shortcut) = crate::menu::split_accelerator(name, shortcut);
                 let temp = CString::new(name).unwrap();
                 unsafe {
-                    unsafe extern "C" fn shim(_wid: *mut Fl_Widget, data: *mut raw::c_void) {
-                        let a: *mut Box<dyn FnMut()> = mem::transmute(data);
-                        let f: &mut (dyn FnMut()) = &mut **a;
-                        f();
+                    if let Some(item) = self.find_item(name) {
+                        crate::menu::free_item_callback(item._inner);
                     }
-                    let a: *mut Box<dyn FnMut()> = Box::into_raw(Box::new(cb));
-                    let data: *mut raw::c_void = mem::transmute(a);
-                    let callback: Fl_Callback = Some(shim);
-                    #insert(self._inner, idx as i32, temp.as_ptr(), shortcut as i32, callback, data, flag as i32);
+                    let data = crate::menu::new_item_data(cb);
+                    let callback: Fl_Callback = Some(crate::menu::menu_item_shim);
+                    crate::menu::free_items_on_delete(self);
+                    #insert(self._inner, idx as i32, temp.as_ptr(), shortcut.bits(), callback, data, flag.bits());
                 }
             }
 
+            fn insert_emit<T: 'static + Copy + Send + Sync>(&mut self, idx: u32, name: &str, shortcut: Shortcut, flag: MenuFlag, sender: crate::app::Sender<T>, msg: T) {
+                self.insert(idx, name, shortcut, flag, Box::new(move || sender.send(msg)))
+            }
+
             fn find_item(&self, name: &str) -> Option<MenuItem> {
                 let name = CString::new(name).unwrap().clone();
                 unsafe {
@@ -168,6 +209,19 @@ pub fn impl_menu_trait(ast: &DeriveInput) -> TokenStream {
                 }
             }
 
+            fn add_choices<I>(&mut self, choices: I)
+            where
+                I: IntoIterator,
+                I::Item: AsRef<str>,
+            {
+                for choice in choices {
+                    let label = CString::new(crate::menu::escape_label(choice.as_ref())).unwrap();
+                    unsafe {
+                        #add_item(self._inner, label.as_ptr())
+                    }
+                }
+            }
+
             fn choice(&self) -> Option<String> {
                 unsafe {
                     let choice_ptr = #get_choice(self._inner);
@@ -193,6 +247,7 @@ pub fn impl_menu_trait(ast: &DeriveInput) -> TokenStream {
 
             fn clear(&mut self) {
                 unsafe {
+                    crate::menu::free_menu_items(self, 0, self.size());
                     #clear(self._inner)
                 }
             }
@@ -200,12 +255,163 @@ pub fn impl_menu_trait(ast: &DeriveInput) -> TokenStream {
             fn clear_submenu(&mut self, idx: u32) -> Result<(), FltkError> {
                 debug_assert!(idx <= std::i32::MAX as u32, "u32 entries have to be < std::i32::MAX for compatibility!");
                 unsafe {
+                    if let Some(end) = crate::menu::submenu_end(self, idx) {
+                        crate::menu::free_menu_items(self, idx + 1, end);
+                    }
                     match #clear_submenu(self._inner, idx as i32) {
                         0 => Ok(()),
                         _ => Err(FltkError::Internal(FltkErrorKind::FailedOperation)),
                     }
                 }
             }
+
+            fn remove(&mut self, idx: u32) -> Result<(), FltkError> {
+                debug_assert!(idx <= std::i32::MAX as u32, "u32 entries have to be < std::i32::MAX for compatibility!");
+                // Terminators aren't items and can't be removed
+                match self.at(idx) {
+                    Some(item) if item.label().is_some() => (),
+                    _ => return Err(FltkError::Internal(FltkErrorKind::FailedOperation)),
+                }
+                unsafe {
+                    let end = crate::menu::submenu_end(self, idx).unwrap_or(idx);
+                    crate::menu::free_menu_items(self, idx, end + 1);
+                    #remove(self._inner, idx as i32);
+                }
+                Ok(())
+            }
+
+            fn remove_by_path(&mut self, path: &str) -> Result<(), FltkError> {
+                match self.find_index(path) {
+                    Some(idx) => self.remove(idx),
+                    None => Err(FltkError::Internal(FltkErrorKind::ResourceNotFound)),
+                }
+            }
+
+            fn find_index(&self, path: &str) -> Option<u32> {
+                let item = self.find_item(path)?;
+                (0..self.size()).find(|idx| self.at(*idx).map(|i| i._inner) == Some(item._inner))
+            }
+
+            fn set_item_active(&mut self, path: &str, active: bool) -> Result<(), FltkError> {
+                let mut item = self.find_item(path).ok_or(FltkError::Internal(FltkErrorKind::ResourceNotFound))?;
+                if active {
+                    item.activate();
+                } else {
+                    item.deactivate();
+                }
+                self.redraw();
+                Ok(())
+            }
+
+            fn add_image<I: ImageExt>(&mut self, path: &str, image: &I, on_left: bool) -> Result<(), FltkError> {
+                let mut item = self.find_item(path).ok_or(FltkError::Internal(FltkErrorKind::ResourceNotFound))?;
+                crate::menu::free_items_on_delete(self);
+                item.add_image(image, on_left);
+                self.redraw();
+                Ok(())
+            }
+
+            fn set_item_value(&mut self, path: &str, value: bool) -> Result<(), FltkError> {
+                let mut item = self.find_item(path).ok_or(FltkError::Internal(FltkErrorKind::ResourceNotFound))?;
+                match (value, item.is_radio()) {
+                    (true, true) => item.setonly(),
+                    (true, false) => item.set(),
+                    (false, _) => item.clear(),
+                }
+                self.redraw();
+                Ok(())
+            }
+
+            fn clear_submenu_by_path(&mut self, path: &str) -> Result<(), FltkError> {
+                match self.find_index(path) {
+                    Some(idx) => self.clear_submenu(idx),
+                    None => Err(FltkError::Internal(FltkErrorKind::ResourceNotFound)),
+                }
+            }
+
+            fn replace(&mut self, idx: u32, label: &str) -> Result<(), FltkError> {
+                debug_assert!(idx <= std::i32::MAX as u32, "u32 entries have to be < std::i32::MAX for compatibility!");
+                let item = match self.at(idx) {
+                    Some(item) if item.label().is_some() => item,
+                    _ => return Err(FltkError::Internal(FltkErrorKind::FailedOperation)),
+                };
+                let label = CString::new(label).unwrap();
+                unsafe {
+                    // The item's image goes along with its previous label
+                    Fl_Menu_Item_release_label(item._inner);
+                    let old = Fl_Menu_Item_label(item._inner);
+                    #replace(self._inner, idx as i32, label.as_ptr());
+                    crate::menu::rekey_item_drawer(old, item._inner);
+                }
+                Ok(())
+            }
+
+            fn size(&self) -> u32 {
+                unsafe {
+                    #size(self._inner) as u32
+                }
+            }
+
+            fn at(&self, idx: u32) -> Option<MenuItem> {
+                debug_assert!(idx <= std::i32::MAX as u32, "u32 entries have to be < std::i32::MAX for compatibility!");
+                unsafe {
+                    let menu_item = #at(self._inner, idx as i32);
+                    if menu_item.is_null() {
+                        None
+                    } else {
+                        Some(MenuItem {
+                            _inner: menu_item,
+                        })
+                    }
+                }
+            }
+
+            fn test_shortcut(&mut self) -> Option<MenuItem> {
+                unsafe {
+                    let menu_item = #test_shortcut(self._inner);
+                    if menu_item.is_null() {
+                        None
+                    } else {
+                        Some(MenuItem {
+                            _inner: menu_item,
+                        })
+                    }
+                }
+            }
+
+            fn find_radio_selected(&self, path: &str) -> Option<MenuItem> {
+                let items: Vec<MenuItem> = if path.is_empty() {
+                    let first = self.at(0).filter(|item| item.label().is_some());
+                    std::iter::successors(first, |item| item.next(1)).collect()
+                } else {
+                    self.find_item(path)?.children()
+                };
+                items.into_iter().find(|item| item.is_radio() && item.value())
+            }
+
+            fn items(&self) -> Vec<MenuItem> {
+                (0..self.size())
+                    .filter_map(|idx| self.at(idx))
+                    .filter(|item| item.label().is_some())
+                    .collect()
+            }
+
+            fn item_pathname(&self, item: &MenuItem) -> Option<String> {
+                let mut buf: Vec<u8> = vec![0u8; 256];
+                unsafe {
+                    let ret = #item_pathname(self._inner, buf.as_mut_ptr() as *mut raw::c_char, 256, item._inner);
+                    if ret != 0 {
+                        return None;
+                    }
+                    Some(CStr::from_ptr(buf.as_ptr() as *const raw::c_char).to_string_lossy().to_string())
+                }
+            }
+
+            fn pick(&mut self, item: &MenuItem) {
+                unsafe {
+                    #picked(self._inner, item._inner)
+                }
+            }
         }
     };
     gen.into()
@@ -0,0 +1,188 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Delimiter, Span, TokenStream as TokenStream2, TokenTree};
+use quote::*;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::*;
+
+/// An entry of a menu declaration
+enum MenuEntry {
+    /// `"label" { entries }`
+    Submenu(LitStr, Vec<MenuEntry>),
+    /// `"label" [, shortcut [, flags]] => handler;`
+    Item {
+        label: LitStr,
+        shortcut: Option<TokenStream2>,
+        flags: Option<TokenStream2>,
+        handler: TokenStream2,
+    },
+}
+
+fn parse_entries(input: ParseStream) -> Result<Vec<MenuEntry>> {
+    let mut entries = vec![];
+    while !input.is_empty() {
+        entries.push(input.parse()?);
+    }
+    Ok(entries)
+}
+
+/// Collects the tokens up to the next top level `,` or `=>`
+fn parse_argument(input: ParseStream) -> Result<TokenStream2> {
+    let mut arg = TokenStream2::new();
+    while !input.is_empty() && !input.peek(Token![,]) && !input.peek(Token![=>]) {
+        arg.extend(std::iter::once(input.parse::<TokenTree>()?));
+    }
+    if arg.is_empty() {
+        return Err(input.error("expected an expression"));
+    }
+    Ok(arg)
+}
+
+impl Parse for MenuEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let label: LitStr = input.parse()?;
+        if input.peek(token::Brace) {
+            let content;
+            braced!(content in input);
+            let entries = parse_entries(&content)?;
+            if input.peek(Token![;]) || input.peek(Token![,]) {
+                input.parse::<TokenTree>()?;
+            }
+            return Ok(MenuEntry::Submenu(label, entries));
+        }
+        let mut shortcut = None;
+        let mut flags = None;
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            shortcut = Some(parse_argument(input)?);
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+                flags = Some(parse_argument(input)?);
+            }
+        }
+        input.parse::<Token![=>]>()?;
+        // The handler runs up to the next semicolon, closures may contain commas
+        let mut handler = TokenStream2::new();
+        while !input.is_empty() && !input.peek(Token![;]) {
+            handler.extend(std::iter::once(input.parse::<TokenTree>()?));
+        }
+        if handler.is_empty() {
+            return Err(Error::new(
+                label.span(),
+                "expected a handler for the menu item",
+            ));
+        }
+        if input.peek(Token![;]) {
+            input.parse::<Token![;]>()?;
+        }
+        Ok(MenuEntry::Item {
+            label,
+            shortcut,
+            flags,
+            handler,
+        })
+    }
+}
+
+/// Escapes a label so that it's added as a single item, like fltk::menu::escape_label:
+/// '/', '\\', '_' and tabs are escaped by a backslash, '&' by doubling it
+fn escape_label(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for c in label.chars() {
+        match c {
+            '/' | '\\' | '_' | '\t' => escaped.push('\\'),
+            '&' => escaped.push('&'),
+            _ => (),
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn expand_entries(
+    menu: &Ident,
+    prefix: &str,
+    entries: &[MenuEntry],
+    stmts: &mut Vec<TokenStream2>,
+) {
+    for entry in entries.iter() {
+        match entry {
+            MenuEntry::Submenu(label, entries) => {
+                let path = format!("{}{}", prefix, escape_label(&label.value()));
+                if entries.is_empty() {
+                    let path = LitStr::new(&path, label.span());
+                    stmts.push(quote! {
+                        ::fltk::prelude::MenuExt::add(
+                            &mut #menu,
+                            #path,
+                            ::fltk::enums::Shortcut::None,
+                            ::fltk::menu::MenuFlag::Submenu,
+                            ::std::boxed::Box::new(|| ()),
+                        );
+                    });
+                } else {
+                    expand_entries(menu, &format!("{}/", path), entries, stmts);
+                }
+            }
+            MenuEntry::Item {
+                label,
+                shortcut,
+                flags,
+                handler,
+            } => {
+                let path = format!("{}{}", prefix, escape_label(&label.value()));
+                let path = LitStr::new(&path, label.span());
+                let shortcut = shortcut
+                    .clone()
+                    .unwrap_or_else(|| quote! { ::fltk::enums::Shortcut::None });
+                let flags = flags
+                    .clone()
+                    .unwrap_or_else(|| quote! { ::fltk::menu::MenuFlag::Normal });
+                stmts.push(quote! {
+                    ::fltk::prelude::MenuExt::add(
+                        &mut #menu,
+                        #path,
+                        #shortcut,
+                        #flags,
+                        ::std::boxed::Box::new(#handler),
+                    );
+                });
+            }
+        }
+    }
+}
+
+pub fn impl_menu_macro(input: TokenStream) -> TokenStream {
+    let mut tokens: Vec<TokenTree> = TokenStream2::from(input).into_iter().collect();
+    let body = match tokens.pop() {
+        Some(TokenTree::Group(group))
+            if group.delimiter() == Delimiter::Brace && !tokens.is_empty() =>
+        {
+            group
+        }
+        _ => {
+            return Error::new(
+                Span::call_site(),
+                "expected a menu expression followed by braced entries",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+    let entries = match parse_entries.parse2(body.stream()) {
+        Ok(entries) => entries,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let target: TokenStream2 = tokens.into_iter().collect();
+    let menu = Ident::new("__menu", Span::call_site());
+    let mut stmts = vec![];
+    expand_entries(&menu, "", &entries, &mut stmts);
+    let gen = quote! {
+        {
+            #[allow(unused_mut)]
+            let mut #menu = #target;
+            #(#stmts)*
+            #menu
+        }
+    };
+    gen.into()
+}
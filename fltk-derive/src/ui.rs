@@ -0,0 +1,190 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::*;
+use syn::parse::{Parse, ParseStream};
+use syn::*;
+
+/// A widget declaration: `[id =] Type(args).builder(args)... [{ items }]`,
+/// the type can also be a constructor function like Type::default
+struct UiNode {
+    id: Option<Ident>,
+    ty: Path,
+    args: TokenStream2,
+    chain: Vec<(Ident, TokenStream2)>,
+    items: Option<Vec<UiItem>>,
+}
+
+/// An item within the braces of a widget declaration
+enum UiItem {
+    /// `.method(args)`, called on the widget
+    Call(Ident, TokenStream2),
+    /// `name: value`, calls set_name(value), or set_callback(Box::new(value)) for callback
+    Property(Ident, TokenStream2),
+    /// A child widget
+    Child(UiNode),
+}
+
+struct Ui {
+    nodes: Vec<UiNode>,
+}
+
+fn parse_separator(input: ParseStream) -> Result<()> {
+    if input.peek(Token![;]) {
+        input.parse::<Token![;]>()?;
+    } else if input.peek(Token![,]) {
+        input.parse::<Token![,]>()?;
+    }
+    Ok(())
+}
+
+fn parse_call(input: ParseStream) -> Result<(Ident, TokenStream2)> {
+    input.parse::<Token![.]>()?;
+    let name: Ident = input.parse()?;
+    let content;
+    parenthesized!(content in input);
+    Ok((name, content.parse()?))
+}
+
+impl Parse for UiNode {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let id = if input.peek(Ident) && input.peek2(Token![=]) {
+            let id: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Some(id)
+        } else {
+            None
+        };
+        let ty = input.call(Path::parse_mod_style)?;
+        let content;
+        parenthesized!(content in input);
+        let args: TokenStream2 = content.parse()?;
+        let mut chain = vec![];
+        while input.peek(Token![.]) {
+            chain.push(parse_call(input)?);
+        }
+        let items = if input.peek(token::Brace) {
+            let content;
+            braced!(content in input);
+            let mut items = vec![];
+            while !content.is_empty() {
+                items.push(content.parse()?);
+            }
+            Some(items)
+        } else {
+            None
+        };
+        parse_separator(input)?;
+        Ok(UiNode {
+            id,
+            ty,
+            args,
+            chain,
+            items,
+        })
+    }
+}
+
+impl Parse for UiItem {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![.]) {
+            let (name, args) = parse_call(input)?;
+            parse_separator(input)?;
+            return Ok(UiItem::Call(name, args));
+        }
+        if input.peek(Ident) && input.peek2(Token![:]) && !input.peek2(Token![::]) {
+            let name: Ident = input.parse()?;
+            input.parse::<Token![:]>()?;
+            // The value runs up to the next semicolon, closures may contain commas
+            let mut value = TokenStream2::new();
+            while !input.is_empty() && !input.peek(Token![;]) {
+                value.extend(std::iter::once(input.parse::<TokenTree>()?));
+            }
+            if value.is_empty() {
+                return Err(Error::new(name.span(), "expected a value for the property"));
+            }
+            parse_separator(input)?;
+            return Ok(UiItem::Property(name, value));
+        }
+        Ok(UiItem::Child(input.parse()?))
+    }
+}
+
+impl Parse for Ui {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut nodes = vec![];
+        while !input.is_empty() {
+            nodes.push(input.parse()?);
+        }
+        Ok(Ui { nodes })
+    }
+}
+
+fn expand_node(node: &UiNode, stmts: &mut Vec<TokenStream2>, counter: &mut usize) -> Ident {
+    let id = match &node.id {
+        Some(id) => id.clone(),
+        None => {
+            *counter += 1;
+            Ident::new(&format!("__ui_widget_{}", counter), Span::call_site())
+        }
+    };
+    let ty = &node.ty;
+    let args = &node.args;
+    // A path ending in a function, like Window::default, is called as is
+    let is_fn = ty.segments.last().map_or(false, |s| {
+        s.ident.to_string().starts_with(|c: char| c.is_lowercase())
+    });
+    let mut ctor = if is_fn {
+        quote! { #ty(#args) }
+    } else if args.is_empty() {
+        quote! { #ty::default() }
+    } else {
+        quote! { #ty::new(#args) }
+    };
+    for (name, args) in node.chain.iter() {
+        ctor = quote! { #ctor.#name(#args) };
+    }
+    stmts.push(quote! {
+        #[allow(unused_mut)]
+        let mut #id = #ctor;
+    });
+    if let Some(items) = &node.items {
+        for item in items.iter() {
+            match item {
+                UiItem::Call(name, args) => stmts.push(quote! { #id.#name(#args); }),
+                UiItem::Property(name, value) => {
+                    if name == "callback" {
+                        stmts.push(quote! { #id.set_callback(Box::new(#value)); });
+                    } else {
+                        let setter = Ident::new(&format!("set_{}", name), name.span());
+                        stmts.push(quote! { #id.#setter(#value); });
+                    }
+                }
+                UiItem::Child(child) => {
+                    let child_id = expand_node(child, stmts, counter);
+                    // Explicitly parented, so the hierarchy doesn't depend on the current group
+                    stmts.push(quote! { #id.add(&#child_id); });
+                }
+            }
+        }
+    }
+    // Groups begin when created, so they're ended even without children
+    stmts.push(quote! {
+        {
+            #[allow(unused_imports)]
+            use ::fltk::ui_support::{EndGroup as _, EndWidget as _};
+            (&mut ::fltk::ui_support::UiNode(&mut #id)).end_node();
+        }
+    });
+    id
+}
+
+pub fn impl_ui_macro(input: TokenStream) -> TokenStream {
+    let ui = parse_macro_input!(input as Ui);
+    let mut stmts = vec![];
+    let mut counter = 0;
+    for node in ui.nodes.iter() {
+        expand_node(node, &mut stmts, &mut counter);
+    }
+    let gen = quote! { #(#stmts)* };
+    gen.into()
+}
@@ -110,6 +110,14 @@ pub fn impl_widget_trait(ast: &DeriveInput) -> TokenStream {
         name.span(),
     );
     let set_draw = Ident::new(format!("{}_{}", name_str, "set_draw").as_str(), name.span());
+    let set_draw_filter = Ident::new(
+        format!("{}_{}", name_str, "set_draw_filter").as_str(),
+        name.span(),
+    );
+    let set_draw_over = Ident::new(
+        format!("{}_{}", name_str, "set_draw_over").as_str(),
+        name.span(),
+    );
     let parent = Ident::new(format!("{}_{}", name_str, "parent").as_str(), name.span());
     let selection_color = Ident::new(
         format!("{}_{}", name_str, "selection_color").as_str(),
@@ -369,11 +377,11 @@ pub fn impl_widget_trait(ast: &DeriveInput) -> TokenStream {
             }
 
             fn align(&self) -> Align {
-                unsafe { mem::transmute(#align(self._inner)) }
+                unsafe { Align::from_i32(#align(self._inner)) }
             }
 
             fn set_align(&mut self, align: Align) {
-                unsafe { #set_align(self._inner, align as i32) }
+                unsafe { #set_align(self._inner, align.bits()) }
             }
 
             fn set_image<Image: ImageExt>(&mut self, image: &Image) {
@@ -400,15 +408,11 @@ pub fn impl_widget_trait(ast: &DeriveInput) -> TokenStream {
                     self.top_window().unwrap().takes_events() && self.takes_events(), 
                     "Handling events requires that the window and widget be active!"
                 );
+                crate::widget::register_data_deleter();
                 unsafe {
-                    unsafe extern "C" fn shim(_wid: *mut fltk_sys::widget::Fl_Widget, data: *mut raw::c_void) {
-                        let a: *mut Box<dyn FnMut()> = mem::transmute(data);
-                        let f: &mut (dyn FnMut()) = &mut **a;
-                        f();
-                    }
                     let a: *mut Box<dyn FnMut()> = Box::into_raw(Box::new(cb));
                     let data: *mut raw::c_void = mem::transmute(a);
-                    let callback: fltk_sys::widget::Fl_Callback = Some(shim);
+                    let callback: fltk_sys::widget::Fl_Callback = Some(crate::widget::callback_shim);
                     fltk_sys::widget::Fl_Widget_callback_with_captures(self.as_widget_ptr(), callback, data);
                 }
             }
@@ -418,6 +422,7 @@ pub fn impl_widget_trait(ast: &DeriveInput) -> TokenStream {
                     self.top_window().unwrap().takes_events() && self.takes_events(), 
                     "Handling events requires that the window and widget be active!"
                 );
+                crate::widget::register_data_deleter();
                 unsafe {
                     unsafe extern "C" fn shim(_ev: std::os::raw::c_int, data: *mut raw::c_void) -> i32 {
                         let ev: Event = mem::transmute(_ev);
@@ -440,6 +445,7 @@ pub fn impl_widget_trait(ast: &DeriveInput) -> TokenStream {
                     self.top_window().unwrap().takes_events() && self.takes_events(), 
                     "Handling events requires that the window and widget be active!"
                 );
+                crate::widget::register_data_deleter();
                 unsafe {
                     unsafe extern "C" fn shim(data: *mut raw::c_void) {
                         let a: *mut Box<dyn FnMut()> = mem::transmute(data);
@@ -453,6 +459,54 @@ pub fn impl_widget_trait(ast: &DeriveInput) -> TokenStream {
                 }
             }
 
+            fn set_draw_filter(&mut self, filter: crate::widget::Filter) {
+                type PixelMap = Box<dyn FnMut((u8, u8, u8)) -> (u8, u8, u8)>;
+                crate::widget::register_data_deleter();
+                unsafe {
+                    unsafe extern "C" fn shim(pixels: *mut u8, count: i32, data: *mut raw::c_void) {
+                        let a: *mut PixelMap = mem::transmute(data);
+                        let f: &mut (dyn FnMut((u8, u8, u8)) -> (u8, u8, u8)) = &mut **a;
+                        let pixels = std::slice::from_raw_parts_mut(pixels, count as usize * 3);
+                        for px in pixels.chunks_mut(3) {
+                            let (r, g, b) = f((px[0], px[1], px[2]));
+                            px[0] = r;
+                            px[1] = g;
+                            px[2] = b;
+                        }
+                    }
+                    let old = match filter.into_fn() {
+                        Some(f) => {
+                            let a: *mut PixelMap = Box::into_raw(Box::new(f));
+                            let data: *mut raw::c_void = mem::transmute(a);
+                            let callback: custom_filter_callback = Some(shim);
+                            #set_draw_filter(self._inner, callback, data)
+                        }
+                        None => #set_draw_filter(self._inner, None, std::ptr::null_mut()),
+                    };
+                    if !old.is_null() {
+                        let _ = Box::from_raw(old as *mut PixelMap);
+                    }
+                }
+            }
+
+            fn draw_over(&mut self, cb: Box<dyn FnMut(i32, i32, i32, i32)>) {
+                crate::widget::register_data_deleter();
+                unsafe {
+                    unsafe extern "C" fn shim(x: i32, y: i32, w: i32, h: i32, data: *mut raw::c_void) {
+                        let a: *mut Box<dyn FnMut(i32, i32, i32, i32)> = mem::transmute(data);
+                        let f: &mut (dyn FnMut(i32, i32, i32, i32)) = &mut **a;
+                        f(x, y, w, h);
+                    }
+                    let a: *mut Box<dyn FnMut(i32, i32, i32, i32)> = Box::into_raw(Box::new(cb));
+                    let data: *mut raw::c_void = mem::transmute(a);
+                    let callback: custom_overlay_callback = Some(shim);
+                    let old = #set_draw_over(self._inner, callback, data);
+                    if !old.is_null() {
+                        let _ = Box::from_raw(old as *mut Box<dyn FnMut(i32, i32, i32, i32)>);
+                    }
+                }
+            }
+
             fn set_trigger(&mut self, trigger: CallbackTrigger) {
                 unsafe {
                     #set_trigger(self._inner, trigger as i32)
@@ -513,6 +567,18 @@ pub fn impl_widget_trait(ast: &DeriveInput) -> TokenStream {
                 }
             }
 
+            fn set_parent_explicit<G: GroupExt>(&mut self, parent: &mut G) {
+                parent.add(self);
+            }
+
+            fn set_help_id(&mut self, id: &str) {
+                unsafe { crate::dialog::set_widget_help_id(self.as_widget_ptr(), id) }
+            }
+
+            fn help_id(&self) -> Option<String> {
+                unsafe { crate::dialog::widget_help_id(self.as_widget_ptr()) }
+            }
+
             fn selection_color(&mut self) -> Color {
                 unsafe {
                     mem::transmute(#selection_color(self._inner))
@@ -29,6 +29,10 @@ pub fn impl_window_trait(ast: &DeriveInput) -> TokenStream {
     let set_cursor = Ident::new(format!("{}_{}", name_str, "set_cursor").as_str(), name.span());
     let shown = Ident::new(format!("{}_{}", name_str, "shown").as_str(), name.span());
     let raw_handle = Ident::new(format!("{}_{}", name_str, "raw_handle").as_str(), name.span());
+    let set_state_callback = Ident::new(
+        format!("{}_{}", name_str, "set_state_callback").as_str(),
+        name.span(),
+    );
 
     let gen = quote! {
         unsafe impl WindowExt for #name {
@@ -96,6 +100,21 @@ pub fn impl_window_trait(ast: &DeriveInput) -> TokenStream {
                     #raw_handle(self._inner)
                 }
             }
+
+            fn set_state_callback(&mut self, cb: Box<dyn FnMut(WindowState)>) {
+                crate::widget::register_data_deleter();
+                unsafe {
+                    unsafe extern "C" fn shim(state: raw::c_int, data: *mut raw::c_void) {
+                        let a: *mut Box<dyn FnMut(WindowState)> = mem::transmute(data);
+                        let f: &mut (dyn FnMut(WindowState)) = &mut **a;
+                        f(mem::transmute(state));
+                    }
+                    let a: *mut Box<dyn FnMut(WindowState)> = Box::into_raw(Box::new(cb));
+                    let data: *mut raw::c_void = mem::transmute(a);
+                    let callback: Fl_Window_State_Cb = Some(shim);
+                    #set_state_callback(self._inner, callback, data);
+                }
+            }
         }
     };
     gen.into()
@@ -1,7 +1,9 @@
 #include "cfl.h"
 #include "cfl_widget.h"
 #include <FL/Fl.H>
+#include <FL/Fl_Group.H>
 #include <FL/Fl_Widget.H>
+#include <FL/Fl_Window.H>
 #include <new>
 #include <random>
 #include <string.h>
@@ -51,6 +53,10 @@ int Fl_screen_h(void) { return Fl::h(); }
 
 int Fl_screen_w(void) { return Fl::w(); }
 
+void Fl_screen_work_area(int *x, int *y, int *w, int *h, int mx, int my) {
+    Fl::screen_work_area(*x, *y, *w, *h, mx, my);
+}
+
 void Fl_paste(Fl_Widget *widget, int src) {
     Fl::paste(*widget, src, Fl::clipboard_plain_text);
 }
@@ -67,6 +73,11 @@ unsigned char Fl_set_fonts(const char *c) { return Fl::set_fonts(c); }
 
 void Fl_add_handler(int (*ev_handler)(int ev)) { Fl::add_handler(ev_handler); }
 
+void Fl_add_system_handler(int (*handler)(void *event, void *data),
+                           void *data) {
+    Fl::add_system_handler(handler, data);
+}
+
 void Fl_awake_msg(void *msg) { Fl::awake(msg); }
 
 void *Fl_thread_msg(void) { return Fl::thread_message(); }
@@ -85,6 +96,24 @@ void Fl_remove_timeout(void (*timeout_h)(void *), void *data) {
     Fl::remove_timeout(timeout_h, data);
 }
 
+void Fl_add_fd(int fd, int when, void (*fd_h)(int, void *), void *data) {
+    Fl::add_fd(fd, when, fd_h, data);
+}
+
+void Fl_remove_fd(int fd, int when) { Fl::remove_fd(fd, when); }
+
+void Fl_add_idle(void (*idle_h)(void *), void *data) {
+    Fl::add_idle(idle_h, data);
+}
+
+int Fl_has_idle(void (*idle_h)(void *), void *data) {
+    return Fl::has_idle(idle_h, data);
+}
+
+void Fl_remove_idle(void (*idle_h)(void *), void *data) {
+    Fl::remove_idle(idle_h, data);
+}
+
 int Fl_dnd(void) { return Fl::dnd(); }
 
 void *Fl_first_window(void) { return (void *)Fl::first_window(); }
@@ -106,3 +135,137 @@ int Fl_event_inside(int x, int y, int w, int h) { return Fl::event_inside(x, y,
 // Fl_Widget *Fl_belowmouse() { return Fl::belowmouse(); }
 
 void Fl_delete_widget(Fl_Widget *w) { Fl::delete_widget(w); }
+
+// Delivers a synthetic key press or release to the window having focus
+int Fl_send_key(int key, int down) {
+    Fl_Widget *focus = Fl::focus();
+    Fl_Window *win = focus ? focus->top_window() : Fl::first_window();
+    if (!win)
+        return 0;
+    Fl::e_keysym = key;
+    Fl::e_original_keysym = key;
+    Fl::e_text = (char *)"";
+    Fl::e_length = 0;
+    return Fl::handle(down ? FL_KEYBOARD : FL_KEYUP, win);
+}
+
+int Fl_dnd_text_ops(void) { return Fl::dnd_text_ops(); }
+
+void Fl_set_dnd_text_ops(int flag) { Fl::dnd_text_ops(flag); }
+
+static Fl_Screen_Change_Handler screen_change_cb = NULL;
+static void *screen_change_data = NULL;
+
+static int screen_change_handler(int ev) {
+    if (ev == FL_SCREEN_CONFIGURATION_CHANGED && screen_change_cb)
+        screen_change_cb(screen_change_data);
+    return 0;
+}
+
+void *Fl_set_screen_change_callback(Fl_Screen_Change_Handler cb, void *data) {
+    void *old = screen_change_data;
+    if (!screen_change_cb)
+        Fl::add_handler(screen_change_handler);
+    screen_change_cb = cb;
+    screen_change_data = data;
+    return old;
+}
+
+int Fl_widget_visible_r(Fl_Widget *w) { return w->visible_r(); }
+
+void *Fl_Widget_Tracker_new(Fl_Widget *w) {
+    return (void *)new Fl_Widget_Tracker(w);
+}
+
+int Fl_Widget_Tracker_deleted(void *self) {
+    return ((Fl_Widget_Tracker *)self)->deleted();
+}
+
+void Fl_Widget_Tracker_delete(void *self) {
+    delete (Fl_Widget_Tracker *)self;
+}
+
+static Fl_Context_Help_Handler context_help_cb = NULL;
+static void *context_help_data = NULL;
+static Fl_Event_Dispatch context_help_prev_dispatch = NULL;
+static bool context_help_installed = false;
+static bool whats_this_active = false;
+static bool whats_this_swallow_release = false;
+
+// Returns the innermost visible widget containing the window relative point
+static Fl_Widget *widget_at(Fl_Group *g, int x, int y) {
+    for (int i = g->children() - 1; i >= 0; i--) {
+        Fl_Widget *c = g->child(i);
+        if (!c->visible() || c->as_window())
+            continue;
+        if (x >= c->x() && y >= c->y() && x < c->x() + c->w() &&
+            y < c->y() + c->h()) {
+            Fl_Group *cg = c->as_group();
+            return cg ? widget_at(cg, x, y) : c;
+        }
+    }
+    return g;
+}
+
+static void whats_this_end(void) {
+    whats_this_active = false;
+    for (Fl_Window *w = Fl::first_window(); w; w = Fl::next_window(w))
+        w->cursor(FL_CURSOR_DEFAULT);
+}
+
+// F1 requests help for the focused widget, or the one under the mouse.
+// In What's This mode, the next click requests help for the clicked widget
+// instead of being delivered to it
+static int context_help_dispatch(int event, Fl_Window *win) {
+    if (win && context_help_cb) {
+        if (whats_this_active) {
+            if (event == FL_PUSH) {
+                whats_this_end();
+                whats_this_swallow_release = true;
+                context_help_cb(widget_at(win, Fl::event_x(), Fl::event_y()),
+                                context_help_data);
+                return 1;
+            }
+            if (event == FL_KEYBOARD && Fl::event_key() == FL_Escape) {
+                whats_this_end();
+                return 1;
+            }
+        }
+        if (whats_this_swallow_release &&
+            (event == FL_DRAG || event == FL_RELEASE)) {
+            if (event == FL_RELEASE)
+                whats_this_swallow_release = false;
+            return 1;
+        }
+        if (event == FL_KEYBOARD && Fl::event_key() == FL_F + 1) {
+            Fl_Widget *w = Fl::focus();
+            if (!w || w->window() != win)
+                w = widget_at(win, Fl::event_x(), Fl::event_y());
+            context_help_cb(w, context_help_data);
+            return 1;
+        }
+    }
+    return context_help_prev_dispatch ? context_help_prev_dispatch(event, win)
+                                      : Fl::handle_(event, win);
+}
+
+void *Fl_set_context_help_callback(Fl_Context_Help_Handler cb, void *data) {
+    void *old = context_help_data;
+    if (!context_help_installed) {
+        // Chains to any previously installed dispatch function
+        context_help_prev_dispatch = Fl::event_dispatch();
+        Fl::event_dispatch(context_help_dispatch);
+        context_help_installed = true;
+    }
+    context_help_cb = cb;
+    context_help_data = data;
+    return old;
+}
+
+void Fl_start_whats_this(void) {
+    if (!context_help_cb)
+        return;
+    whats_this_active = true;
+    for (Fl_Window *w = Fl::first_window(); w; w = Fl::next_window(w))
+        w->cursor(FL_CURSOR_HELP);
+}
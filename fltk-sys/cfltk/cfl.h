@@ -40,6 +40,8 @@ int Fl_screen_h(void);
 
 int Fl_screen_w(void);
 
+void Fl_screen_work_area(int *x, int *y, int *w, int *h, int mx, int my);
+
 void Fl_paste(Fl_Widget *, int src);
 
 void Fl_set_scheme(const char *scheme);
@@ -52,6 +54,9 @@ unsigned char Fl_set_fonts(const char *c);
 
 void Fl_add_handler(int (*ev_handler)(int ev));
 
+void Fl_add_system_handler(int (*handler)(void *event, void *data),
+                           void *data);
+
 void Fl_awake_msg(void *msg);
 
 void *Fl_thread_msg(void);
@@ -64,6 +69,16 @@ void Fl_repeat_timeout(double t, void (*)(void *), void *);
 
 void Fl_remove_timeout(void (*)(void *), void *);
 
+void Fl_add_idle(void (*)(void *), void *);
+
+void Fl_add_fd(int fd, int when, void (*)(int, void *), void *);
+
+void Fl_remove_fd(int fd, int when);
+
+int Fl_has_idle(void (*)(void *), void *);
+
+void Fl_remove_idle(void (*)(void *), void *);
+
 int Fl_dnd(void);
 
 void *Fl_first_window(void);
@@ -80,6 +95,30 @@ int Fl_event_inside(int, int, int, int);
 
 void Fl_delete_widget(Fl_Widget *w);
 
+int Fl_send_key(int key, int down);
+
+int Fl_dnd_text_ops(void);
+
+void Fl_set_dnd_text_ops(int flag);
+
+typedef void (*Fl_Screen_Change_Handler)(void *data);
+
+void *Fl_set_screen_change_callback(Fl_Screen_Change_Handler cb, void *data);
+
+int Fl_widget_visible_r(Fl_Widget *w);
+
+void *Fl_Widget_Tracker_new(Fl_Widget *w);
+
+int Fl_Widget_Tracker_deleted(void *self);
+
+void Fl_Widget_Tracker_delete(void *self);
+
+typedef void (*Fl_Context_Help_Handler)(Fl_Widget *w, void *data);
+
+void *Fl_set_context_help_callback(Fl_Context_Help_Handler cb, void *data);
+
+void Fl_start_whats_this(void);
+
 #ifdef __cplusplus
 }
 #endif
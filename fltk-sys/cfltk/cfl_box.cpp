@@ -5,3 +5,9 @@
 #include <new>
 
 WIDGET_DEFINE(Fl_Box)
+
+void Fl_Box_set_deimage(Fl_Box *self, void *image) {
+    LOCK(self->deimage(((Fl_Image *)image)->copy()); self->redraw();)
+}
+
+void *Fl_Box_deimage(const Fl_Box *self) { return (Fl_Image *)self->deimage(); }
@@ -8,6 +8,10 @@ extern "C" {
 
 WIDGET_DECLARE(Fl_Box)
 
+void Fl_Box_set_deimage(Fl_Box *self, void *image);
+
+void *Fl_Box_deimage(const Fl_Box *self);
+
 #ifdef __cplusplus
 }
 #endif
\ No newline at end of file
@@ -21,14 +21,51 @@ GROUP_DEFINE(Fl_Pack)
 
 WIDGET_DEFINE(Fl_Scroll)
 
+int Fl_Scroll_xposition(const Fl_Scroll *self) { return self->xposition(); }
+
+int Fl_Scroll_yposition(const Fl_Scroll *self) { return self->yposition(); }
+
+void Fl_Scroll_scroll_to(Fl_Scroll *self, int x, int y) {
+    LOCK(self->scroll_to(x, y);)
+}
+
+void *Fl_Scroll_scrollbar(Fl_Scroll *self) { return &self->scrollbar; }
+
+void *Fl_Scroll_hscrollbar(Fl_Scroll *self) { return &self->hscrollbar; }
+
+int Fl_Scroll_scrollbar_size(const Fl_Scroll *self) {
+    return self->scrollbar_size();
+}
+
+void Fl_Scroll_set_scrollbar_size(Fl_Scroll *self, int size) {
+    LOCK(self->scrollbar_size(size);)
+}
+
 GROUP_DEFINE(Fl_Scroll)
 
 WIDGET_DEFINE(Fl_Tabs)
 
+Fl_Widget *Fl_Tabs_value(Fl_Tabs *self) { return self->value(); }
+
+int Fl_Tabs_set_value(Fl_Tabs *self, Fl_Widget *w) {
+    int ret = 0;
+    LOCK(ret = self->value(w);)
+    return ret;
+}
+
+void Fl_Tabs_client_area(Fl_Tabs *self, int *rx, int *ry, int *rw, int *rh,
+                         int tabh) {
+    self->client_area(*rx, *ry, *rw, *rh, tabh);
+}
+
 GROUP_DEFINE(Fl_Tabs)
 
 WIDGET_DEFINE(Fl_Tile)
 
+void Fl_Tile_position(Fl_Tile *self, int oldx, int oldy, int newx, int newy) {
+    LOCK(self->position(oldx, oldy, newx, newy);)
+}
+
 GROUP_DEFINE(Fl_Tile)
 
 WIDGET_DEFINE(Fl_Wizard)
@@ -53,7 +90,24 @@ double Fl_Color_Chooser_r(Fl_Color_Chooser *self) { return self->r(); }
 double Fl_Color_Chooser_g(Fl_Color_Chooser *self) { return self->g(); }
 double Fl_Color_Chooser_b(Fl_Color_Chooser *self) { return self->b(); }
 
+int Fl_Color_Chooser_set_rgb(Fl_Color_Chooser *self, double r, double g,
+                             double b) {
+    int ret = 0;
+    LOCK(ret = self->rgb(r, g, b);)
+    return ret;
+}
+
+int Fl_Color_Chooser_mode(Fl_Color_Chooser *self) { return self->mode(); }
+
+void Fl_Color_Chooser_set_mode(Fl_Color_Chooser *self, int mode) {
+    LOCK(self->mode(mode);)
+}
+
 GROUP_DEFINE(Fl_Color_Chooser)
 
 int Fl_Pack_spacing(Fl_Pack *self) { return self->spacing(); }
 void Fl_Pack_set_spacing(Fl_Pack *self,int spacing) { self->spacing(spacing); }
+
+Fl_Group *Fl_Group_current(void) { return Fl_Group::current(); }
+
+void Fl_Group_set_current(Fl_Group *grp) { LOCK(Fl_Group::current(grp);) }
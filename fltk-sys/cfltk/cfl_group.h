@@ -16,7 +16,11 @@ extern "C" {
     void widget##_clear(widget *self);                                         \
     int widget##_children(widget *self);                                       \
     Fl_Widget *widget##_child(widget *, int index);                            \
-    void widget##_resizable(widget *self, void *);
+    void widget##_resizable(widget *self, void *);                             \
+    void widget##_set_clip_children(widget *self, int c);                      \
+    int widget##_clip_children(widget *self);                                  \
+    void widget##_draw_children(widget *self);                                 \
+    void widget##_override_draw(widget *self, int flag);
 
 #define GROUP_DEFINE(widget)                                                   \
     void widget##_begin(widget *self) { LOCK(self->begin();) }                 \
@@ -40,6 +44,21 @@ extern "C" {
     }                                                                          \
     void widget##_resizable(widget *self, void *wid) {                         \
         LOCK(self->resizable((Fl_Widget *)wid);)                               \
+    }                                                                          \
+    void widget##_set_clip_children(widget *self, int c) {                     \
+        LOCK(self->clip_children(c);)                                          \
+    }                                                                          \
+    int widget##_clip_children(widget *self) {                                 \
+        return self->clip_children();                                          \
+    }                                                                          \
+    struct widget##_Draw_Access : public Fl_Group {                            \
+        void draw_children_() { draw_children(); }                             \
+    };                                                                         \
+    void widget##_draw_children(widget *self) {                                \
+        ((widget##_Draw_Access *)(Fl_Group *)self)->draw_children_();          \
+    }                                                                          \
+    void widget##_override_draw(widget *self, int flag) {                      \
+        ((widget##_Derived *)self)->draw_base_ = !flag;                        \
     }
 
 WIDGET_DECLARE(Fl_Group)
@@ -52,14 +71,37 @@ GROUP_DECLARE(Fl_Pack)
 
 WIDGET_DECLARE(Fl_Scroll)
 
+int Fl_Scroll_xposition(const Fl_Scroll *self);
+
+int Fl_Scroll_yposition(const Fl_Scroll *self);
+
+void Fl_Scroll_scroll_to(Fl_Scroll *self, int x, int y);
+
+void *Fl_Scroll_scrollbar(Fl_Scroll *self);
+
+void *Fl_Scroll_hscrollbar(Fl_Scroll *self);
+
+int Fl_Scroll_scrollbar_size(const Fl_Scroll *self);
+
+void Fl_Scroll_set_scrollbar_size(Fl_Scroll *self, int size);
+
 GROUP_DECLARE(Fl_Scroll)
 
 WIDGET_DECLARE(Fl_Tabs)
 
+Fl_Widget *Fl_Tabs_value(Fl_Tabs *self);
+
+int Fl_Tabs_set_value(Fl_Tabs *self, Fl_Widget *w);
+
+void Fl_Tabs_client_area(Fl_Tabs *self, int *rx, int *ry, int *rw, int *rh,
+                         int tabh);
+
 GROUP_DECLARE(Fl_Tabs)
 
 WIDGET_DECLARE(Fl_Tile)
 
+void Fl_Tile_position(Fl_Tile *self, int oldx, int oldy, int newx, int newy);
+
 GROUP_DECLARE(Fl_Tile)
 
 WIDGET_DECLARE(Fl_Wizard)
@@ -82,12 +124,23 @@ double Fl_Color_Chooser_g(Fl_Color_Chooser *self);
 
 double Fl_Color_Chooser_b(Fl_Color_Chooser *self);
 
+int Fl_Color_Chooser_set_rgb(Fl_Color_Chooser *self, double r, double g,
+                             double b);
+
+int Fl_Color_Chooser_mode(Fl_Color_Chooser *self);
+
+void Fl_Color_Chooser_set_mode(Fl_Color_Chooser *self, int mode);
+
 GROUP_DECLARE(Fl_Color_Chooser)
 
 int Fl_Pack_spacing(Fl_Pack *self);
 
 void Fl_Pack_set_spacing(Fl_Pack *self,int spacing);
 
+Fl_Group *Fl_Group_current(void);
+
+void Fl_Group_set_current(Fl_Group *grp);
+
 #ifdef __cplusplus
 }
 #endif
\ No newline at end of file
@@ -9,6 +9,7 @@
 #include <FL/Fl_SVG_Image.H>
 #include <FL/Fl_Shared_Image.H>
 #include <new>
+#include <string.h>
 
 #ifndef LOCK
 #define LOCK(x)                                                                \
@@ -92,6 +93,31 @@ Fl_RGB_Image *Fl_RGB_Image_new(const unsigned char *bits, int W, int H,
     return new (std::nothrow) Fl_RGB_Image(bits, W, H, depth);
 }
 
+int Fl_RGB_Image_depth(Fl_RGB_Image *self) { return self->d(); }
+
+int Fl_RGB_Image_data_w(Fl_RGB_Image *self) { return self->data_w(); }
+
+int Fl_RGB_Image_data_h(Fl_RGB_Image *self) { return self->data_h(); }
+
+int Fl_RGB_Image_line_size(Fl_RGB_Image *self) {
+    return self->ld() ? self->ld() : self->data_w() * self->d();
+}
+
+// Copies borrowed pixel data into the image so it can be modified
+unsigned char *Fl_RGB_Image_pixels(Fl_RGB_Image *self) {
+    if (!self->alloc_array) {
+        int sz = Fl_RGB_Image_line_size(self) * self->data_h();
+        unsigned char *buf = new (std::nothrow) unsigned char[sz];
+        if (!buf)
+            return NULL;
+        memcpy(buf, self->array, sz);
+        LOCK(self->array = buf; self->alloc_array = 1;)
+    }
+    return (unsigned char *)self->array;
+}
+
+void Fl_RGB_Image_uncache(Fl_RGB_Image *self) { LOCK(self->uncache();) }
+
 void Fl_Shared_Image_draw(Fl_Shared_Image *self, int X, int Y, int W, int H) {
     LOCK(self->draw(X, Y, W, H);)
 }
@@ -54,6 +54,18 @@ IMAGE_DECLARE(Fl_RGB_Image)
 Fl_RGB_Image *Fl_RGB_Image_new(const unsigned char *bits, int W, int H,
                                int depth);
 
+int Fl_RGB_Image_depth(Fl_RGB_Image *self);
+
+int Fl_RGB_Image_data_w(Fl_RGB_Image *self);
+
+int Fl_RGB_Image_data_h(Fl_RGB_Image *self);
+
+int Fl_RGB_Image_line_size(Fl_RGB_Image *self);
+
+unsigned char *Fl_RGB_Image_pixels(Fl_RGB_Image *self);
+
+void Fl_RGB_Image_uncache(Fl_RGB_Image *self);
+
 IMAGE_DECLARE(Fl_Shared_Image)
 
 Fl_Shared_Image *Fl_Shared_Image_get(const char *name, int W, int H);
@@ -5,7 +5,12 @@
 #include <FL/Fl_Menu_Bar.H>
 #include <FL/Fl_Menu_Button.H>
 #include <FL/Fl_Menu_Item.H>
+#include <FL/Fl_Multi_Label.H>
+#include <FL/Fl_Sys_Menu_Bar.H>
+#include <FL/fl_draw.H>
 #include <new>
+#include <set>
+#include <string.h>
 
 #define MENU_DEFINE(widget)                                                    \
     void widget##_add(widget *self, const char *name, int shortcut,            \
@@ -31,22 +36,30 @@
     }                                                                          \
     int widget##_text_font(widget *self) { return self->textfont(); }          \
     void widget##_set_text_font(widget *self, int c) {                         \
-        LOCK(self->textfont(c);)                                               \
+        LOCK(self->textfont(c); self->redraw();)                               \
     }                                                                          \
     int widget##_text_size(widget *self) { return self->textsize(); }          \
     void widget##_set_text_size(widget *self, int c) {                         \
-        LOCK(self->textsize(c);)                                               \
+        LOCK(self->textsize(c); self->redraw();)                               \
     }                                                                          \
     unsigned int widget##_text_color(widget *self) {                           \
         return self->textcolor();                                              \
     }                                                                          \
     void widget##_set_text_color(widget *self, unsigned int c) {               \
-        LOCK(self->textcolor(c);)                                              \
+        LOCK(self->textcolor(c); self->redraw();)                              \
     }                                                                          \
     void widget##_add_choice(widget *self, const char *str) {                  \
         LOCK(self->add(str);)                                                  \
     }                                                                          \
-    const char *widget##_get_choice(widget *self) { return self->text(); }     \
+    void widget##_add_item(widget *self, const char *name) {                   \
+        LOCK(self->add(name, 0, NULL, NULL, 0);)                               \
+    }                                                                          \
+    const char *widget##_get_choice(widget *self) {                            \
+        const Fl_Menu_Item *item = self->mvalue();                             \
+        if (!item)                                                             \
+            return NULL;                                                       \
+        return Fl_Menu_Item_label((Fl_Menu_Item *)item);                       \
+    }                                                                          \
     int widget##_value(widget *self) {                                         \
         int ret = 0;                                                           \
         LOCK(ret = self->value();)                                             \
@@ -54,6 +67,12 @@
     }                                                                          \
     int widget##_set_value(widget *self,int v) {                               \
         int ret = 0;                                                           \
+        if (v == -1) {                                                         \
+            LOCK(ret = self->value((const Fl_Menu_Item *)NULL);)               \
+            return ret;                                                        \
+        }                                                                      \
+        if (!self->menu() || v < 0 || v >= self->size() - 1)                   \
+            return 0;                                                          \
         LOCK(ret = self->value(v);)                                            \
         return ret;                                                            \
     }                                                                          \
@@ -62,6 +81,30 @@
         int ret = 0;                                                           \
         LOCK(ret = self->clear_submenu(index));                                \
         return ret;                                                            \
+    }                                                                          \
+    void widget##_remove(widget *self, int idx) {                              \
+        LOCK(self->remove(idx);)                                               \
+    }                                                                          \
+    void widget##_replace(widget *self, int idx, const char *name) {           \
+        LOCK(self->replace(idx, name);)                                        \
+    }                                                                          \
+    int widget##_size(const widget *self) { return self->size(); }             \
+    Fl_Menu_Item *widget##_at(const widget *self, int idx) {                   \
+        if (!self->menu() || idx < 0 || idx >= self->size() - 1)               \
+            return NULL;                                                       \
+        return (Fl_Menu_Item *)&self->menu()[idx];                             \
+    }                                                                          \
+    int widget##_item_pathname(const widget *self, char *buf, int sz,          \
+                               const Fl_Menu_Item *item) {                     \
+        return self->item_pathname(buf, sz, item);                             \
+    }                                                                          \
+    void widget##_picked(widget *self, const Fl_Menu_Item *item) {             \
+        LOCK(self->picked(item);)                                              \
+    }                                                                          \
+    Fl_Menu_Item *widget##_test_shortcut(widget *self) {                       \
+        const Fl_Menu_Item *ret = NULL;                                        \
+        LOCK(ret = self->test_shortcut();)                                     \
+        return (Fl_Menu_Item *)ret;                                            \
     }
 
 WIDGET_DEFINE(Fl_Menu_Bar)
@@ -72,10 +115,30 @@ WIDGET_DEFINE(Fl_Menu_Button)
 
 MENU_DEFINE(Fl_Menu_Button)
 
+Fl_Menu_Item *Fl_Menu_Button_popup(Fl_Menu_Button *self) {
+    const Fl_Menu_Item *ret = NULL;
+    LOCK(ret = self->popup();)
+    return (Fl_Menu_Item *)ret;
+}
+
 WIDGET_DEFINE(Fl_Choice)
 
 MENU_DEFINE(Fl_Choice)
 
+WIDGET_DEFINE(Fl_Sys_Menu_Bar)
+
+MENU_DEFINE(Fl_Sys_Menu_Bar)
+
+void Fl_Sys_Menu_Bar_about(Fl_Sys_Menu_Bar *self, Fl_Callback *cb,
+                           void *data) {
+    (void)self;
+    // The About callback is shared by the menu bars, the previous one is freed
+    static void *about_data = NULL;
+    LOCK(Fl_Sys_Menu_Bar::about(cb, data);)
+    Fl_Widget_free_data(0, about_data);
+    about_data = data;
+}
+
 Fl_Menu_Item *Fl_Menu_Item_new(char **args, int sz) {
     Fl_Menu_Item *items = new (std::nothrow) Fl_Menu_Item[sz + 1];
     if (!items)
@@ -93,13 +156,135 @@ const Fl_Menu_Item *Fl_Menu_Item_popup(Fl_Menu_Item *self, int x, int y) {
     return self->popup(x, y);
 }
 
-const char *Fl_Menu_Item_label(Fl_Menu_Item *self) { return self->label(); }
+const Fl_Menu_Item *Fl_Menu_Item_pulldown(Fl_Menu_Item *self, int x, int y,
+                                          int w, int h,
+                                          const Fl_Menu_Item *picked) {
+    return self->pulldown(x, y, w, h, picked);
+}
+
+void Fl_Menu_Item_do_callback(Fl_Menu_Item *self, Fl_Widget *w) {
+    LOCK(self->do_callback(w);)
+}
+
+// Items with an image carry their text in a multi label, as its non-image part
+static Fl_Multi_Label *menu_item_multi_label(const Fl_Menu_Item *self) {
+    if (self->labeltype_ != _FL_MULTI_LABEL)
+        return NULL;
+    return (Fl_Multi_Label *)self->label();
+}
+
+const char *Fl_Menu_Item_label(Fl_Menu_Item *self) {
+    Fl_Multi_Label *ml = menu_item_multi_label(self);
+    if (ml)
+        return ml->typea == _FL_IMAGE_LABEL ? ml->labelb : ml->labela;
+    return self->label();
+}
+
+// The labels and multi labels allocated below, only these are freed when an
+// item's label is replaced, menus built from static arrays don't own theirs
+static std::set<const void *> &owned_labels() {
+    static std::set<const void *> labels;
+    return labels;
+}
+
+static const char *owned_strdup(const char *a) {
+    const char *ret = strdup(a ? a : "");
+    owned_labels().insert(ret);
+    return ret;
+}
+
+static void free_owned_label(const char *a) {
+    if (a && owned_labels().erase(a))
+        free((void *)a);
+}
+
+// Frees a multi label created by add_image and its image copy, not its text
+static void free_multi_label(Fl_Multi_Label *ml) {
+    if (!owned_labels().erase(ml))
+        return;
+    if (ml->typea == _FL_IMAGE_LABEL)
+        delete (Fl_Image *)ml->labela;
+    else
+        delete (Fl_Image *)ml->labelb;
+    free(ml);
+}
 
 void Fl_Menu_Item_set_label(Fl_Menu_Item *self, const char *a) {
-    LOCK(self->label(a);)
+    // The label is copied, the caller's string is temporary
+    Fl_Multi_Label *ml = menu_item_multi_label(self);
+    if (ml) {
+        const char **text =
+            ml->typea == _FL_IMAGE_LABEL ? &ml->labelb : &ml->labela;
+        const char *old = *text;
+        LOCK(*text = owned_strdup(a);)
+        free_owned_label(old);
+        return;
+    }
+    const char *old = self->label();
+    LOCK(self->label(owned_strdup(a));)
+    free_owned_label(old);
+}
+
+void Fl_Menu_Item_add_image(Fl_Menu_Item *self, void *image, int on_left) {
+    Fl_Multi_Label *old = menu_item_multi_label(self);
+    const char *text = Fl_Menu_Item_label(self);
+    if (!image) {
+        if (old) {
+            LOCK(self->label(FL_NORMAL_LABEL, text);)
+            free_multi_label(old);
+        }
+        return;
+    }
+    // Menus copying their labels free them using free(), multi labels included
+    Fl_Multi_Label *ml =
+        (Fl_Multi_Label *)calloc(1, sizeof(Fl_Multi_Label));
+    if (!ml)
+        return;
+    owned_labels().insert(ml);
+    const char *img = (const char *)((Fl_Image *)image)->copy();
+    // The text of a previous multi label is taken over
+    if (!old)
+        text = owned_strdup(text);
+    if (on_left) {
+        ml->typea = _FL_IMAGE_LABEL;
+        ml->labela = img;
+        ml->typeb = FL_NORMAL_LABEL;
+        ml->labelb = text;
+    } else {
+        ml->typea = FL_NORMAL_LABEL;
+        ml->labela = text;
+        ml->typeb = _FL_IMAGE_LABEL;
+        ml->labelb = img;
+    }
+    LOCK(ml->label(self);)
+    if (old)
+        free_multi_label(old);
+}
+
+void Fl_Menu_Item_release_label(Fl_Menu_Item *self) {
+    Fl_Multi_Label *ml = menu_item_multi_label(self);
+    if (ml) {
+        LOCK(self->label(FL_NORMAL_LABEL, Fl_Menu_Item_label(self));)
+        free_multi_label(ml);
+    }
+    owned_labels().erase(self->label());
+}
+
+int Fl_Menu_Item_has_image(const Fl_Menu_Item *self) {
+    return menu_item_multi_label(self) != NULL ||
+           self->labeltype_ == _FL_IMAGE_LABEL;
 }
 
-int Fl_Menu_Item_label_type(Fl_Menu_Item *self) { return self->labeltype(); }
+// The label type of the items given a drawer, the last one FLTK has room for,
+// so that applications setting FL_FREE_LABELTYPE keep their own
+#define ITEM_DRAWER_LABELTYPE ((Fl_Labeltype)(FL_FREE_LABELTYPE + 7))
+
+int Fl_Menu_Item_label_type(Fl_Menu_Item *self) {
+    // The label type is reserved, these items are reported as custom drawn
+    if (self->labeltype() == ITEM_DRAWER_LABELTYPE)
+        return FL_FREE_LABELTYPE;
+    return self->labeltype();
+}
 
 void Fl_Menu_Item_set_label_type(Fl_Menu_Item *self, int a) {
     LOCK(self->labeltype(static_cast<Fl_Labeltype>(a));)
@@ -142,3 +327,103 @@ int Fl_Menu_Item_active(Fl_Menu_Item *self) { return self->active(); }
 void Fl_Menu_Item_activate(Fl_Menu_Item *self) { LOCK(self->activate();) }
 
 void Fl_Menu_Item_deactivate(Fl_Menu_Item *self) { LOCK(self->deactivate();) }
+
+int Fl_Menu_Item_submenu(Fl_Menu_Item *self) { return self->submenu() != 0; }
+
+Fl_Callback *Fl_Menu_Item_callback(Fl_Menu_Item *self) {
+    return self->callback();
+}
+
+void *Fl_Menu_Item_user_data(Fl_Menu_Item *self) { return self->user_data(); }
+
+void Fl_Menu_Item_set_callback(Fl_Menu_Item *self, Fl_Callback *cb,
+                               void *data) {
+    self->callback(cb, data);
+}
+
+void Fl_Menu_Item_setonly(Fl_Menu_Item *self) { LOCK(self->setonly();) }
+
+int Fl_Menu_Item_flags(const Fl_Menu_Item *self) { return self->flags; }
+
+void Fl_Menu_Item_set_flags(Fl_Menu_Item *self, int flags) {
+    LOCK(self->flags = flags;)
+}
+
+int Fl_Menu_Item_shortcut(const Fl_Menu_Item *self) {
+    return self->shortcut();
+}
+
+void Fl_Menu_Item_set_shortcut(Fl_Menu_Item *self, int shortcut) {
+    LOCK(self->shortcut(shortcut);)
+}
+
+Fl_Menu_Item *Fl_Menu_Item_next(Fl_Menu_Item *self, int idx) {
+    return self->next(idx);
+}
+
+Fl_Menu_Item *Fl_Menu_Item_first_child(Fl_Menu_Item *self) {
+    if (!self->submenu())
+        return NULL;
+    // Submenu pointers store their items in the user data
+    if (self->flags & FL_SUBMENU_POINTER)
+        return (Fl_Menu_Item *)self->user_data();
+    return self + 1;
+}
+
+int Fl_Menu_Item_size(const Fl_Menu_Item *self) { return self->size(); }
+
+Fl_Menu_Item *Fl_Menu_Item_at(Fl_Menu_Item *self, int idx) {
+    return &self[idx];
+}
+
+Fl_Menu_Item *Fl_Menu_Item_from_menu(Fl_Widget *menu) {
+    Fl_Menu_ *m = dynamic_cast<Fl_Menu_ *>(menu);
+    if (!m)
+        return NULL;
+    return (Fl_Menu_Item *)m->mvalue();
+}
+
+Fl_Menu_Item *Fl_Menu_Item_test_shortcut(Fl_Menu_Item *self) {
+    return (Fl_Menu_Item *)self->test_shortcut();
+}
+
+void Fl_Menu_Item_draw(const Fl_Menu_Item *self, int x, int y, int w, int h,
+                       Fl_Widget *menu, int selected) {
+    LOCK(self->draw(x, y, w, h, dynamic_cast<Fl_Menu_ *>(menu), selected);)
+}
+
+int Fl_Menu_Item_measure(const Fl_Menu_Item *self, int *h, Fl_Widget *menu) {
+    int ret = 0;
+    LOCK(ret = self->measure(h, dynamic_cast<Fl_Menu_ *>(menu));)
+    return ret;
+}
+
+static custom_label_draw_callback label_draw_cb = NULL;
+static custom_label_measure_callback label_measure_cb = NULL;
+
+// Labels the callbacks don't handle are drawn as normal labels
+static void label_draw(const Fl_Label *l, int x, int y, int w, int h,
+                       Fl_Align align) {
+    if (label_draw_cb && label_draw_cb(l->value, x, y, w, h, l->color))
+        return;
+    fl_font(l->font, l->size);
+    fl_color((Fl_Color)l->color);
+    fl_draw(l->value, x, y, w, h, align, l->image);
+}
+
+static void label_measure(const Fl_Label *l, int &w, int &h) {
+    if (label_measure_cb && label_measure_cb(l->value, &w, &h))
+        return;
+    fl_font(l->font, l->size);
+    w = 0;
+    fl_measure(l->value, w, h);
+}
+
+void Fl_Menu_Item_set_label_drawer(Fl_Menu_Item *self,
+                                   custom_label_draw_callback draw,
+                                   custom_label_measure_callback measure) {
+    label_draw_cb = draw;
+    label_measure_cb = measure;
+    LOCK(Fl::set_labeltype(ITEM_DRAWER_LABELTYPE, label_draw, label_measure);
+         self->labeltype(ITEM_DRAWER_LABELTYPE);)
+}
@@ -20,14 +20,28 @@ extern "C" {
     unsigned int widget##_text_color(widget *);                                \
     void widget##_set_text_color(widget *, unsigned int c);                    \
     void widget##_add_choice(widget *, const char *);                          \
+    void widget##_add_item(widget *, const char *name);                        \
     const char *widget##_get_choice(widget *);                                 \
     int widget##_value(widget *);                                              \
     int widget##_set_value(widget *,int v);                                    \
     void widget##_clear(widget *);                                             \
-    int widget##_clear_submenu(widget *, int index);
+    int widget##_clear_submenu(widget *, int index);                           \
+    void widget##_remove(widget *, int idx);                                   \
+    void widget##_replace(widget *, int idx, const char *name);                \
+    int widget##_size(const widget *);                                         \
+    Fl_Menu_Item *widget##_at(const widget *, int idx);                        \
+    int widget##_item_pathname(const widget *self, char *buf, int sz,          \
+                               const Fl_Menu_Item *item);                      \
+    void widget##_picked(widget *self, const Fl_Menu_Item *item);              \
+    Fl_Menu_Item *widget##_test_shortcut(widget *self);
 
 typedef struct Fl_Menu_Item Fl_Menu_Item;
 
+typedef int (*custom_label_draw_callback)(const char *label, int x, int y,
+                                          int w, int h, unsigned int color);
+typedef int (*custom_label_measure_callback)(const char *label, int *w,
+                                             int *h);
+
 WIDGET_DECLARE(Fl_Menu_Bar)
 
 MENU_DECLARE(Fl_Menu_Bar)
@@ -36,20 +50,41 @@ WIDGET_DECLARE(Fl_Menu_Button)
 
 MENU_DECLARE(Fl_Menu_Button)
 
+Fl_Menu_Item *Fl_Menu_Button_popup(Fl_Menu_Button *self);
+
 WIDGET_DECLARE(Fl_Choice)
 
 MENU_DECLARE(Fl_Choice)
 
+WIDGET_DECLARE(Fl_Sys_Menu_Bar)
+
+MENU_DECLARE(Fl_Sys_Menu_Bar)
+
+void Fl_Sys_Menu_Bar_about(Fl_Sys_Menu_Bar *self, Fl_Callback *cb,
+                           void *data);
+
 Fl_Menu_Item *Fl_Menu_Item_new(char **args, int sz);
 
 void Fl_Menu_Item_delete(Fl_Menu_Item *self);
 
 const Fl_Menu_Item *Fl_Menu_Item_popup(Fl_Menu_Item *self, int x, int y);
 
+const Fl_Menu_Item *Fl_Menu_Item_pulldown(Fl_Menu_Item *self, int x, int y,
+                                          int w, int h,
+                                          const Fl_Menu_Item *picked);
+
+void Fl_Menu_Item_do_callback(Fl_Menu_Item *self, Fl_Widget *w);
+
 const char *Fl_Menu_Item_label(Fl_Menu_Item *);
 
 void Fl_Menu_Item_set_label(Fl_Menu_Item *, const char *a);
 
+void Fl_Menu_Item_add_image(Fl_Menu_Item *self, void *image, int on_left);
+
+void Fl_Menu_Item_release_label(Fl_Menu_Item *self);
+
+int Fl_Menu_Item_has_image(const Fl_Menu_Item *self);
+
 int Fl_Menu_Item_label_type(Fl_Menu_Item *);
 
 void Fl_Menu_Item_set_label_type(Fl_Menu_Item *, int a);
@@ -84,6 +119,45 @@ void Fl_Menu_Item_activate(Fl_Menu_Item *);
 
 void Fl_Menu_Item_deactivate(Fl_Menu_Item *);
 
+int Fl_Menu_Item_submenu(Fl_Menu_Item *);
+
+Fl_Callback *Fl_Menu_Item_callback(Fl_Menu_Item *self);
+
+void *Fl_Menu_Item_user_data(Fl_Menu_Item *self);
+
+void Fl_Menu_Item_set_callback(Fl_Menu_Item *self, Fl_Callback *cb, void *data);
+
+void Fl_Menu_Item_setonly(Fl_Menu_Item *self);
+
+int Fl_Menu_Item_flags(const Fl_Menu_Item *self);
+
+void Fl_Menu_Item_set_flags(Fl_Menu_Item *self, int flags);
+
+int Fl_Menu_Item_shortcut(const Fl_Menu_Item *self);
+
+void Fl_Menu_Item_set_shortcut(Fl_Menu_Item *self, int shortcut);
+
+Fl_Menu_Item *Fl_Menu_Item_next(Fl_Menu_Item *self, int idx);
+
+Fl_Menu_Item *Fl_Menu_Item_first_child(Fl_Menu_Item *self);
+
+int Fl_Menu_Item_size(const Fl_Menu_Item *self);
+
+Fl_Menu_Item *Fl_Menu_Item_at(Fl_Menu_Item *self, int idx);
+
+Fl_Menu_Item *Fl_Menu_Item_from_menu(Fl_Widget *menu);
+
+Fl_Menu_Item *Fl_Menu_Item_test_shortcut(Fl_Menu_Item *self);
+
+void Fl_Menu_Item_draw(const Fl_Menu_Item *self, int x, int y, int w, int h,
+                       Fl_Widget *menu, int selected);
+
+int Fl_Menu_Item_measure(const Fl_Menu_Item *self, int *h, Fl_Widget *menu);
+
+void Fl_Menu_Item_set_label_drawer(Fl_Menu_Item *self,
+                                   custom_label_draw_callback draw,
+                                   custom_label_measure_callback measure);
+
 int Fl_Choice_value(Fl_Choice *self);
 
 int Fl_Choice_set_value(Fl_Choice *self, int val);
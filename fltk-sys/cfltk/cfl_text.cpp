@@ -1,12 +1,259 @@
 #include "cfl_text.h"
 #include <FL/Fl.H>
 #include <FL/Fl_Image.H>
+#include <FL/Fl_Scrollbar.H>
 #include <FL/Fl_Simple_Terminal.H>
 #include <FL/Fl_Text_Buffer.H>
 #include <FL/Fl_Text_Display.H>
 #include <FL/Fl_Text_Editor.H>
 #include <FL/Fl_Widget.H>
+#include <FL/fl_draw.H>
+#include <climits>
+#include <map>
 #include <new>
+#include <tuple>
+#include <vector>
+
+// Displays keep a pointer to their style table, so it's kept alive until
+// replaced
+static std::map<const Fl_Text_Display *, Fl_Text_Display::Style_Table_Entry *>
+    ext_style_tables;
+
+// The display holds a reference to its style buffer, released when replaced
+static std::map<const Fl_Text_Display *, Fl_Text_Buffer *> style_buffers;
+
+// Backgrounds of the secondary selection and of highlighted text, the display
+// doesn't draw the former and blends the latter from its selection color
+static std::map<const Fl_Text_Display *, Fl_Color> secondary_colors;
+static std::map<const Fl_Text_Display *, Fl_Color> highlight_colors;
+
+// Style tables of terminals appending styled text
+typedef std::vector<Fl_Text_Display::Style_Table_Entry> Style_Table;
+static std::map<const Fl_Text_Display *, Style_Table> terminal_styles;
+
+static void set_highlight_data(Fl_Text_Display *self, Fl_Text_Buffer *sbuf,
+                               Fl_Text_Display::Style_Table_Entry *stable,
+                               int sz) {
+    Fl_Text_Buffer_retain(sbuf);
+    self->highlight_data(sbuf, stable, sz, 'A', 0, 0);
+    Fl_Text_Buffer *old = style_buffers[self];
+    style_buffers[self] = sbuf;
+    if (old)
+        Fl_Text_Buffer_delete(old);
+}
+
+static void remove_key_bindings(Fl_Text_Editor *self);
+static void remove_protections(Fl_Text_Editor *self);
+
+// Releases the buffers and the style table held by a deleted display, the
+// displayed buffer is detached first since the display's destructor uses it
+static void display_deleted(Fl_Widget *w) {
+    Fl_Text_Display *self = dynamic_cast<Fl_Text_Display *>(w);
+    if (!self)
+        return;
+    Fl_Text_Buffer *buf = self->buffer();
+    if (buf) {
+        self->buffer(NULL);
+        Fl_Text_Buffer_delete(buf);
+    }
+    auto it = style_buffers.find(self);
+    if (it != style_buffers.end()) {
+        Fl_Text_Buffer_delete(it->second);
+        style_buffers.erase(it);
+    }
+    auto table = ext_style_tables.find(self);
+    if (table != ext_style_tables.end()) {
+        delete[] table->second;
+        ext_style_tables.erase(table);
+    }
+    secondary_colors.erase(self);
+    highlight_colors.erase(self);
+    terminal_styles.erase(self);
+    Fl_Text_Editor *editor = dynamic_cast<Fl_Text_Editor *>(self);
+    if (editor) {
+        remove_key_bindings(editor);
+        remove_protections(editor);
+    }
+}
+
+[[maybe_unused]] static int display_observer =
+    Fl_Widget_add_delete_observer(display_deleted);
+
+static void set_style_table_ext(Fl_Text_Display *self, Fl_Text_Buffer *sbuf,
+                                unsigned int *color, int *font, int *fontsz,
+                                unsigned int *attr, unsigned int *bgcolor,
+                                int sz) {
+    Fl_Text_Display::Style_Table_Entry *stable =
+        new (std::nothrow) Fl_Text_Display::Style_Table_Entry[sz];
+    if (!stable)
+        return;
+    for (int i = 0; i < sz; ++i) {
+        stable[i].color = color[i];
+        stable[i].font = font[i];
+        stable[i].size = fontsz[i];
+        stable[i].attr = attr[i];
+        stable[i].bgcolor = bgcolor[i];
+    }
+    set_highlight_data(self, sbuf, stable, sz);
+    delete[] ext_style_tables[self];
+    ext_style_tables[self] = stable;
+}
+
+// Gives access to the protected layout of Fl_Text_Display
+struct Fl_Text_Display_Layout : public Fl_Text_Display {
+    // Returns the start of the line shown at y if x is within the line number
+    // area, otherwise -1
+    static int linenumber_position(const Fl_Text_Display *self, int x, int y) {
+        const auto &area = self->*(&Fl_Text_Display_Layout::text_area);
+        Fl_Scrollbar *vscroll = self->*(&Fl_Text_Display_Layout::mVScrollBar);
+        if (self->linenumber_width() <= 0 || x >= area.x || y < area.y ||
+            y >= area.y + area.h)
+            return -1;
+        if (vscroll->visible() && x >= vscroll->x() &&
+            x < vscroll->x() + vscroll->w())
+            return -1;
+        int pos = (self->*(&Fl_Text_Display_Layout::xy_to_position))(
+            area.x, y, CURSOR_POS);
+        return self->line_start(pos);
+    }
+
+    // highlight_data() doesn't accept a null style buffer
+    static void reset_styles(Fl_Text_Display *self) {
+        self->*(&Fl_Text_Display_Layout::mStyleBuffer) = NULL;
+        self->*(&Fl_Text_Display_Layout::mStyleTable) = NULL;
+        self->*(&Fl_Text_Display_Layout::mNStyles) = 0;
+    }
+
+    // Redraws the visible text of the spans with the given background, going
+    // once over the visible lines and redrawing only their parts in a span
+    static void draw_spans(Fl_Text_Display *self,
+                           const std::vector<std::pair<int, int>> &spans,
+                           Fl_Color bg) {
+        const auto &area = self->*(&Fl_Text_Display_Layout::text_area);
+        int height = self->*(&Fl_Text_Display_Layout::mMaxsFontHeight);
+        int lines = self->*(&Fl_Text_Display_Layout::mNVisibleLines);
+        const int *starts = self->*(&Fl_Text_Display_Layout::mLineStarts);
+        int len = self->buffer()->length();
+        int right = area.x + area.w;
+        if (spans.empty() || !starts || height <= 0)
+            return;
+        Fl_Color color = self->color();
+        Fl_Color selection = self->selection_color();
+        self->color(bg);
+        self->selection_color(bg);
+        for (int i = 0; i < lines && starts[i] != -1; ++i) {
+            int line_start = starts[i];
+            int next = i + 1 < lines && starts[i + 1] != -1 ? starts[i + 1]
+                                                             : len + 1;
+            int y = area.y + i * height;
+            for (auto &span : spans) {
+                if (span.second <= line_start || span.first >= next)
+                    continue;
+                int x = area.x, x_end = right, unused;
+                if (span.first > line_start &&
+                    !self->position_to_xy(span.first, &x, &unused))
+                    continue;
+                if (span.second < next &&
+                    !self->position_to_xy(span.second, &x_end, &unused))
+                    x_end = right;
+                x = x < area.x ? area.x : x;
+                x_end = x_end > right ? right : x_end;
+                if (x_end <= x)
+                    continue;
+                fl_push_clip(x, y, x_end - x, height);
+                (self->*(&Fl_Text_Display_Layout::draw_vline))(i, x, x_end, 0,
+                                                               INT_MAX);
+                fl_pop_clip();
+            }
+        }
+        self->color(color);
+        self->selection_color(selection);
+    }
+};
+
+// Removes the style buffer and table of the display, releasing them
+static void clear_highlight_data(Fl_Text_Display *self) {
+    Fl_Text_Display_Layout::reset_styles(self);
+    auto it = style_buffers.find(self);
+    if (it != style_buffers.end()) {
+        Fl_Text_Buffer_delete(it->second);
+        style_buffers.erase(it);
+    }
+    auto table = ext_style_tables.find(self);
+    if (table != ext_style_tables.end()) {
+        delete[] table->second;
+        ext_style_tables.erase(table);
+    }
+    self->redraw();
+}
+
+// Removes the text from start to end out of the spans
+static void subtract_span(std::vector<std::pair<int, int>> &spans, int start,
+                          int end) {
+    std::vector<std::pair<int, int>> rest;
+    for (auto &span : spans) {
+        if (end <= span.first || start >= span.second) {
+            rest.push_back(span);
+            continue;
+        }
+        if (span.first < start)
+            rest.push_back(std::make_pair(span.first, start));
+        if (end < span.second)
+            rest.push_back(std::make_pair(end, span.second));
+    }
+    spans.swap(rest);
+}
+
+// Draws the secondary selection and the highlighted text of displays having
+// colors set for them, the primary selection is left as drawn
+static void display_drawn(Fl_Widget *w) {
+    if (secondary_colors.empty() && highlight_colors.empty())
+        return;
+    Fl_Text_Display *self = dynamic_cast<Fl_Text_Display *>(w);
+    if (!self || !self->buffer())
+        return;
+    Fl_Text_Buffer *buf = self->buffer();
+    int start, end, hl_start, hl_end;
+    int selected = buf->selection_position(&start, &end);
+    int highlighted = buf->highlight_position(&hl_start, &hl_end);
+    auto secondary = secondary_colors.find(self);
+    int sec_start, sec_end;
+    if (secondary != secondary_colors.end() &&
+        buf->secondary_selection_position(&sec_start, &sec_end)) {
+        std::vector<std::pair<int, int>> spans{
+            std::make_pair(sec_start, sec_end)};
+        if (selected)
+            subtract_span(spans, start, end);
+        if (highlighted)
+            subtract_span(spans, hl_start, hl_end);
+        Fl_Text_Display_Layout::draw_spans(self, spans, secondary->second);
+    }
+    auto highlight = highlight_colors.find(self);
+    if (highlight != highlight_colors.end() && highlighted) {
+        std::vector<std::pair<int, int>> spans{
+            std::make_pair(hl_start, hl_end)};
+        if (selected)
+            subtract_span(spans, start, end);
+        Fl_Text_Display_Layout::draw_spans(self, spans, highlight->second);
+    }
+}
+
+[[maybe_unused]] static int display_draw_observer =
+    Fl_Widget_add_draw_observer(display_drawn);
+
+static void set_range_color(
+    std::map<const Fl_Text_Display *, Fl_Color> &colors,
+    Fl_Text_Display *self, Fl_Color color) {
+    colors[self] = color;
+    self->redraw();
+}
+
+static Fl_Color range_color(
+    const std::map<const Fl_Text_Display *, Fl_Color> &colors,
+    const Fl_Text_Display *self, Fl_Color fallback) {
+    auto it = colors.find(self);
+    return it != colors.end() ? it->second : fallback;
+}
 
 #define DISPLAY_DEFINE(widget)                                                 \
     int widget##_text_font(const widget *self) { return self->textfont(); }    \
@@ -75,10 +322,18 @@
         for (int i = 0; i < sz; ++i) {                                         \
             stable[i] = {color[i], font[i], fontsz[i]};                        \
         }                                                                      \
-        LOCK(self->highlight_data((Fl_Text_Buffer *)sbuff, stable, sz, 'A', 0, \
-                                  0);)                                         \
+        LOCK(set_highlight_data(self, (Fl_Text_Buffer *)sbuff, stable, sz);)   \
         delete[] stable;                                                       \
     }                                                                          \
+    void widget##_set_style_table_entry_ext(                                   \
+        widget *self, void *sbuff, unsigned int *color, int *font,             \
+        int *fontsz, unsigned int *attr, unsigned int *bgcolor, int sz) {      \
+        LOCK(set_style_table_ext(self, (Fl_Text_Buffer *)sbuff, color, font,   \
+                                 fontsz, attr, bgcolor, sz);)                  \
+    }                                                                          \
+    void widget##_clear_style_table(widget *self) {                            \
+        LOCK(clear_highlight_data(self);)                                      \
+    }                                                                          \
     void widget##_set_cursor_style(widget *self, int style) {                  \
         LOCK(self->cursor_style(style);)                                       \
     }                                                                          \
@@ -177,13 +432,72 @@
     }                                                                          \
     int widget##_in_selection(const widget *self, int x, int y) {              \
         return self->in_selection(x, y);                                       \
+    }                                                                          \
+    int widget##_linenumber_position(const widget *self, int x, int y) {       \
+        return Fl_Text_Display_Layout::linenumber_position(self, x, y);        \
+    }                                                                          \
+    void widget##_show_insert_position(widget *self) {                         \
+        LOCK(self->show_insert_position();)                                    \
+    }                                                                          \
+    void widget##_set_secondary_selection_color(widget *self,                  \
+                                                unsigned int color) {          \
+        LOCK(set_range_color(secondary_colors, self, color);)                  \
+    }                                                                          \
+    unsigned int widget##_secondary_selection_color(const widget *self) {      \
+        return range_color(secondary_colors, self, self->color());             \
+    }                                                                          \
+    void widget##_set_highlight_color(widget *self, unsigned int color) {      \
+        LOCK(set_range_color(highlight_colors, self, color);)                  \
+    }                                                                          \
+    unsigned int widget##_highlight_color(const widget *self) {                \
+        return range_color(                                                    \
+            highlight_colors, self,                                            \
+            fl_color_average(self->color(), self->selection_color(), 0.5f));   \
     }
 
+struct Line_Ending_Data {
+    int ending;
+    Fl_Text_Buffer_Line_Ending_Cb cb;
+    void *data;
+};
+
+static std::map<const Fl_Text_Buffer *, Line_Ending_Data> line_endings;
+
+// Reference counts of the buffers created through Fl_Text_Buffer_new, shared
+// between the Rust handles and the widgets displaying the buffers
+static std::map<const Fl_Text_Buffer *, int> buffer_refs;
+
 Fl_Text_Buffer *Fl_Text_Buffer_new(void) {
-    return new (std::nothrow) Fl_Text_Buffer;
+    Fl_Text_Buffer *buf = new (std::nothrow) Fl_Text_Buffer;
+    if (buf) {
+        LOCK(buffer_refs[buf] = 1;)
+    }
+    return buf;
+}
+
+void Fl_Text_Buffer_retain(Fl_Text_Buffer *self) {
+    auto it = buffer_refs.find(self);
+    if (it == buffer_refs.end())
+        return;
+    LOCK(it->second++;)
+}
+
+int Fl_Text_Buffer_refcount(const Fl_Text_Buffer *self) {
+    auto it = buffer_refs.find(self);
+    if (it == buffer_refs.end())
+        return 0;
+    return it->second;
 }
 
-void Fl_Text_Buffer_delete(Fl_Text_Buffer *self) { delete self; }
+void Fl_Text_Buffer_delete(Fl_Text_Buffer *self) {
+    auto it = buffer_refs.find(self);
+    if (it == buffer_refs.end())
+        return;
+    LOCK(it->second--;)
+    if (it->second > 0)
+        return;
+    LOCK(buffer_refs.erase(it); line_endings.erase(self); delete self;)
+}
 
 const char *Fl_Text_Buffer_text(Fl_Text_Buffer *self) { return self->text(); }
 
@@ -233,6 +547,10 @@ int Fl_Text_Buffer_loadfile(Fl_Text_Buffer *self, const char *file,
     return self->loadfile(file, 128 * 1024);
 }
 
+int Fl_Text_Buffer_savefile(Fl_Text_Buffer *self, const char *file) {
+    return self->savefile(file);
+}
+
 int Fl_Text_Buffer_tab_distance(const Fl_Text_Buffer *self) {
     return self->tab_distance();
 }
@@ -256,6 +574,20 @@ int Fl_Text_Buffer_selection_position(Fl_Text_Buffer *self, int *start,
     return self->selection_position(start, end);
 }
 
+void Fl_Text_Buffer_secondary_select(Fl_Text_Buffer *self, int start,
+                                     int end) {
+    LOCK(self->secondary_select(start, end);)
+}
+
+void Fl_Text_Buffer_secondary_unselect(Fl_Text_Buffer *self) {
+    LOCK(self->secondary_unselect();)
+}
+
+int Fl_Text_Buffer_secondary_selection_position(Fl_Text_Buffer *self,
+                                                int *start, int *end) {
+    return self->secondary_selection_position(start, end);
+}
+
 char *Fl_Text_Buffer_selection_text(Fl_Text_Buffer *self) {
     return self->selection_text();
 }
@@ -297,6 +629,14 @@ int Fl_Text_Buffer_line_start(const Fl_Text_Buffer *self, int pos) {
     return self->line_start(pos);
 }
 
+int Fl_Text_Buffer_line_end(const Fl_Text_Buffer *self, int pos) {
+    return self->line_end(pos);
+}
+
+int Fl_Text_Buffer_skip_lines(Fl_Text_Buffer *self, int startPos, int nLines) {
+    return self->skip_lines(startPos, nLines);
+}
+
 int Fl_Text_Buffer_word_start(const Fl_Text_Buffer *self, int pos) {
     return self->word_start(pos);
 }
@@ -310,6 +650,74 @@ int Fl_Text_Buffer_count_lines(const Fl_Text_Buffer *self, int startPos,
     return self->count_lines(startPos, endPos);
 }
 
+int Fl_Text_Buffer_utf8_align(const Fl_Text_Buffer *self, int pos) {
+    return self->utf8_align(pos);
+}
+
+int Fl_Text_Buffer_char_count(const Fl_Text_Buffer *self) {
+    return Fl_Text_Buffer_byte_to_char(self, self->length());
+}
+
+int Fl_Text_Buffer_char_to_byte(const Fl_Text_Buffer *self, int idx) {
+    int len = self->length();
+    int pos = 0;
+    for (int i = 0; i < idx && pos < len; ++i)
+        pos = self->next_char(pos);
+    return pos;
+}
+
+// Gives access to the protected gap buffer of Fl_Text_Buffer
+struct Fl_Text_Buffer_Gap : public Fl_Text_Buffer {
+    static void halves(const Fl_Text_Buffer *self, const char **first,
+                       int *first_len, const char **second,
+                       int *second_len) {
+        const char *buf = self->*(&Fl_Text_Buffer_Gap::mBuf);
+        int gap_start = self->*(&Fl_Text_Buffer_Gap::mGapStart);
+        int gap_end = self->*(&Fl_Text_Buffer_Gap::mGapEnd);
+        *first = buf;
+        *first_len = gap_start;
+        *second = buf + gap_end;
+        *second_len = self->length() - gap_start;
+    }
+    static const char *contiguous(Fl_Text_Buffer *self) {
+        (self->*(&Fl_Text_Buffer_Gap::move_gap))(self->length());
+        return self->*(&Fl_Text_Buffer_Gap::mBuf);
+    }
+};
+
+// Returns the text before and after the gap of the buffer, read in place and
+// valid until the buffer is modified
+void Fl_Text_Buffer_gap_halves(const Fl_Text_Buffer *self, const char **first,
+                               int *first_len, const char **second,
+                               int *second_len) {
+    Fl_Text_Buffer_Gap::halves(self, first, first_len, second, second_len);
+}
+
+// Moves the gap of the buffer to its end and returns its text in place,
+// without a null terminator and valid until the buffer is modified
+const char *Fl_Text_Buffer_contiguous(Fl_Text_Buffer *self) {
+    const char *ret = NULL;
+    LOCK(ret = Fl_Text_Buffer_Gap::contiguous(self));
+    return ret;
+}
+
+// Counts the bytes starting a UTF-8 sequence before pos
+// Positions within a character are clamped to the start of that character
+int Fl_Text_Buffer_byte_to_char(const Fl_Text_Buffer *self, int pos) {
+    int len = self->length();
+    if (pos > len)
+        pos = len;
+    while (pos > 0 && pos < len &&
+           ((unsigned char)self->byte_at(pos) & 0xC0) == 0x80)
+        pos--;
+    int count = 0;
+    for (int i = 0; i < pos; ++i) {
+        if (((unsigned char)self->byte_at(i) & 0xC0) != 0x80)
+            count++;
+    }
+    return count;
+}
+
 void Fl_Text_Buffer_add_modify_callback(Fl_Text_Buffer *self,
                                         Fl_Text_Modify_Cb bufModifiedCB,
                                         void *cbArg) {
@@ -326,11 +734,46 @@ void Fl_Text_Buffer_call_modify_callbacks(Fl_Text_Buffer *self) {
     LOCK(self->call_modify_callbacks();)
 }
 
+int Fl_Text_Buffer_line_ending(const Fl_Text_Buffer *self) {
+    auto it = line_endings.find(self);
+    if (it == line_endings.end())
+        return 0;
+    return it->second.ending;
+}
+
+void Fl_Text_Buffer_set_line_ending(Fl_Text_Buffer *self, int ending) {
+    Line_Ending_Data &d = line_endings[self];
+    if (d.ending == ending)
+        return;
+    LOCK(d.ending = ending;)
+    if (d.cb)
+        d.cb(ending, d.data);
+}
+
+void *Fl_Text_Buffer_set_line_ending_callback(Fl_Text_Buffer *self,
+                                              Fl_Text_Buffer_Line_Ending_Cb cb,
+                                              void *data) {
+    Line_Ending_Data &d = line_endings[self];
+    void *old = d.data;
+    LOCK(d.cb = cb; d.data = data;)
+    return old;
+}
+
+// The display holds a reference to its buffer, released when replaced
+static void replace_buffer(Fl_Text_Display *self, Fl_Text_Buffer *buf) {
+    Fl_Text_Buffer *old = self->buffer();
+    if (old == buf)
+        return;
+    Fl_Text_Buffer_retain(buf);
+    self->buffer(buf);
+    if (old)
+        Fl_Text_Buffer_delete(old);
+}
+
 WIDGET_DEFINE(Fl_Text_Display)
 
 void Fl_Text_Display_init(Fl_Text_Display *self) {
-    Fl_Text_Buffer *buff = new (std::nothrow) Fl_Text_Buffer();
-    self->buffer(buff);
+    self->buffer(Fl_Text_Buffer_new());
 }
 
 Fl_Text_Buffer *Fl_Text_Display_get_buffer(Fl_Text_Display *self) {
@@ -338,7 +781,7 @@ Fl_Text_Buffer *Fl_Text_Display_get_buffer(Fl_Text_Display *self) {
 }
 
 void Fl_Text_Display_set_buffer(Fl_Text_Display *self, Fl_Text_Buffer *buf) {
-    LOCK(self->buffer(buf);)
+    LOCK(replace_buffer(self, buf);)
 }
 
 DISPLAY_DEFINE(Fl_Text_Display)
@@ -346,8 +789,7 @@ DISPLAY_DEFINE(Fl_Text_Display)
 WIDGET_DEFINE(Fl_Text_Editor)
 
 void Fl_Text_Editor_init(Fl_Text_Editor *self) {
-    Fl_Text_Buffer *buff = new (std::nothrow) Fl_Text_Buffer();
-    self->buffer(buff);
+    self->buffer(Fl_Text_Buffer_new());
 }
 
 Fl_Text_Buffer *Fl_Text_Editor_get_buffer(Fl_Text_Editor *self) {
@@ -355,7 +797,9 @@ Fl_Text_Buffer *Fl_Text_Editor_get_buffer(Fl_Text_Editor *self) {
 }
 
 void Fl_Text_Editor_set_buffer(Fl_Text_Editor *self, Fl_Text_Buffer *buf) {
-    LOCK(self->buffer(buf);)
+    if (self->buffer() != buf) {
+        LOCK(remove_protections(self); replace_buffer(self, buf);)
+    }
 }
 
 DISPLAY_DEFINE(Fl_Text_Editor)
@@ -384,11 +828,233 @@ int kf_undo(Fl_Text_Editor *e) {
     return ret;
 }
 
+struct Key_Binding_Data {
+    Fl_Text_Editor_Key_Func cb;
+    void *data;
+};
+
+static std::map<std::tuple<Fl_Text_Editor *, int, int>, Key_Binding_Data>
+    key_bindings;
+
+// Fl_Text_Editor::Key_Func carries no user data, so dispatch through a lookup
+static int key_binding_trampoline(int key, Fl_Text_Editor *e) {
+    int state = Fl::event_state() & (FL_SHIFT | FL_CTRL | FL_ALT | FL_META);
+    auto it = key_bindings.find(std::make_tuple(e, key, state));
+    if (it == key_bindings.end())
+        it = key_bindings.find(
+            std::make_tuple(e, key, (int)FL_TEXT_EDITOR_ANY_STATE));
+    if (it == key_bindings.end())
+        return 0;
+    return it->second.cb(key, e, it->second.data);
+}
+
+void *Fl_Text_Editor_add_key_binding(Fl_Text_Editor *self, int key, int state,
+                                     Fl_Text_Editor_Key_Func cb, void *data) {
+    if (!cb || !data)
+        return NULL;
+    void *prev = NULL;
+    Key_Binding_Data binding = {cb, data};
+    LOCK(auto it = key_bindings.find(std::make_tuple(self, key, state));
+         if (it != key_bindings.end()) prev = it->second.data;
+         key_bindings[std::make_tuple(self, key, state)] = binding;
+         self->remove_key_binding(key, state);
+         self->add_key_binding(key, state, key_binding_trampoline);)
+    return prev;
+}
+
+// Binds the key back to the function FLTK binds it to by default, if any
+static void restore_default_binding(Fl_Text_Editor *self, int key, int state) {
+    Fl_Text_Editor::Key_Binding *defaults = NULL;
+    self->add_default_key_bindings(&defaults);
+    Fl_Text_Editor::Key_Func f = self->bound_key_function(key, state, defaults);
+    self->remove_all_key_bindings(&defaults);
+    self->remove_key_binding(key, state);
+    if (f)
+        self->add_key_binding(key, state, f);
+}
+
+void *Fl_Text_Editor_remove_key_binding(Fl_Text_Editor *self, int key,
+                                        int state) {
+    void *data = NULL;
+    LOCK(auto it = key_bindings.find(std::make_tuple(self, key, state));
+         if (it != key_bindings.end()) {
+             data = it->second.data;
+             key_bindings.erase(it);
+             restore_default_binding(self, key, state);
+         })
+    return data;
+}
+
+// Frees the closures bound to the keys of a deleted editor
+static void remove_key_bindings(Fl_Text_Editor *self) {
+    for (auto it = key_bindings.begin(); it != key_bindings.end();) {
+        if (std::get<0>(it->first) == self) {
+            Fl_Widget_free_data(7, it->second.data);
+            it = key_bindings.erase(it);
+        } else {
+            ++it;
+        }
+    }
+}
+
+struct Protected_Range {
+    int start;
+    int end;
+};
+
+struct Protection_Data {
+    Fl_Text_Buffer *buf;
+    std::vector<Protected_Range> ranges;
+};
+
+static std::map<Fl_Text_Editor *, Protection_Data *> protections;
+
+// Maps a position from before a modification of the buffer to after it
+static int shift_position(int p, int pos, int nInserted, int nDeleted,
+                          bool is_end) {
+    if (p > pos + nDeleted)
+        p -= nDeleted;
+    else if (p > pos)
+        p = pos;
+    if (p > pos || (p == pos && !is_end))
+        p += nInserted;
+    return p;
+}
+
+// Makes the protected ranges follow the text they cover
+static void protection_cb(int pos, int nInserted, int nDeleted, int nRestyled,
+                          const char *deletedText, void *cbArg) {
+    Protection_Data *d = (Protection_Data *)cbArg;
+    if (!nInserted && !nDeleted)
+        return;
+    for (auto &r : d->ranges) {
+        r.start = shift_position(r.start, pos, nInserted, nDeleted, false);
+        r.end = shift_position(r.end, pos, nInserted, nDeleted, true);
+        if (r.end < r.start)
+            r.end = r.start;
+    }
+}
+
+static bool touches_protection(const Protection_Data *d, int start, int end) {
+    for (auto &r : d->ranges) {
+        if (start == end ? (start > r.start && start < r.end)
+                         : (start < r.end && end > r.start))
+            return true;
+    }
+    return false;
+}
+
+// Returns the key function the editor runs for a key press, text input being
+// handled like kf_default
+static Fl_Text_Editor::Key_Func key_function(Fl_Text_Editor *self) {
+    int key = Fl::event_key();
+    int state = Fl::event_state() & (FL_SHIFT | FL_CTRL | FL_ALT | FL_META);
+    unsigned char c = (unsigned char)Fl::event_text()[0];
+    if (Fl::event_length() && c >= ' ' && c != 0x7f &&
+        !(state & (FL_CTRL | FL_META)))
+        return Fl_Text_Editor::kf_default;
+    Fl_Text_Editor::Key_Func f =
+        self->bound_key_function(key, state, self->global_key_bindings);
+    if (!f)
+        f = self->bound_key_function(key, state, self->key_bindings);
+    if (!f && !state)
+        f = Fl_Text_Editor::kf_default;
+    return f;
+}
+
+// Whether a key press or paste would edit protected text, such events are
+// rejected before the editor handles them
+int Fl_Text_Editor_edits_protected(Fl_Text_Editor *self, int event) {
+    auto it = protections.find(self);
+    Fl_Text_Buffer *buf = self->buffer();
+    if (it == protections.end() || it->second->buf != buf ||
+        (event != FL_KEYBOARD && event != FL_PASTE))
+        return 0;
+    const Protection_Data *d = it->second;
+    Fl_Text_Editor::Key_Func f =
+        event == FL_PASTE ? Fl_Text_Editor::kf_paste : key_function(self);
+    int pos = self->insert_position();
+    int start = 0, end = 0;
+    bool selected = buf->selection_position(&start, &end) && start < end;
+    if (selected && touches_protection(d, start, end) &&
+        (f == Fl_Text_Editor::kf_default || f == Fl_Text_Editor::kf_enter ||
+         f == Fl_Text_Editor::kf_paste || f == Fl_Text_Editor::kf_cut ||
+         f == Fl_Text_Editor::kf_backspace || f == Fl_Text_Editor::kf_delete))
+        return 1;
+    if (f == Fl_Text_Editor::kf_undo)
+        return !d->ranges.empty();
+    if (f == Fl_Text_Editor::kf_default && !self->insert_mode() &&
+        !selected && pos < buf->line_end(pos))
+        return touches_protection(d, pos, buf->next_char(pos));
+    if (f == Fl_Text_Editor::kf_default || f == Fl_Text_Editor::kf_enter ||
+        f == Fl_Text_Editor::kf_paste)
+        return touches_protection(d, pos, pos);
+    if (selected)
+        return 0;
+    bool word = Fl::event_state(FL_CTRL) != 0;
+    if (f == Fl_Text_Editor::kf_backspace && pos > 0) {
+        int from = buf->prev_char(pos);
+        return touches_protection(d, word ? buf->word_start(from) : from, pos);
+    }
+    if (f == Fl_Text_Editor::kf_delete && pos < buf->length()) {
+        int to = word ? buf->word_end(pos) : buf->next_char(pos);
+        return touches_protection(d, pos, to > pos ? to : buf->next_char(pos));
+    }
+    return 0;
+}
+
+static void add_protection(Fl_Text_Editor *self, int start, int end) {
+    Fl_Text_Buffer *buf = self->buffer();
+    Protection_Data *d = protections[self];
+    if (!d) {
+        d = new (std::nothrow) Protection_Data();
+        if (!d)
+            return;
+        d->buf = buf;
+        protections[self] = d;
+        buf->add_modify_callback(protection_cb, d);
+    } else if (d->buf != buf) {
+        d->buf->remove_modify_callback(protection_cb, d);
+        d->buf = buf;
+        d->ranges.clear();
+        buf->add_modify_callback(protection_cb, d);
+    }
+    Protected_Range range = {start, end};
+    d->ranges.push_back(range);
+}
+
+static void remove_protections(Fl_Text_Editor *self) {
+    auto it = protections.find(self);
+    if (it == protections.end())
+        return;
+    Protection_Data *d = it->second;
+    d->buf->remove_modify_callback(protection_cb, d);
+    delete d;
+    protections.erase(it);
+}
+
+void Fl_Text_Editor_protect_range(Fl_Text_Editor *self, int start, int end) {
+    if (!self->buffer() || start >= end)
+        return;
+    LOCK(add_protection(self, start, end);)
+}
+
+void Fl_Text_Editor_clear_protections(Fl_Text_Editor *self) {
+    LOCK(remove_protections(self);)
+}
+
+int Fl_Text_Editor_insert_mode(Fl_Text_Editor *self) {
+    return self->insert_mode();
+}
+
+void Fl_Text_Editor_set_insert_mode(Fl_Text_Editor *self, int b) {
+    LOCK(self->insert_mode(b);)
+}
+
 WIDGET_DEFINE(Fl_Simple_Terminal)
 
 void Fl_Simple_Terminal_init(Fl_Simple_Terminal *self) {
-    Fl_Text_Buffer *buff = new (std::nothrow) Fl_Text_Buffer();
-    self->buffer(buff);
+    self->buffer(Fl_Text_Buffer_new());
 }
 
 Fl_Text_Buffer *Fl_Simple_Terminal_get_buffer(Fl_Simple_Terminal *self) {
@@ -397,7 +1063,82 @@ Fl_Text_Buffer *Fl_Simple_Terminal_get_buffer(Fl_Simple_Terminal *self) {
 
 void Fl_Simple_Terminal_set_buffer(Fl_Simple_Terminal *self,
                                    Fl_Text_Buffer *buf) {
-    LOCK(self->buffer(buf);)
+    LOCK(replace_buffer(self, buf);)
+}
+
+DISPLAY_DEFINE(Fl_Simple_Terminal)
+
+int Fl_Simple_Terminal_ansi(const Fl_Simple_Terminal *self) {
+    return self->ansi();
 }
 
-DISPLAY_DEFINE(Fl_Simple_Terminal)
\ No newline at end of file
+void Fl_Simple_Terminal_set_ansi(Fl_Simple_Terminal *self, int val) {
+    LOCK(self->ansi(val);)
+}
+
+void Fl_Simple_Terminal_append(Fl_Simple_Terminal *self, const char *s) {
+    LOCK(self->append(s);)
+}
+
+int Fl_Simple_Terminal_history_lines(const Fl_Simple_Terminal *self) {
+    return self->history_lines();
+}
+
+void Fl_Simple_Terminal_set_history_lines(Fl_Simple_Terminal *self, int val) {
+    LOCK(self->history_lines(val);)
+}
+
+int Fl_Simple_Terminal_stay_at_bottom(const Fl_Simple_Terminal *self) {
+    return self->stay_at_bottom();
+}
+
+void Fl_Simple_Terminal_set_stay_at_bottom(Fl_Simple_Terminal *self, int val) {
+    LOCK(self->stay_at_bottom(val);)
+}
+
+// Style indices map to style buffer characters starting at 'A'
+static const size_t max_terminal_styles = 'z' - 'A';
+
+// Looks up (or adds) the style in the terminal's own style table, index 0
+// being the terminal's current normal text style. Escape sequences index the
+// ANSI style table, so this fails in ANSI mode
+static int append_styled(Fl_Simple_Terminal *self, const char *s,
+                         Fl_Color color, Fl_Font font, Fl_Fontsize size) {
+    if (self->ansi())
+        return 0;
+    Style_Table &styles = terminal_styles[self];
+    Fl_Text_Display::Style_Table_Entry normal = {
+        self->textcolor(), self->textfont(), self->textsize()};
+    if (styles.empty())
+        styles.push_back(normal);
+    else
+        styles[0] = normal;
+    int idx = -1;
+    for (size_t i = 0; i < styles.size(); ++i) {
+        if (styles[i].color == color && styles[i].font == font &&
+            styles[i].size == size) {
+            idx = (int)i;
+            break;
+        }
+    }
+    if (idx < 0 && styles.size() < max_terminal_styles) {
+        Fl_Text_Display::Style_Table_Entry entry = {color, font, size};
+        styles.push_back(entry);
+        idx = (int)styles.size() - 1;
+    }
+    if (idx < 0)
+        idx = 0;
+    self->style_table(&styles[0], (int)(styles.size() * sizeof(styles[0])), 0);
+    int prev = self->current_style_index();
+    self->current_style_index(idx);
+    self->append(s);
+    self->current_style_index(prev);
+    return 1;
+}
+
+int Fl_Simple_Terminal_append_styled(Fl_Simple_Terminal *self, const char *s,
+                                     unsigned int color, int font, int size) {
+    int ret = 0;
+    LOCK(ret = append_styled(self, s, color, font, size));
+    return ret;
+}
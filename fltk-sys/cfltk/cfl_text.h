@@ -28,6 +28,10 @@ extern "C" {
     void widget##_set_style_table_entry(widget *self, void *sbuf,              \
                                         unsigned int *color, int *font,        \
                                         int *fontsz, int sz);                  \
+    void widget##_set_style_table_entry_ext(                                   \
+        widget *self, void *sbuf, unsigned int *color, int *font,              \
+        int *fontsz, unsigned int *attr, unsigned int *bgcolor, int sz);       \
+    void widget##_clear_style_table(widget *self);                             \
     void widget##_set_cursor_style(widget *, int style);                       \
     void widget##_set_cursor_color(widget *, unsigned int color);              \
     void widget##_set_scrollbar_width(widget *, int width);                    \
@@ -62,7 +66,14 @@ extern "C" {
     unsigned int widget##_linenumber_bgcolor(const widget *self);              \
     void widget##_set_linenumber_align(widget *self, int val);                 \
     int widget##_linenumber_align(const widget *self);                         \
-    int widget##_in_selection(const widget *self, int x, int y);
+    int widget##_in_selection(const widget *self, int x, int y);               \
+    int widget##_linenumber_position(const widget *self, int x, int y);        \
+    void widget##_show_insert_position(widget *self);                          \
+    void widget##_set_secondary_selection_color(widget *self,                  \
+                                                unsigned int color);           \
+    unsigned int widget##_secondary_selection_color(const widget *self);       \
+    void widget##_set_highlight_color(widget *self, unsigned int color);       \
+    unsigned int widget##_highlight_color(const widget *self);
 
 typedef void (*Fl_Text_Modify_Cb)(int pos, int nInserted, int nDeleted,
                                   int nRestyled, const char *deletedText,
@@ -74,6 +85,10 @@ Fl_Text_Buffer *Fl_Text_Buffer_new(void);
 
 void Fl_Text_Buffer_delete(Fl_Text_Buffer *);
 
+void Fl_Text_Buffer_retain(Fl_Text_Buffer *self);
+
+int Fl_Text_Buffer_refcount(const Fl_Text_Buffer *self);
+
 const char *Fl_Text_Buffer_text(Fl_Text_Buffer *self);
 
 void Fl_Text_Buffer_set_text(Fl_Text_Buffer *self, const char *txt);
@@ -100,6 +115,8 @@ void Fl_Text_Buffer_canUndo(Fl_Text_Buffer *self, char flag);
 
 int Fl_Text_Buffer_loadfile(Fl_Text_Buffer *self, const char *file, int buflen);
 
+int Fl_Text_Buffer_savefile(Fl_Text_Buffer *self, const char *file);
+
 int Fl_Text_Buffer_tab_distance(const Fl_Text_Buffer *self);
 
 void Fl_Text_Buffer_set_tab_distance(Fl_Text_Buffer *self, int tabDist);
@@ -113,6 +130,14 @@ void Fl_Text_Buffer_unselect(Fl_Text_Buffer *self);
 int Fl_Text_Buffer_selection_position(Fl_Text_Buffer *self, int *start,
                                       int *end);
 
+void Fl_Text_Buffer_secondary_select(Fl_Text_Buffer *self, int start,
+                                     int end);
+
+void Fl_Text_Buffer_secondary_unselect(Fl_Text_Buffer *self);
+
+int Fl_Text_Buffer_secondary_selection_position(Fl_Text_Buffer *self,
+                                                int *start, int *end);
+
 char *Fl_Text_Buffer_selection_text(Fl_Text_Buffer *self);
 
 void Fl_Text_Buffer_remove_selection(Fl_Text_Buffer *self);
@@ -134,6 +159,10 @@ char *Fl_Text_Buffer_line_text(const Fl_Text_Buffer *self, int pos);
 
 int Fl_Text_Buffer_line_start(const Fl_Text_Buffer *self, int pos);
 
+int Fl_Text_Buffer_line_end(const Fl_Text_Buffer *self, int pos);
+
+int Fl_Text_Buffer_skip_lines(Fl_Text_Buffer *self, int startPos, int nLines);
+
 int Fl_Text_Buffer_word_start(const Fl_Text_Buffer *self, int pos);
 
 int Fl_Text_Buffer_word_end(const Fl_Text_Buffer *self, int pos);
@@ -141,6 +170,20 @@ int Fl_Text_Buffer_word_end(const Fl_Text_Buffer *self, int pos);
 int Fl_Text_Buffer_count_lines(const Fl_Text_Buffer *self, int startPos,
                                int endPos);
 
+int Fl_Text_Buffer_utf8_align(const Fl_Text_Buffer *self, int pos);
+
+int Fl_Text_Buffer_char_count(const Fl_Text_Buffer *self);
+
+int Fl_Text_Buffer_char_to_byte(const Fl_Text_Buffer *self, int idx);
+
+int Fl_Text_Buffer_byte_to_char(const Fl_Text_Buffer *self, int pos);
+
+void Fl_Text_Buffer_gap_halves(const Fl_Text_Buffer *self, const char **first,
+                               int *first_len, const char **second,
+                               int *second_len);
+
+const char *Fl_Text_Buffer_contiguous(Fl_Text_Buffer *self);
+
 void Fl_Text_Buffer_add_modify_callback(Fl_Text_Buffer *self,
                                         Fl_Text_Modify_Cb bufModifiedCB,
                                         void *cbArg);
@@ -151,6 +194,16 @@ void Fl_Text_Buffer_remove_modify_callback(Fl_Text_Buffer *self,
 
 void Fl_Text_Buffer_call_modify_callbacks(Fl_Text_Buffer *self);
 
+typedef void (*Fl_Text_Buffer_Line_Ending_Cb)(int ending, void *data);
+
+int Fl_Text_Buffer_line_ending(const Fl_Text_Buffer *self);
+
+void Fl_Text_Buffer_set_line_ending(Fl_Text_Buffer *self, int ending);
+
+void *Fl_Text_Buffer_set_line_ending_callback(Fl_Text_Buffer *self,
+                                              Fl_Text_Buffer_Line_Ending_Cb cb,
+                                              void *data);
+
 WIDGET_DECLARE(Fl_Text_Display)
 
 void Fl_Text_Display_init(Fl_Text_Display *);
@@ -179,6 +232,24 @@ int kf_paste(Fl_Text_Editor *e);
 
 int kf_undo(Fl_Text_Editor *e);
 
+typedef int (*Fl_Text_Editor_Key_Func)(int key, Fl_Text_Editor *, void *data);
+
+void *Fl_Text_Editor_add_key_binding(Fl_Text_Editor *self, int key, int state,
+                                     Fl_Text_Editor_Key_Func cb, void *data);
+
+void *Fl_Text_Editor_remove_key_binding(Fl_Text_Editor *self, int key,
+                                        int state);
+
+void Fl_Text_Editor_protect_range(Fl_Text_Editor *self, int start, int end);
+
+void Fl_Text_Editor_clear_protections(Fl_Text_Editor *self);
+
+int Fl_Text_Editor_edits_protected(Fl_Text_Editor *self, int event);
+
+int Fl_Text_Editor_insert_mode(Fl_Text_Editor *self);
+
+void Fl_Text_Editor_set_insert_mode(Fl_Text_Editor *self, int b);
+
 WIDGET_DECLARE(Fl_Simple_Terminal)
 
 void Fl_Simple_Terminal_init(Fl_Simple_Terminal *);
@@ -189,6 +260,23 @@ void Fl_Simple_Terminal_set_buffer(Fl_Simple_Terminal *, Fl_Text_Buffer *);
 
 DISPLAY_DECLARE(Fl_Simple_Terminal)
 
+int Fl_Simple_Terminal_ansi(const Fl_Simple_Terminal *self);
+
+void Fl_Simple_Terminal_set_ansi(Fl_Simple_Terminal *self, int val);
+
+void Fl_Simple_Terminal_append(Fl_Simple_Terminal *self, const char *s);
+
+int Fl_Simple_Terminal_history_lines(const Fl_Simple_Terminal *self);
+
+void Fl_Simple_Terminal_set_history_lines(Fl_Simple_Terminal *self, int val);
+
+int Fl_Simple_Terminal_stay_at_bottom(const Fl_Simple_Terminal *self);
+
+void Fl_Simple_Terminal_set_stay_at_bottom(Fl_Simple_Terminal *self, int val);
+
+int Fl_Simple_Terminal_append_styled(Fl_Simple_Terminal *self, const char *s,
+                                     unsigned int color, int font, int size);
+
 #ifdef __cplusplus
 }
 #endif
\ No newline at end of file
@@ -1,8 +1,18 @@
 #include "cfl_widget.h"
 #include <FL/Fl.H>
+#include <FL/Fl_Group.H>
 #include <FL/Fl_Image.H>
+#include <FL/Fl_Input_.H>
+#include <FL/Fl_Menu_.H>
+#include <FL/Fl_Scrollbar.H>
+#include <FL/Fl_Slider.H>
+#include <FL/Fl_Text_Editor.H>
 #include <FL/Fl_Widget.H>
+#include <FL/fl_draw.H>
 #include <new>
+#include <stdlib.h>
+#include <utility>
+#include <vector>
 
 WIDGET_DEFINE(Fl_Widget)
 
@@ -16,3 +26,187 @@ void Fl_Widget_callback_with_captures(Fl_Widget *self, Fl_Callback *cb,
         return;
     LOCK(self->callback(cb, data);)
 }
+
+static int implicit_parenting = 1;
+
+int Fl_Widget_implicit_parenting(void) { return implicit_parenting; }
+
+void Fl_Widget_set_implicit_parenting(int flag) { implicit_parenting = flag; }
+
+// Without implicit parenting, widgets are constructed with no current group so
+// they aren't added to it, and groups constructed don't become current
+void *Fl_Widget_parenting_begin(void) {
+    Fl_Group *current = Fl_Group::current();
+    if (!implicit_parenting)
+        Fl_Group::current(NULL);
+    return current;
+}
+
+void Fl_Widget_parenting_end(void *current) {
+    if (!implicit_parenting)
+        Fl_Group::current((Fl_Group *)current);
+}
+
+static int touch_mode = 0;
+static int touch_prev_scrollbar_size = 0;
+
+int Fl_Widget_touch_mode(void) { return touch_mode; }
+
+void Fl_Widget_set_touch_mode(int flag) {
+    if (flag == touch_mode)
+        return;
+    touch_mode = flag;
+    if (flag) {
+        touch_prev_scrollbar_size = Fl::scrollbar_size();
+        Fl::scrollbar_size(2 * touch_prev_scrollbar_size);
+    } else {
+        Fl::scrollbar_size(touch_prev_scrollbar_size);
+    }
+}
+
+// Enlarges the hit targets of a newly constructed widget in touch mode
+void Fl_Widget_apply_touch_mode(Fl_Widget *w) {
+    if (!touch_mode)
+        return;
+    if (Fl_Menu_ *m = dynamic_cast<Fl_Menu_ *>(w)) {
+        if (m->textsize() < 2 * FL_NORMAL_SIZE)
+            m->textsize(2 * FL_NORMAL_SIZE);
+    } else if (Fl_Slider *s = dynamic_cast<Fl_Slider *>(w)) {
+        // Scrollbars size their slider from the visible range
+        if (!dynamic_cast<Fl_Scrollbar *>(w) && s->slider_size() < 0.25)
+            s->slider_size(0.25);
+    }
+}
+
+static Fl_Input_Focus_Cb input_focus_cb = NULL;
+static void *input_focus_data = NULL;
+
+void *Fl_Widget_set_input_focus_callback(Fl_Input_Focus_Cb cb, void *data) {
+    void *old = input_focus_data;
+    input_focus_cb = cb;
+    input_focus_data = data;
+    return old;
+}
+
+void Fl_Widget_focus_changed(Fl_Widget *w, int focused) {
+    if (!input_focus_cb)
+        return;
+    if (dynamic_cast<Fl_Input_ *>(w) || dynamic_cast<Fl_Text_Editor *>(w))
+        input_focus_cb(w, focused, input_focus_data);
+}
+
+static Fl_Widget_Data_Deleter data_deleter = NULL;
+static std::vector<std::pair<int, void *>> pending_data;
+
+void Fl_Widget_set_data_deleter(Fl_Widget_Data_Deleter deleter) {
+    data_deleter = deleter;
+}
+
+static void free_pending_data_cb(void *) { Fl_Widget_free_pending_data(); }
+
+// Frees the data of a deleted widget, its callback (0), handler (1), drawer
+// (2), draw filter (3), overlay (4) or window state callback (5), the latter
+// also when replaced like the About callback of the system menu bar (0), a
+// removed text buffer modify callback (6) or the key bindings of a text
+// editor (7). The widget may be deleted by its
+// own callback, like a button clearing its group, so the deleter only runs
+// once the event being handled is done
+void Fl_Widget_free_data(int kind, void *data) {
+    if (!data)
+        return;
+    if (!data_deleter) {
+        free(data);
+        return;
+    }
+    if (pending_data.empty())
+        Fl::add_timeout(0.0, free_pending_data_cb);
+    pending_data.push_back(std::make_pair(kind, data));
+}
+
+void Fl_Widget_free_pending_data(void) {
+    Fl::remove_timeout(free_pending_data_cb);
+    std::vector<std::pair<int, void *>> pending;
+    pending.swap(pending_data);
+    for (auto &p : pending)
+        data_deleter(p.first, p.second);
+}
+
+static std::vector<Fl_Widget_Delete_Observer> &delete_observers() {
+    static std::vector<Fl_Widget_Delete_Observer> observers;
+    return observers;
+}
+
+// Returns a value so that observers can be added by static initializers
+int Fl_Widget_add_delete_observer(Fl_Widget_Delete_Observer cb) {
+    delete_observers().push_back(cb);
+    return 1;
+}
+
+void Fl_Widget_deleted(Fl_Widget *w) {
+    for (auto cb : delete_observers())
+        cb(w);
+}
+
+static std::vector<Fl_Widget_Draw_Observer> &draw_observers() {
+    static std::vector<Fl_Widget_Draw_Observer> observers;
+    return observers;
+}
+
+// Observers run after the base drawing of a widget, before its drawer
+int Fl_Widget_add_draw_observer(Fl_Widget_Draw_Observer cb) {
+    draw_observers().push_back(cb);
+    return 1;
+}
+
+void Fl_Widget_drawn(Fl_Widget *w) {
+    for (auto cb : draw_observers())
+        cb(w);
+}
+
+static std::vector<Fl_Widget_Resize_Observer> &resize_observers() {
+    static std::vector<Fl_Widget_Resize_Observer> observers;
+    return observers;
+}
+
+// Observers run once a widget and its children were resized
+int Fl_Widget_add_resize_observer(Fl_Widget_Resize_Observer cb) {
+    resize_observers().push_back(cb);
+    return 1;
+}
+
+void Fl_Widget_resized(Fl_Widget *w) {
+    for (auto cb : resize_observers())
+        cb(w);
+}
+
+static std::vector<Fl_Widget_Event_Interceptor> &event_interceptors() {
+    static std::vector<Fl_Widget_Event_Interceptor> interceptors;
+    return interceptors;
+}
+
+// Interceptors run before a widget handles an event, taking it by returning 1
+int Fl_Widget_add_event_interceptor(Fl_Widget_Event_Interceptor cb) {
+    event_interceptors().push_back(cb);
+    return 1;
+}
+
+int Fl_Widget_intercepted(Fl_Widget *w, int event) {
+    for (auto cb : event_interceptors())
+        if (cb(w, event))
+            return 1;
+    return 0;
+}
+
+// Reads back the drawn widget, runs the filter over its RGB pixels then draws
+// the result in place
+void Fl_Widget_apply_draw_filter(Fl_Widget *w, custom_filter_callback cb,
+                                 void *data) {
+    int x = w->as_window() ? 0 : w->x();
+    int y = w->as_window() ? 0 : w->y();
+    unsigned char *pixels = fl_read_image(NULL, x, y, w->w(), w->h());
+    if (!pixels)
+        return;
+    cb(pixels, w->w() * w->h(), data);
+    fl_draw_image(pixels, x, y, w->w(), w->h(), 3);
+    delete[] pixels;
+}
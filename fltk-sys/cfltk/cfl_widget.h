@@ -16,7 +16,39 @@ typedef struct Fl_Widget Fl_Widget;
 typedef void(Fl_Callback)(Fl_Widget *, void *);
 typedef int (*custom_handler_callback)(int, void *);
 typedef void (*custom_draw_callback)(void *);
+typedef void (*custom_filter_callback)(unsigned char *pixels, int count,
+                                       void *data);
+typedef void (*custom_overlay_callback)(int x, int y, int w, int h,
+                                        void *data);
 void Fl_Widget_callback_with_captures(Fl_Widget *, Fl_Callback *cb, void *);
+void Fl_Widget_apply_draw_filter(Fl_Widget *w, custom_filter_callback cb,
+                                 void *data);
+int Fl_Widget_implicit_parenting(void);
+void Fl_Widget_set_implicit_parenting(int flag);
+void *Fl_Widget_parenting_begin(void);
+void Fl_Widget_parenting_end(void *current);
+int Fl_Widget_touch_mode(void);
+void Fl_Widget_set_touch_mode(int flag);
+void Fl_Widget_apply_touch_mode(Fl_Widget *w);
+typedef void (*Fl_Input_Focus_Cb)(Fl_Widget *w, int focused, void *data);
+void *Fl_Widget_set_input_focus_callback(Fl_Input_Focus_Cb cb, void *data);
+void Fl_Widget_focus_changed(Fl_Widget *w, int focused);
+typedef void (*Fl_Widget_Data_Deleter)(int kind, void *data);
+void Fl_Widget_set_data_deleter(Fl_Widget_Data_Deleter deleter);
+void Fl_Widget_free_data(int kind, void *data);
+void Fl_Widget_free_pending_data(void);
+typedef void (*Fl_Widget_Delete_Observer)(Fl_Widget *w);
+int Fl_Widget_add_delete_observer(Fl_Widget_Delete_Observer cb);
+void Fl_Widget_deleted(Fl_Widget *w);
+typedef void (*Fl_Widget_Draw_Observer)(Fl_Widget *w);
+int Fl_Widget_add_draw_observer(Fl_Widget_Draw_Observer cb);
+void Fl_Widget_drawn(Fl_Widget *w);
+typedef void (*Fl_Widget_Resize_Observer)(Fl_Widget *w);
+int Fl_Widget_add_resize_observer(Fl_Widget_Resize_Observer cb);
+void Fl_Widget_resized(Fl_Widget *w);
+typedef int (*Fl_Widget_Event_Interceptor)(Fl_Widget *w, int event);
+int Fl_Widget_add_event_interceptor(Fl_Widget_Event_Interceptor cb);
+int Fl_Widget_intercepted(Fl_Widget *w, int event);
 
 #define WIDGET_DECLARE(widget)                                                 \
     typedef struct widget widget;                                              \
@@ -62,6 +94,10 @@ void Fl_Widget_callback_with_captures(Fl_Widget *, Fl_Callback *cb, void *);
     void widget##_set_handler(widget *self, custom_handler_callback cb,        \
                               void *data);                                     \
     void widget##_set_draw(widget *self, custom_draw_callback cb, void *data); \
+    void *widget##_set_draw_filter(widget *self, custom_filter_callback cb,    \
+                                   void *data);                                \
+    void *widget##_set_draw_over(widget *self, custom_overlay_callback cb,     \
+                                 void *data);                                  \
     void widget##_set_trigger(widget *, int);                                  \
     void *widget##_image(const widget *);                                      \
     void *widget##_parent(const widget *self);                                 \
@@ -77,19 +113,28 @@ void Fl_Widget_callback_with_captures(Fl_Widget *, Fl_Callback *cb, void *);
     class widget##_Derived : public widget {                                   \
         void *ev_data_ = NULL;                                                 \
         void *draw_data_ = NULL;                                               \
+        void *filter_data_ = NULL;                                             \
+        void *overlay_data_ = NULL;                                            \
                                                                                \
       public:                                                                  \
         typedef int (*handler)(int, void *data);                               \
         handler inner_handler = NULL;                                          \
         typedef void (*drawer)(void *data);                                    \
         drawer inner_drawer = NULL;                                            \
+        custom_filter_callback inner_filter = NULL;                            \
+        custom_overlay_callback inner_overlay = NULL;                          \
+        int draw_base_ = 1;                                                    \
         widget##_Derived(int x, int y, int w, int h, const char *title = 0)    \
             : widget(x, y, w, h, title) {}                                     \
         operator widget *() { return (widget *)this; }                         \
         void set_handler(handler h) { inner_handler = h; }                     \
         void set_handler_data(void *data) { ev_data_ = data; }                 \
         int handle(int event) override {                                       \
+            if (Fl_Widget_intercepted(this, event))                            \
+                return 1;                                                      \
             int ret = widget::handle(event);                                   \
+            if ((event == FL_FOCUS && ret) || event == FL_UNFOCUS)             \
+                Fl_Widget_focus_changed(this, event == FL_FOCUS);              \
             int local = 0;                                                     \
             if (ev_data_ && inner_handler) {                                   \
                 local = inner_handler(event, ev_data_);                        \
@@ -101,26 +146,59 @@ void Fl_Widget_callback_with_captures(Fl_Widget *, Fl_Callback *cb, void *);
                 return ret;                                                    \
             }                                                                  \
         }                                                                      \
+        void resize(int x, int y, int w, int h) override {                     \
+            widget::resize(x, y, w, h);                                        \
+            Fl_Widget_resized(this);                                           \
+        }                                                                      \
         void set_drawer(drawer h) { inner_drawer = h; }                        \
         void set_drawer_data(void *data) { draw_data_ = data; }                \
         void draw() override {                                                 \
-            if constexpr (!std::is_same_v<widget, Fl_Widget>)                  \
-                widget::draw();                                                \
+            if constexpr (!std::is_same_v<widget, Fl_Widget>) {                \
+                if (draw_base_) {                                              \
+                    widget::draw();                                            \
+                    Fl_Widget_drawn(this);                                     \
+                }                                                              \
+            }                                                                  \
                                                                                \
             if (draw_data_ && inner_drawer)                                    \
                 inner_drawer(draw_data_);                                      \
+            if (filter_data_ && inner_filter)                                  \
+                Fl_Widget_apply_draw_filter(this, inner_filter, filter_data_); \
+            if (overlay_data_ && inner_overlay)                                \
+                inner_overlay(this->as_window() ? 0 : x(),                     \
+                              this->as_window() ? 0 : y(), w(), h(),           \
+                              overlay_data_);                                  \
         };                                                                     \
+        void *set_filter(custom_filter_callback f, void *data) {               \
+            void *old = filter_data_;                                          \
+            inner_filter = f;                                                  \
+            filter_data_ = data;                                               \
+            return old;                                                        \
+        }                                                                      \
+        void *set_overlay(custom_overlay_callback f, void *data) {             \
+            void *old = overlay_data_;                                         \
+            inner_overlay = f;                                                 \
+            overlay_data_ = data;                                              \
+            return old;                                                        \
+        }                                                                      \
         ~widget##_Derived() {                                                  \
-            void *user_data_ = widget::user_data();                            \
-            free(user_data_);                                                  \
-            free(ev_data_);                                                    \
-            free(draw_data_);                                                  \
+            Fl_Widget_deleted(this);                                           \
+            Fl_Widget_free_data(0, widget::user_data());                       \
+            Fl_Widget_free_data(1, ev_data_);                                  \
+            Fl_Widget_free_data(2, draw_data_);                                \
+            Fl_Widget_free_data(3, filter_data_);                              \
+            Fl_Widget_free_data(4, overlay_data_);                             \
         }                                                                      \
     };                                                                         \
     widget *widget##_new(int x, int y, int width, int height,                  \
                          const char *title) {                                  \
-        return new (std::nothrow)                                              \
+        void *current = Fl_Widget_parenting_begin();                           \
+        widget *ret = new (std::nothrow)                                       \
             widget##_Derived(x, y, width, height, title);                      \
+        Fl_Widget_parenting_end(current);                                      \
+        if (ret)                                                               \
+            Fl_Widget_apply_touch_mode(ret);                                   \
+        return ret;                                                            \
     }                                                                          \
     int widget##_x(widget *self) { return self->x(); }                         \
     int widget##_y(widget *self) { return self->y(); }                         \
@@ -202,6 +280,20 @@ void Fl_Widget_callback_with_captures(Fl_Widget *, Fl_Callback *cb, void *);
         LOCK(((widget##_Derived *)self)->set_drawer_data(data);                \
              ((widget##_Derived *)self)->set_drawer(cb);)                      \
     }                                                                          \
+    void *widget##_set_draw_filter(widget *self, custom_filter_callback cb,    \
+                                   void *data) {                               \
+        void *old = NULL;                                                      \
+        LOCK(old = ((widget##_Derived *)self)->set_filter(cb, data);           \
+             self->redraw();)                                                  \
+        return old;                                                            \
+    }                                                                          \
+    void *widget##_set_draw_over(widget *self, custom_overlay_callback cb,     \
+                                 void *data) {                                 \
+        void *old = NULL;                                                      \
+        LOCK(old = ((widget##_Derived *)self)->set_overlay(cb, data);          \
+             self->redraw();)                                                  \
+        return old;                                                            \
+    }                                                                          \
     void *widget##_parent(const widget *self) {                                \
         return (Fl_Group *)self->parent();                                     \
     }                                                                          \
@@ -8,8 +8,93 @@
 #include <FL/Fl_Single_Window.H>
 #include <FL/Fl_Window.H>
 #include <FL/platform.H>
+#include <map>
 #include <new>
 
+struct Window_State_Data {
+    Fl_Window_State_Cb cb;
+    void *data;
+    bool minimized;
+    bool maximized;
+};
+
+static std::map<Fl_Window *, Window_State_Data> window_states;
+static Fl_Event_Dispatch window_state_prev_dispatch = NULL;
+static bool window_state_installed = false;
+
+// Window state changes are reported by the platform as events sent to the
+// window, maximization is checked after every event the window receives
+static int window_state_dispatch(int event, Fl_Window *w) {
+    int ret = window_state_prev_dispatch ? window_state_prev_dispatch(event, w)
+                                         : Fl::handle_(event, w);
+    auto it = window_states.find(w);
+    if (it == window_states.end())
+        return ret;
+    Window_State_Data &d = it->second;
+    int states[2];
+    int count = 0;
+    if (event == FL_HIDE && w->shown() && !d.minimized) {
+        d.minimized = true;
+        states[count++] = 0;
+    } else if (event == FL_SHOW && d.minimized) {
+        d.minimized = false;
+        states[count++] = 1;
+    } else if (event == FL_FOCUS) {
+        states[count++] = 4;
+    } else if (event == FL_UNFOCUS) {
+        states[count++] = 5;
+    }
+    bool maximized = w->maximize_active();
+    if (maximized != d.maximized) {
+        d.maximized = maximized;
+        states[count++] = maximized ? 2 : 3;
+    }
+    // A callback can replace the callback or delete the window, erasing the
+    // entry, so it's looked up again for every state
+    for (int i = 0; i < count; i++) {
+        it = window_states.find(w);
+        if (it == window_states.end())
+            break;
+        Fl_Window_State_Cb cb = it->second.cb;
+        void *data = it->second.data;
+        cb(states[i], data);
+    }
+    return ret;
+}
+
+// Drops the callback of a deleted window, a new window could reuse its address
+static void window_state_deleted(Fl_Widget *w) {
+    auto it = window_states.find((Fl_Window *)w);
+    if (it == window_states.end())
+        return;
+    Fl_Widget_free_data(5, it->second.data);
+    window_states.erase(it);
+}
+
+[[maybe_unused]] static int window_state_observer =
+    Fl_Widget_add_delete_observer(window_state_deleted);
+
+// The replaced callback is freed once the event being handled is done since
+// it could be the one replacing itself
+static void set_window_state_callback(Fl_Window *self, Fl_Window_State_Cb cb,
+                                      void *data) {
+    auto it = window_states.find(self);
+    if (it != window_states.end()) {
+        Fl_Widget_free_data(5, it->second.data);
+        window_states.erase(it);
+    }
+    if (cb && data) {
+        Window_State_Data d = {cb, data, false, self->maximize_active()};
+        window_states[self] = d;
+        if (!window_state_installed) {
+            // Chains to any previously installed dispatch function
+            window_state_prev_dispatch = Fl::event_dispatch();
+            Fl::event_dispatch(window_state_dispatch);
+            window_state_installed = true;
+        }
+    }
+}
+
 #define WINDOW_DEFINE(widget)                                                  \
     void widget##_make_modal(widget *self, unsigned int boolean) {             \
         LOCK(if (boolean) { self->set_modal(); } else {                        \
@@ -37,7 +122,11 @@
         LOCK(self->cursor((Fl_Cursor)cursor);)                                 \
     }                                                                          \
     int widget##_shown(widget *self) { return self->shown(); }                 \
-    void *widget##_raw_handle(const widget *w) { return (void *)fl_xid_(w); }
+    void *widget##_raw_handle(const widget *w) { return (void *)fl_xid_(w); }  \
+    void widget##_set_state_callback(widget *self, Fl_Window_State_Cb cb,      \
+                                     void *data) {                             \
+        LOCK(set_window_state_callback(self, cb, data);)                       \
+    }
 
 WIDGET_DEFINE(Fl_Window)
 
@@ -7,6 +7,8 @@
 extern "C" {
 #endif
 
+typedef void (*Fl_Window_State_Cb)(int state, void *data);
+
 #define WINDOW_DECLARE(widget)                                                 \
     void widget##_make_modal(widget *, unsigned int boolean);                  \
     void widget##_fullscreen(widget *, unsigned int boolean);                  \
@@ -16,7 +18,9 @@ extern "C" {
     void widget##_make_resizable(widget *self, void *);                        \
     void widget##_set_cursor(widget *self, int cursor);                        \
     int widget##_shown(widget *self);                                          \
-    void *widget##_raw_handle(const widget *w);
+    void *widget##_raw_handle(const widget *w);                                \
+    void widget##_set_state_callback(widget *self, Fl_Window_State_Cb cb,      \
+                                     void *data);
 
 WIDGET_DECLARE(Fl_Window)
 
@@ -16,6 +16,22 @@ pub type custom_handler_callback = ::std::option::Option<
 >;
 pub type custom_draw_callback =
     ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>;
+pub type custom_filter_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        pixels: *mut ::std::os::raw::c_uchar,
+        count: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type custom_overlay_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
 extern "C" {
     pub fn Fl_Widget_callback_with_captures(
         arg1: *mut Fl_Widget,
@@ -23,6 +39,50 @@ extern "C" {
         arg2: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Widget_apply_draw_filter(
+        w: *mut Fl_Widget,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn Fl_Widget_implicit_parenting() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_set_implicit_parenting(flag: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Widget_parenting_begin() -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_parenting_end(current: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn Fl_Widget_touch_mode() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_set_touch_mode(flag: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Widget_apply_touch_mode(w: *mut Fl_Widget);
+}
+pub type Fl_Input_Focus_Cb = ::std::option::Option<
+    unsafe extern "C" fn(
+        w: *mut Fl_Widget,
+        focused: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+extern "C" {
+    pub fn Fl_Widget_set_input_focus_callback(
+        cb: Fl_Input_Focus_Cb,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_focus_changed(w: *mut Fl_Widget, focused: ::std::os::raw::c_int);
+}
 extern "C" {
     pub fn Fl_Widget_new(
         x: ::std::os::raw::c_int,
@@ -168,6 +228,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Widget_set_draw_filter(
+        self_: *mut Fl_Widget,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_set_draw_over(
+        self_: *mut Fl_Widget,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Widget_set_trigger(arg1: *mut Fl_Widget, arg2: ::std::os::raw::c_int);
 }
@@ -351,6 +425,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Button_set_draw_filter(
+        self_: *mut Fl_Button,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Button_set_draw_over(
+        self_: *mut Fl_Button,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Button_set_trigger(arg1: *mut Fl_Button, arg2: ::std::os::raw::c_int);
 }
@@ -558,6 +646,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Check_Button_set_draw_filter(
+        self_: *mut Fl_Check_Button,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Check_Button_set_draw_over(
+        self_: *mut Fl_Check_Button,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Check_Button_set_trigger(arg1: *mut Fl_Check_Button, arg2: ::std::os::raw::c_int);
 }
@@ -777,6 +879,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Radio_Button_set_draw_filter(
+        self_: *mut Fl_Radio_Button,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Radio_Button_set_draw_over(
+        self_: *mut Fl_Radio_Button,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Radio_Button_set_trigger(arg1: *mut Fl_Radio_Button, arg2: ::std::os::raw::c_int);
 }
@@ -1002,6 +1118,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Toggle_Button_set_draw_filter(
+        self_: *mut Fl_Toggle_Button,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Toggle_Button_set_draw_over(
+        self_: *mut Fl_Toggle_Button,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Toggle_Button_set_trigger(arg1: *mut Fl_Toggle_Button, arg2: ::std::os::raw::c_int);
 }
@@ -1223,6 +1353,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Round_Button_set_draw_filter(
+        self_: *mut Fl_Round_Button,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Round_Button_set_draw_over(
+        self_: *mut Fl_Round_Button,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Round_Button_set_trigger(arg1: *mut Fl_Round_Button, arg2: ::std::os::raw::c_int);
 }
@@ -1481,6 +1625,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Radio_Round_Button_set_draw_filter(
+        self_: *mut Fl_Radio_Round_Button,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Radio_Round_Button_set_draw_over(
+        self_: *mut Fl_Radio_Round_Button,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Radio_Round_Button_set_trigger(
         arg1: *mut Fl_Radio_Round_Button,
@@ -1764,6 +1922,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Radio_Light_Button_set_draw_filter(
+        self_: *mut Fl_Radio_Light_Button,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Radio_Light_Button_set_draw_over(
+        self_: *mut Fl_Radio_Light_Button,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Radio_Light_Button_set_trigger(
         arg1: *mut Fl_Radio_Light_Button,
@@ -2008,6 +2180,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Light_Button_set_draw_filter(
+        self_: *mut Fl_Light_Button,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Light_Button_set_draw_over(
+        self_: *mut Fl_Light_Button,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Light_Button_set_trigger(arg1: *mut Fl_Light_Button, arg2: ::std::os::raw::c_int);
 }
@@ -2233,6 +2419,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Repeat_Button_set_draw_filter(
+        self_: *mut Fl_Repeat_Button,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Repeat_Button_set_draw_over(
+        self_: *mut Fl_Repeat_Button,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Repeat_Button_set_trigger(arg1: *mut Fl_Repeat_Button, arg2: ::std::os::raw::c_int);
 }
@@ -2454,6 +2654,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Return_Button_set_draw_filter(
+        self_: *mut Fl_Return_Button,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Return_Button_set_draw_over(
+        self_: *mut Fl_Return_Button,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Return_Button_set_trigger(arg1: *mut Fl_Return_Button, arg2: ::std::os::raw::c_int);
 }
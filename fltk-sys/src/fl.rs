@@ -58,6 +58,16 @@ extern "C" {
 extern "C" {
     pub fn Fl_screen_w() -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_screen_work_area(
+        x: *mut ::std::os::raw::c_int,
+        y: *mut ::std::os::raw::c_int,
+        w: *mut ::std::os::raw::c_int,
+        h: *mut ::std::os::raw::c_int,
+        mx: ::std::os::raw::c_int,
+        my: ::std::os::raw::c_int,
+    );
+}
 extern "C" {
     pub fn Fl_paste(arg1: *mut Fl_Widget, src: ::std::os::raw::c_int);
 }
@@ -84,6 +94,17 @@ extern "C" {
         >,
     );
 }
+extern "C" {
+    pub fn Fl_add_system_handler(
+        handler: ::std::option::Option<
+            unsafe extern "C" fn(
+                event: *mut ::std::os::raw::c_void,
+                data: *mut ::std::os::raw::c_void,
+            ) -> ::std::os::raw::c_int,
+        >,
+        data: *mut ::std::os::raw::c_void,
+    );
+}
 extern "C" {
     pub fn Fl_awake_msg(msg: *mut ::std::os::raw::c_void);
 }
@@ -113,6 +134,37 @@ extern "C" {
         arg2: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_add_fd(
+        fd: ::std::os::raw::c_int,
+        when: ::std::os::raw::c_int,
+        arg1: ::std::option::Option<
+            unsafe extern "C" fn(arg1: ::std::os::raw::c_int, arg2: *mut ::std::os::raw::c_void),
+        >,
+        arg2: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn Fl_remove_fd(fd: ::std::os::raw::c_int, when: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_add_idle(
+        arg1: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+        arg2: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn Fl_has_idle(
+        arg1: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+        arg2: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_remove_idle(
+        arg1: ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>,
+        arg2: *mut ::std::os::raw::c_void,
+    );
+}
 extern "C" {
     pub fn Fl_dnd() -> ::std::os::raw::c_int;
 }
@@ -139,3 +191,47 @@ extern "C" {
 extern "C" {
     pub fn Fl_delete_widget(w: *mut Fl_Widget);
 }
+extern "C" {
+    pub fn Fl_send_key(
+        key: ::std::os::raw::c_int,
+        down: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_dnd_text_ops() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_set_dnd_text_ops(flag: ::std::os::raw::c_int);
+}
+pub type Fl_Screen_Change_Handler =
+    ::std::option::Option<unsafe extern "C" fn(data: *mut ::std::os::raw::c_void)>;
+extern "C" {
+    pub fn Fl_set_screen_change_callback(
+        cb: Fl_Screen_Change_Handler,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_widget_visible_r(w: *mut Fl_Widget) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_Tracker_new(w: *mut Fl_Widget) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_Tracker_deleted(self_: *mut ::std::os::raw::c_void) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_Tracker_delete(self_: *mut ::std::os::raw::c_void);
+}
+pub type Fl_Context_Help_Handler = ::std::option::Option<
+    unsafe extern "C" fn(w: *mut Fl_Widget, data: *mut ::std::os::raw::c_void),
+>;
+extern "C" {
+    pub fn Fl_set_context_help_callback(
+        cb: Fl_Context_Help_Handler,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_start_whats_this();
+}
@@ -16,6 +16,22 @@ pub type custom_handler_callback = ::std::option::Option<
 >;
 pub type custom_draw_callback =
     ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>;
+pub type custom_filter_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        pixels: *mut ::std::os::raw::c_uchar,
+        count: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type custom_overlay_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
 extern "C" {
     pub fn Fl_Widget_callback_with_captures(
         arg1: *mut Fl_Widget,
@@ -23,6 +39,50 @@ extern "C" {
         arg2: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Widget_apply_draw_filter(
+        w: *mut Fl_Widget,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn Fl_Widget_implicit_parenting() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_set_implicit_parenting(flag: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Widget_parenting_begin() -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_parenting_end(current: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn Fl_Widget_touch_mode() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_set_touch_mode(flag: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Widget_apply_touch_mode(w: *mut Fl_Widget);
+}
+pub type Fl_Input_Focus_Cb = ::std::option::Option<
+    unsafe extern "C" fn(
+        w: *mut Fl_Widget,
+        focused: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+extern "C" {
+    pub fn Fl_Widget_set_input_focus_callback(
+        cb: Fl_Input_Focus_Cb,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_focus_changed(w: *mut Fl_Widget, focused: ::std::os::raw::c_int);
+}
 extern "C" {
     pub fn Fl_Widget_new(
         x: ::std::os::raw::c_int,
@@ -168,6 +228,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Widget_set_draw_filter(
+        self_: *mut Fl_Widget,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_set_draw_over(
+        self_: *mut Fl_Widget,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Widget_set_trigger(arg1: *mut Fl_Widget, arg2: ::std::os::raw::c_int);
 }
@@ -351,6 +425,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Box_set_draw_filter(
+        self_: *mut Fl_Box,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Box_set_draw_over(
+        self_: *mut Fl_Box,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Box_set_trigger(arg1: *mut Fl_Box, arg2: ::std::os::raw::c_int);
 }
@@ -384,3 +472,9 @@ extern "C" {
 extern "C" {
     pub fn Fl_Box_takes_events(arg1: *const Fl_Box) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_Box_set_deimage(self_: *mut Fl_Box, image: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn Fl_Box_deimage(self_: *const Fl_Box) -> *mut ::std::os::raw::c_void;
+}
@@ -349,6 +349,24 @@ extern "C" {
         depth: ::std::os::raw::c_int,
     ) -> *mut Fl_RGB_Image;
 }
+extern "C" {
+    pub fn Fl_RGB_Image_depth(self_: *mut Fl_RGB_Image) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_RGB_Image_data_w(self_: *mut Fl_RGB_Image) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_RGB_Image_data_h(self_: *mut Fl_RGB_Image) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_RGB_Image_line_size(self_: *mut Fl_RGB_Image) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_RGB_Image_pixels(self_: *mut Fl_RGB_Image) -> *mut ::std::os::raw::c_uchar;
+}
+extern "C" {
+    pub fn Fl_RGB_Image_uncache(self_: *mut Fl_RGB_Image);
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Shared_Image {
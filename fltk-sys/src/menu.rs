@@ -16,6 +16,22 @@ pub type custom_handler_callback = ::std::option::Option<
 >;
 pub type custom_draw_callback =
     ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>;
+pub type custom_filter_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        pixels: *mut ::std::os::raw::c_uchar,
+        count: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type custom_overlay_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
 extern "C" {
     pub fn Fl_Widget_callback_with_captures(
         arg1: *mut Fl_Widget,
@@ -23,6 +39,50 @@ extern "C" {
         arg2: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Widget_apply_draw_filter(
+        w: *mut Fl_Widget,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn Fl_Widget_implicit_parenting() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_set_implicit_parenting(flag: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Widget_parenting_begin() -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_parenting_end(current: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn Fl_Widget_touch_mode() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_set_touch_mode(flag: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Widget_apply_touch_mode(w: *mut Fl_Widget);
+}
+pub type Fl_Input_Focus_Cb = ::std::option::Option<
+    unsafe extern "C" fn(
+        w: *mut Fl_Widget,
+        focused: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+extern "C" {
+    pub fn Fl_Widget_set_input_focus_callback(
+        cb: Fl_Input_Focus_Cb,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_focus_changed(w: *mut Fl_Widget, focused: ::std::os::raw::c_int);
+}
 extern "C" {
     pub fn Fl_Widget_new(
         x: ::std::os::raw::c_int,
@@ -168,6 +228,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Widget_set_draw_filter(
+        self_: *mut Fl_Widget,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_set_draw_over(
+        self_: *mut Fl_Widget,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Widget_set_trigger(arg1: *mut Fl_Widget, arg2: ::std::os::raw::c_int);
 }
@@ -356,6 +430,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Menu_Bar_set_draw_filter(
+        self_: *mut Fl_Menu_Bar,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Menu_Bar_set_draw_over(
+        self_: *mut Fl_Menu_Bar,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Menu_Bar_set_trigger(arg1: *mut Fl_Menu_Bar, arg2: ::std::os::raw::c_int);
 }
@@ -443,6 +531,9 @@ extern "C" {
 extern "C" {
     pub fn Fl_Menu_Bar_add_choice(arg1: *mut Fl_Menu_Bar, arg2: *const ::std::os::raw::c_char);
 }
+extern "C" {
+    pub fn Fl_Menu_Bar_add_item(self_: *mut Fl_Menu_Bar, name: *const ::std::os::raw::c_char);
+}
 extern "C" {
     pub fn Fl_Menu_Bar_get_choice(arg1: *mut Fl_Menu_Bar) -> *const ::std::os::raw::c_char;
 }
@@ -464,6 +555,39 @@ extern "C" {
         index: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_Menu_Bar_remove(self_: *mut Fl_Menu_Bar, idx: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Menu_Bar_replace(
+        self_: *mut Fl_Menu_Bar,
+        idx: ::std::os::raw::c_int,
+        name: *const ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn Fl_Menu_Bar_size(arg1: *const Fl_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Menu_Bar_at(
+        arg1: *const Fl_Menu_Bar,
+        idx: ::std::os::raw::c_int,
+    ) -> *mut Fl_Menu_Item;
+}
+extern "C" {
+    pub fn Fl_Menu_Bar_item_pathname(
+        self_: *const Fl_Menu_Bar,
+        buf: *mut ::std::os::raw::c_char,
+        sz: ::std::os::raw::c_int,
+        item: *const Fl_Menu_Item,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Menu_Bar_picked(self_: *mut Fl_Menu_Bar, item: *const Fl_Menu_Item);
+}
+extern "C" {
+    pub fn Fl_Menu_Bar_test_shortcut(self_: *mut Fl_Menu_Bar) -> *mut Fl_Menu_Item;
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Menu_Button {
@@ -620,6 +744,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Menu_Button_set_draw_filter(
+        self_: *mut Fl_Menu_Button,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Menu_Button_set_draw_over(
+        self_: *mut Fl_Menu_Button,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Menu_Button_set_trigger(arg1: *mut Fl_Menu_Button, arg2: ::std::os::raw::c_int);
 }
@@ -713,6 +851,9 @@ extern "C" {
         arg2: *const ::std::os::raw::c_char,
     );
 }
+extern "C" {
+    pub fn Fl_Menu_Button_add_item(self_: *mut Fl_Menu_Button, name: *const ::std::os::raw::c_char);
+}
 extern "C" {
     pub fn Fl_Menu_Button_get_choice(arg1: *mut Fl_Menu_Button) -> *const ::std::os::raw::c_char;
 }
@@ -734,6 +875,42 @@ extern "C" {
         index: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_Menu_Button_remove(self_: *mut Fl_Menu_Button, idx: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Menu_Button_replace(
+        self_: *mut Fl_Menu_Button,
+        idx: ::std::os::raw::c_int,
+        name: *const ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn Fl_Menu_Button_size(arg1: *const Fl_Menu_Button) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Menu_Button_at(
+        arg1: *const Fl_Menu_Button,
+        idx: ::std::os::raw::c_int,
+    ) -> *mut Fl_Menu_Item;
+}
+extern "C" {
+    pub fn Fl_Menu_Button_item_pathname(
+        self_: *const Fl_Menu_Button,
+        buf: *mut ::std::os::raw::c_char,
+        sz: ::std::os::raw::c_int,
+        item: *const Fl_Menu_Item,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Menu_Button_picked(self_: *mut Fl_Menu_Button, item: *const Fl_Menu_Item);
+}
+extern "C" {
+    pub fn Fl_Menu_Button_test_shortcut(self_: *mut Fl_Menu_Button) -> *mut Fl_Menu_Item;
+}
+extern "C" {
+    pub fn Fl_Menu_Button_popup(self_: *mut Fl_Menu_Button) -> *mut Fl_Menu_Item;
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Choice {
@@ -884,6 +1061,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Choice_set_draw_filter(
+        self_: *mut Fl_Choice,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Choice_set_draw_over(
+        self_: *mut Fl_Choice,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Choice_set_trigger(arg1: *mut Fl_Choice, arg2: ::std::os::raw::c_int);
 }
@@ -971,6 +1162,9 @@ extern "C" {
 extern "C" {
     pub fn Fl_Choice_add_choice(arg1: *mut Fl_Choice, arg2: *const ::std::os::raw::c_char);
 }
+extern "C" {
+    pub fn Fl_Choice_add_item(self_: *mut Fl_Choice, name: *const ::std::os::raw::c_char);
+}
 extern "C" {
     pub fn Fl_Choice_get_choice(arg1: *mut Fl_Choice) -> *const ::std::os::raw::c_char;
 }
@@ -992,6 +1186,369 @@ extern "C" {
         index: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_Choice_remove(self_: *mut Fl_Choice, idx: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Choice_replace(
+        self_: *mut Fl_Choice,
+        idx: ::std::os::raw::c_int,
+        name: *const ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn Fl_Choice_size(arg1: *const Fl_Choice) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Choice_at(
+        arg1: *const Fl_Choice,
+        idx: ::std::os::raw::c_int,
+    ) -> *mut Fl_Menu_Item;
+}
+extern "C" {
+    pub fn Fl_Choice_item_pathname(
+        self_: *const Fl_Choice,
+        buf: *mut ::std::os::raw::c_char,
+        sz: ::std::os::raw::c_int,
+        item: *const Fl_Menu_Item,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Choice_picked(self_: *mut Fl_Choice, item: *const Fl_Menu_Item);
+}
+extern "C" {
+    pub fn Fl_Choice_test_shortcut(self_: *mut Fl_Choice) -> *mut Fl_Menu_Item;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Fl_Sys_Menu_Bar {
+    _unused: [u8; 0],
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_new(
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+        title: *const ::std::os::raw::c_char,
+    ) -> *mut Fl_Sys_Menu_Bar;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_x(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_y(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_width(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_height(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_label(arg1: *mut Fl_Sys_Menu_Bar) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_label(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        title: *const ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_redraw(arg1: *mut Fl_Sys_Menu_Bar);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_show(arg1: *mut Fl_Sys_Menu_Bar);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_hide(arg1: *mut Fl_Sys_Menu_Bar);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_activate(arg1: *mut Fl_Sys_Menu_Bar);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_deactivate(arg1: *mut Fl_Sys_Menu_Bar);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_redraw_label(arg1: *mut Fl_Sys_Menu_Bar);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_resize(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_tooltip(arg1: *mut Fl_Sys_Menu_Bar) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_tooltip(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        txt: *const ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_get_type(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_type(arg1: *mut Fl_Sys_Menu_Bar, typ: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_color(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_color(arg1: *mut Fl_Sys_Menu_Bar, color: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_label_color(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_label_color(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        color: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_label_font(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_label_font(arg1: *mut Fl_Sys_Menu_Bar, font: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_label_size(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_label_size(arg1: *mut Fl_Sys_Menu_Bar, sz: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_label_type(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_label_type(arg1: *mut Fl_Sys_Menu_Bar, typ: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_box(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_box(arg1: *mut Fl_Sys_Menu_Bar, typ: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_changed(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_changed(arg1: *mut Fl_Sys_Menu_Bar);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_clear_changed(arg1: *mut Fl_Sys_Menu_Bar);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_align(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_align(arg1: *mut Fl_Sys_Menu_Bar, typ: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_delete(arg1: *mut Fl_Sys_Menu_Bar);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_image(arg1: *mut Fl_Sys_Menu_Bar, arg2: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_image_with_size(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        arg2: *mut ::std::os::raw::c_void,
+        arg3: ::std::os::raw::c_int,
+        arg4: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_handler(
+        self_: *mut Fl_Sys_Menu_Bar,
+        cb: custom_handler_callback,
+        data: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_draw(
+        self_: *mut Fl_Sys_Menu_Bar,
+        cb: custom_draw_callback,
+        data: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_draw_filter(
+        self_: *mut Fl_Sys_Menu_Bar,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_draw_over(
+        self_: *mut Fl_Sys_Menu_Bar,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_trigger(arg1: *mut Fl_Sys_Menu_Bar, arg2: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_image(arg1: *const Fl_Sys_Menu_Bar) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_parent(self_: *const Fl_Sys_Menu_Bar) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_selection_color(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_selection_color(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        color: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_do_callback(arg1: *mut Fl_Sys_Menu_Bar);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_inside(
+        self_: *const Fl_Sys_Menu_Bar,
+        arg1: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_window(arg1: *const Fl_Sys_Menu_Bar) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_top_window(arg1: *const Fl_Sys_Menu_Bar) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_takes_events(arg1: *const Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_add(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        name: *const ::std::os::raw::c_char,
+        shortcut: ::std::os::raw::c_int,
+        arg2: Fl_Callback,
+        arg3: *mut ::std::os::raw::c_void,
+        arg4: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_insert(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        index: ::std::os::raw::c_int,
+        name: *const ::std::os::raw::c_char,
+        shortcut: ::std::os::raw::c_int,
+        arg2: Fl_Callback,
+        arg3: *mut ::std::os::raw::c_void,
+        arg4: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_get_item(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        name: *const ::std::os::raw::c_char,
+    ) -> *mut Fl_Menu_Item;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_item(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        item: *mut Fl_Menu_Item,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_text_font(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_text_font(arg1: *mut Fl_Sys_Menu_Bar, c: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_text_size(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_text_size(arg1: *mut Fl_Sys_Menu_Bar, c: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_text_color(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_text_color(arg1: *mut Fl_Sys_Menu_Bar, c: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_add_choice(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        arg2: *const ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_add_item(self_: *mut Fl_Sys_Menu_Bar, name: *const ::std::os::raw::c_char);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_get_choice(arg1: *mut Fl_Sys_Menu_Bar) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_value(arg1: *mut Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_set_value(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        v: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_clear(arg1: *mut Fl_Sys_Menu_Bar);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_clear_submenu(
+        arg1: *mut Fl_Sys_Menu_Bar,
+        index: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_remove(self_: *mut Fl_Sys_Menu_Bar, idx: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_replace(
+        self_: *mut Fl_Sys_Menu_Bar,
+        idx: ::std::os::raw::c_int,
+        name: *const ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_size(arg1: *const Fl_Sys_Menu_Bar) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_at(
+        arg1: *const Fl_Sys_Menu_Bar,
+        idx: ::std::os::raw::c_int,
+    ) -> *mut Fl_Menu_Item;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_item_pathname(
+        self_: *const Fl_Sys_Menu_Bar,
+        buf: *mut ::std::os::raw::c_char,
+        sz: ::std::os::raw::c_int,
+        item: *const Fl_Menu_Item,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_picked(self_: *mut Fl_Sys_Menu_Bar, item: *const Fl_Menu_Item);
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_test_shortcut(self_: *mut Fl_Sys_Menu_Bar) -> *mut Fl_Menu_Item;
+}
+extern "C" {
+    pub fn Fl_Sys_Menu_Bar_about(
+        self_: *mut Fl_Sys_Menu_Bar,
+        cb: Fl_Callback,
+        data: *mut ::std::os::raw::c_void,
+    );
+}
 extern "C" {
     pub fn Fl_Menu_Item_new(
         args: *mut *mut ::std::os::raw::c_char,
@@ -1008,12 +1565,38 @@ extern "C" {
         y: ::std::os::raw::c_int,
     ) -> *const Fl_Menu_Item;
 }
+extern "C" {
+    pub fn Fl_Menu_Item_pulldown(
+        self_: *mut Fl_Menu_Item,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        picked: *const Fl_Menu_Item,
+    ) -> *const Fl_Menu_Item;
+}
+extern "C" {
+    pub fn Fl_Menu_Item_do_callback(self_: *mut Fl_Menu_Item, w: *mut Fl_Widget);
+}
 extern "C" {
     pub fn Fl_Menu_Item_label(arg1: *mut Fl_Menu_Item) -> *const ::std::os::raw::c_char;
 }
 extern "C" {
     pub fn Fl_Menu_Item_set_label(arg1: *mut Fl_Menu_Item, a: *const ::std::os::raw::c_char);
 }
+extern "C" {
+    pub fn Fl_Menu_Item_add_image(
+        self_: *mut Fl_Menu_Item,
+        image: *mut ::std::os::raw::c_void,
+        on_left: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Menu_Item_release_label(self_: *mut Fl_Menu_Item);
+}
+extern "C" {
+    pub fn Fl_Menu_Item_has_image(self_: *const Fl_Menu_Item) -> ::std::os::raw::c_int;
+}
 extern "C" {
     pub fn Fl_Menu_Item_label_type(arg1: *mut Fl_Menu_Item) -> ::std::os::raw::c_int;
 }
@@ -1065,6 +1648,106 @@ extern "C" {
 extern "C" {
     pub fn Fl_Menu_Item_deactivate(arg1: *mut Fl_Menu_Item);
 }
+extern "C" {
+    pub fn Fl_Menu_Item_submenu(arg1: *mut Fl_Menu_Item) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Menu_Item_callback(self_: *mut Fl_Menu_Item) -> Fl_Callback;
+}
+extern "C" {
+    pub fn Fl_Menu_Item_user_data(self_: *mut Fl_Menu_Item) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Menu_Item_set_callback(
+        self_: *mut Fl_Menu_Item,
+        cb: Fl_Callback,
+        data: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn Fl_Menu_Item_next(
+        self_: *mut Fl_Menu_Item,
+        idx: ::std::os::raw::c_int,
+    ) -> *mut Fl_Menu_Item;
+}
+extern "C" {
+    pub fn Fl_Menu_Item_setonly(self_: *mut Fl_Menu_Item);
+}
+extern "C" {
+    pub fn Fl_Menu_Item_flags(self_: *const Fl_Menu_Item) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Menu_Item_set_flags(self_: *mut Fl_Menu_Item, flags: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Menu_Item_shortcut(self_: *const Fl_Menu_Item) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Menu_Item_set_shortcut(
+        self_: *mut Fl_Menu_Item,
+        shortcut: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Menu_Item_first_child(self_: *mut Fl_Menu_Item) -> *mut Fl_Menu_Item;
+}
+extern "C" {
+    pub fn Fl_Menu_Item_size(self_: *const Fl_Menu_Item) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Menu_Item_at(
+        self_: *mut Fl_Menu_Item,
+        idx: ::std::os::raw::c_int,
+    ) -> *mut Fl_Menu_Item;
+}
+extern "C" {
+    pub fn Fl_Menu_Item_test_shortcut(self_: *mut Fl_Menu_Item) -> *mut Fl_Menu_Item;
+}
+pub type custom_label_draw_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        label: *const ::std::os::raw::c_char,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        color: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int,
+>;
+pub type custom_label_measure_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        label: *const ::std::os::raw::c_char,
+        w: *mut ::std::os::raw::c_int,
+        h: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int,
+>;
+extern "C" {
+    pub fn Fl_Menu_Item_draw(
+        self_: *const Fl_Menu_Item,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        menu: *mut Fl_Widget,
+        selected: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Menu_Item_measure(
+        self_: *const Fl_Menu_Item,
+        h: *mut ::std::os::raw::c_int,
+        menu: *mut Fl_Widget,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Menu_Item_set_label_drawer(
+        self_: *mut Fl_Menu_Item,
+        draw: custom_label_draw_callback,
+        measure: custom_label_measure_callback,
+    );
+}
+extern "C" {
+    pub fn Fl_Menu_Item_from_menu(menu: *mut Fl_Widget) -> *mut Fl_Menu_Item;
+}
 extern "C" {
     pub fn Fl_Choice_set_value_item(
         self_: *mut Fl_Choice,
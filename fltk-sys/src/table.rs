@@ -16,6 +16,22 @@ pub type custom_handler_callback = ::std::option::Option<
 >;
 pub type custom_draw_callback =
     ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>;
+pub type custom_filter_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        pixels: *mut ::std::os::raw::c_uchar,
+        count: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type custom_overlay_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
 extern "C" {
     pub fn Fl_Widget_callback_with_captures(
         arg1: *mut Fl_Widget,
@@ -23,6 +39,50 @@ extern "C" {
         arg2: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Widget_apply_draw_filter(
+        w: *mut Fl_Widget,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn Fl_Widget_implicit_parenting() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_set_implicit_parenting(flag: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Widget_parenting_begin() -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_parenting_end(current: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn Fl_Widget_touch_mode() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_set_touch_mode(flag: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Widget_apply_touch_mode(w: *mut Fl_Widget);
+}
+pub type Fl_Input_Focus_Cb = ::std::option::Option<
+    unsafe extern "C" fn(
+        w: *mut Fl_Widget,
+        focused: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+extern "C" {
+    pub fn Fl_Widget_set_input_focus_callback(
+        cb: Fl_Input_Focus_Cb,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_focus_changed(w: *mut Fl_Widget, focused: ::std::os::raw::c_int);
+}
 extern "C" {
     pub fn Fl_Widget_new(
         x: ::std::os::raw::c_int,
@@ -168,6 +228,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Widget_set_draw_filter(
+        self_: *mut Fl_Widget,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_set_draw_over(
+        self_: *mut Fl_Widget,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Widget_set_trigger(arg1: *mut Fl_Widget, arg2: ::std::os::raw::c_int);
 }
@@ -351,6 +425,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Group_set_draw_filter(
+        self_: *mut Fl_Group,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Group_set_draw_over(
+        self_: *mut Fl_Group,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Group_set_trigger(arg1: *mut Fl_Group, arg2: ::std::os::raw::c_int);
 }
@@ -421,6 +509,18 @@ extern "C" {
 extern "C" {
     pub fn Fl_Group_resizable(self_: *mut Fl_Group, arg1: *mut ::std::os::raw::c_void);
 }
+extern "C" {
+    pub fn Fl_Group_set_clip_children(self_: *mut Fl_Group, c: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Group_clip_children(self_: *mut Fl_Group) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Group_draw_children(self_: *mut Fl_Group);
+}
+extern "C" {
+    pub fn Fl_Group_override_draw(self_: *mut Fl_Group, flag: ::std::os::raw::c_int);
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Pack {
@@ -571,6 +671,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Pack_set_draw_filter(
+        self_: *mut Fl_Pack,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Pack_set_draw_over(
+        self_: *mut Fl_Pack,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Pack_set_trigger(arg1: *mut Fl_Pack, arg2: ::std::os::raw::c_int);
 }
@@ -641,6 +755,18 @@ extern "C" {
 extern "C" {
     pub fn Fl_Pack_resizable(self_: *mut Fl_Pack, arg1: *mut ::std::os::raw::c_void);
 }
+extern "C" {
+    pub fn Fl_Pack_set_clip_children(self_: *mut Fl_Pack, c: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Pack_clip_children(self_: *mut Fl_Pack) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Pack_draw_children(self_: *mut Fl_Pack);
+}
+extern "C" {
+    pub fn Fl_Pack_override_draw(self_: *mut Fl_Pack, flag: ::std::os::raw::c_int);
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Scroll {
@@ -791,6 +917,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Scroll_set_draw_filter(
+        self_: *mut Fl_Scroll,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Scroll_set_draw_over(
+        self_: *mut Fl_Scroll,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Scroll_set_trigger(arg1: *mut Fl_Scroll, arg2: ::std::os::raw::c_int);
 }
@@ -861,6 +1001,18 @@ extern "C" {
 extern "C" {
     pub fn Fl_Scroll_resizable(self_: *mut Fl_Scroll, arg1: *mut ::std::os::raw::c_void);
 }
+extern "C" {
+    pub fn Fl_Scroll_set_clip_children(self_: *mut Fl_Scroll, c: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Scroll_clip_children(self_: *mut Fl_Scroll) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Scroll_draw_children(self_: *mut Fl_Scroll);
+}
+extern "C" {
+    pub fn Fl_Scroll_override_draw(self_: *mut Fl_Scroll, flag: ::std::os::raw::c_int);
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Tabs {
@@ -1011,6 +1163,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Tabs_set_draw_filter(
+        self_: *mut Fl_Tabs,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Tabs_set_draw_over(
+        self_: *mut Fl_Tabs,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Tabs_set_trigger(arg1: *mut Fl_Tabs, arg2: ::std::os::raw::c_int);
 }
@@ -1081,6 +1247,18 @@ extern "C" {
 extern "C" {
     pub fn Fl_Tabs_resizable(self_: *mut Fl_Tabs, arg1: *mut ::std::os::raw::c_void);
 }
+extern "C" {
+    pub fn Fl_Tabs_set_clip_children(self_: *mut Fl_Tabs, c: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Tabs_clip_children(self_: *mut Fl_Tabs) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Tabs_draw_children(self_: *mut Fl_Tabs);
+}
+extern "C" {
+    pub fn Fl_Tabs_override_draw(self_: *mut Fl_Tabs, flag: ::std::os::raw::c_int);
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Tile {
@@ -1231,6 +1409,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Tile_set_draw_filter(
+        self_: *mut Fl_Tile,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Tile_set_draw_over(
+        self_: *mut Fl_Tile,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Tile_set_trigger(arg1: *mut Fl_Tile, arg2: ::std::os::raw::c_int);
 }
@@ -1301,6 +1493,18 @@ extern "C" {
 extern "C" {
     pub fn Fl_Tile_resizable(self_: *mut Fl_Tile, arg1: *mut ::std::os::raw::c_void);
 }
+extern "C" {
+    pub fn Fl_Tile_set_clip_children(self_: *mut Fl_Tile, c: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Tile_clip_children(self_: *mut Fl_Tile) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Tile_draw_children(self_: *mut Fl_Tile);
+}
+extern "C" {
+    pub fn Fl_Tile_override_draw(self_: *mut Fl_Tile, flag: ::std::os::raw::c_int);
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Wizard {
@@ -1451,6 +1655,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Wizard_set_draw_filter(
+        self_: *mut Fl_Wizard,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Wizard_set_draw_over(
+        self_: *mut Fl_Wizard,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Wizard_set_trigger(arg1: *mut Fl_Wizard, arg2: ::std::os::raw::c_int);
 }
@@ -1533,6 +1751,18 @@ extern "C" {
 extern "C" {
     pub fn Fl_Wizard_resizable(self_: *mut Fl_Wizard, arg1: *mut ::std::os::raw::c_void);
 }
+extern "C" {
+    pub fn Fl_Wizard_set_clip_children(self_: *mut Fl_Wizard, c: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Wizard_clip_children(self_: *mut Fl_Wizard) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Wizard_draw_children(self_: *mut Fl_Wizard);
+}
+extern "C" {
+    pub fn Fl_Wizard_override_draw(self_: *mut Fl_Wizard, flag: ::std::os::raw::c_int);
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Color_Chooser {
@@ -1698,6 +1928,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Color_Chooser_set_draw_filter(
+        self_: *mut Fl_Color_Chooser,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Color_Chooser_set_draw_over(
+        self_: *mut Fl_Color_Chooser,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Color_Chooser_set_trigger(arg1: *mut Fl_Color_Chooser, arg2: ::std::os::raw::c_int);
 }
@@ -1788,6 +2032,24 @@ extern "C" {
         arg1: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Color_Chooser_set_clip_children(
+        self_: *mut Fl_Color_Chooser,
+        c: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Color_Chooser_clip_children(self_: *mut Fl_Color_Chooser) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Color_Chooser_draw_children(self_: *mut Fl_Color_Chooser);
+}
+extern "C" {
+    pub fn Fl_Color_Chooser_override_draw(
+        self_: *mut Fl_Color_Chooser,
+        flag: ::std::os::raw::c_int,
+    );
+}
 extern "C" {
     pub fn Fl_Pack_spacing(self_: *mut Fl_Pack) -> ::std::os::raw::c_int;
 }
@@ -1944,6 +2206,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Table_set_draw_filter(
+        self_: *mut Fl_Table,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Table_set_draw_over(
+        self_: *mut Fl_Table,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Table_set_trigger(arg1: *mut Fl_Table, arg2: ::std::os::raw::c_int);
 }
@@ -2014,6 +2290,18 @@ extern "C" {
 extern "C" {
     pub fn Fl_Table_resizable(self_: *mut Fl_Table, arg1: *mut ::std::os::raw::c_void);
 }
+extern "C" {
+    pub fn Fl_Table_set_clip_children(self_: *mut Fl_Table, c: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Table_clip_children(self_: *mut Fl_Table) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Table_draw_children(self_: *mut Fl_Table);
+}
+extern "C" {
+    pub fn Fl_Table_override_draw(self_: *mut Fl_Table, flag: ::std::os::raw::c_int);
+}
 extern "C" {
     pub fn Fl_Table_set_table_box(self_: *mut Fl_Table, val: ::std::os::raw::c_int);
 }
@@ -2359,6 +2647,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Table_Row_set_draw_filter(
+        self_: *mut Fl_Table_Row,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Table_Row_set_draw_over(
+        self_: *mut Fl_Table_Row,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Table_Row_set_trigger(arg1: *mut Fl_Table_Row, arg2: ::std::os::raw::c_int);
 }
@@ -2432,6 +2734,18 @@ extern "C" {
 extern "C" {
     pub fn Fl_Table_Row_resizable(self_: *mut Fl_Table_Row, arg1: *mut ::std::os::raw::c_void);
 }
+extern "C" {
+    pub fn Fl_Table_Row_set_clip_children(self_: *mut Fl_Table_Row, c: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Table_Row_clip_children(self_: *mut Fl_Table_Row) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Table_Row_draw_children(self_: *mut Fl_Table_Row);
+}
+extern "C" {
+    pub fn Fl_Table_Row_override_draw(self_: *mut Fl_Table_Row, flag: ::std::os::raw::c_int);
+}
 extern "C" {
     pub fn Fl_Table_Row_set_table_box(self_: *mut Fl_Table_Row, val: ::std::os::raw::c_int);
 }
@@ -16,6 +16,22 @@ pub type custom_handler_callback = ::std::option::Option<
 >;
 pub type custom_draw_callback =
     ::std::option::Option<unsafe extern "C" fn(arg1: *mut ::std::os::raw::c_void)>;
+pub type custom_filter_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        pixels: *mut ::std::os::raw::c_uchar,
+        count: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type custom_overlay_callback = ::std::option::Option<
+    unsafe extern "C" fn(
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
 extern "C" {
     pub fn Fl_Widget_callback_with_captures(
         arg1: *mut Fl_Widget,
@@ -23,6 +39,50 @@ extern "C" {
         arg2: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Widget_apply_draw_filter(
+        w: *mut Fl_Widget,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn Fl_Widget_implicit_parenting() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_set_implicit_parenting(flag: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Widget_parenting_begin() -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_parenting_end(current: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+    pub fn Fl_Widget_touch_mode() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Widget_set_touch_mode(flag: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Widget_apply_touch_mode(w: *mut Fl_Widget);
+}
+pub type Fl_Input_Focus_Cb = ::std::option::Option<
+    unsafe extern "C" fn(
+        w: *mut Fl_Widget,
+        focused: ::std::os::raw::c_int,
+        data: *mut ::std::os::raw::c_void,
+    ),
+>;
+extern "C" {
+    pub fn Fl_Widget_set_input_focus_callback(
+        cb: Fl_Input_Focus_Cb,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_focus_changed(w: *mut Fl_Widget, focused: ::std::os::raw::c_int);
+}
 extern "C" {
     pub fn Fl_Widget_new(
         x: ::std::os::raw::c_int,
@@ -168,6 +228,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Widget_set_draw_filter(
+        self_: *mut Fl_Widget,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Widget_set_draw_over(
+        self_: *mut Fl_Widget,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Widget_set_trigger(arg1: *mut Fl_Widget, arg2: ::std::os::raw::c_int);
 }
@@ -222,6 +296,12 @@ extern "C" {
 extern "C" {
     pub fn Fl_Text_Buffer_delete(arg1: *mut Fl_Text_Buffer);
 }
+extern "C" {
+    pub fn Fl_Text_Buffer_retain(self_: *mut Fl_Text_Buffer);
+}
+extern "C" {
+    pub fn Fl_Text_Buffer_refcount(self_: *const Fl_Text_Buffer) -> ::std::os::raw::c_int;
+}
 extern "C" {
     pub fn Fl_Text_Buffer_text(self_: *mut Fl_Text_Buffer) -> *const ::std::os::raw::c_char;
 }
@@ -288,6 +368,12 @@ extern "C" {
         buflen: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_Text_Buffer_savefile(
+        self_: *mut Fl_Text_Buffer,
+        file: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
 extern "C" {
     pub fn Fl_Text_Buffer_tab_distance(self_: *const Fl_Text_Buffer) -> ::std::os::raw::c_int;
 }
@@ -317,6 +403,23 @@ extern "C" {
         end: *mut ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_Text_Buffer_secondary_select(
+        self_: *mut Fl_Text_Buffer,
+        start: ::std::os::raw::c_int,
+        end: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Text_Buffer_secondary_unselect(self_: *mut Fl_Text_Buffer);
+}
+extern "C" {
+    pub fn Fl_Text_Buffer_secondary_selection_position(
+        self_: *mut Fl_Text_Buffer,
+        start: *mut ::std::os::raw::c_int,
+        end: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
 extern "C" {
     pub fn Fl_Text_Buffer_selection_text(self_: *mut Fl_Text_Buffer)
         -> *mut ::std::os::raw::c_char;
@@ -366,6 +469,19 @@ extern "C" {
         pos: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_Text_Buffer_line_end(
+        self_: *const Fl_Text_Buffer,
+        pos: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Text_Buffer_skip_lines(
+        self_: *mut Fl_Text_Buffer,
+        startPos: ::std::os::raw::c_int,
+        nLines: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
 extern "C" {
     pub fn Fl_Text_Buffer_word_start(
         self_: *const Fl_Text_Buffer,
@@ -385,6 +501,39 @@ extern "C" {
         endPos: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_Text_Buffer_utf8_align(
+        self_: *const Fl_Text_Buffer,
+        pos: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Text_Buffer_char_count(self_: *const Fl_Text_Buffer) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Text_Buffer_char_to_byte(
+        self_: *const Fl_Text_Buffer,
+        idx: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Text_Buffer_byte_to_char(
+        self_: *const Fl_Text_Buffer,
+        pos: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Text_Buffer_gap_halves(
+        self_: *const Fl_Text_Buffer,
+        first: *mut *const ::std::os::raw::c_char,
+        first_len: *mut ::std::os::raw::c_int,
+        second: *mut *const ::std::os::raw::c_char,
+        second_len: *mut ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Text_Buffer_contiguous(self_: *mut Fl_Text_Buffer) -> *const ::std::os::raw::c_char;
+}
 extern "C" {
     pub fn Fl_Text_Buffer_add_modify_callback(
         self_: *mut Fl_Text_Buffer,
@@ -402,6 +551,25 @@ extern "C" {
 extern "C" {
     pub fn Fl_Text_Buffer_call_modify_callbacks(self_: *mut Fl_Text_Buffer);
 }
+pub type Fl_Text_Buffer_Line_Ending_Cb = ::std::option::Option<
+    unsafe extern "C" fn(ending: ::std::os::raw::c_int, data: *mut ::std::os::raw::c_void),
+>;
+extern "C" {
+    pub fn Fl_Text_Buffer_line_ending(self_: *const Fl_Text_Buffer) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Text_Buffer_set_line_ending(
+        self_: *mut Fl_Text_Buffer,
+        ending: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Text_Buffer_set_line_ending_callback(
+        self_: *mut Fl_Text_Buffer,
+        cb: Fl_Text_Buffer_Line_Ending_Cb,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Text_Display {
@@ -561,6 +729,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Text_Display_set_draw_filter(
+        self_: *mut Fl_Text_Display,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Text_Display_set_draw_over(
+        self_: *mut Fl_Text_Display,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Text_Display_set_trigger(arg1: *mut Fl_Text_Display, arg2: ::std::os::raw::c_int);
 }
@@ -684,6 +866,21 @@ extern "C" {
         sz: ::std::os::raw::c_int,
     );
 }
+extern "C" {
+    pub fn Fl_Text_Display_set_style_table_entry_ext(
+        self_: *mut Fl_Text_Display,
+        sbuf: *mut ::std::os::raw::c_void,
+        color: *mut ::std::os::raw::c_uint,
+        font: *mut ::std::os::raw::c_int,
+        fontsz: *mut ::std::os::raw::c_int,
+        attr: *mut ::std::os::raw::c_uint,
+        bgcolor: *mut ::std::os::raw::c_uint,
+        sz: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Text_Display_clear_style_table(self_: *mut Fl_Text_Display);
+}
 extern "C" {
     pub fn Fl_Text_Display_set_cursor_style(
         arg1: *mut Fl_Text_Display,
@@ -848,6 +1045,31 @@ extern "C" {
         y: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_Text_Display_linenumber_position(
+        self_: *const Fl_Text_Display,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Text_Display_show_insert_position(self_: *mut Fl_Text_Display);
+}
+extern "C" {
+    pub fn Fl_Text_Display_set_secondary_selection_color(
+        self_: *mut Fl_Text_Display,
+        color: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn Fl_Text_Display_secondary_selection_color(self_: *const Fl_Text_Display) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn Fl_Text_Display_set_highlight_color(self_: *mut Fl_Text_Display, color: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn Fl_Text_Display_highlight_color(self_: *const Fl_Text_Display) -> ::std::os::raw::c_uint;
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Text_Editor {
@@ -1004,6 +1226,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Text_Editor_set_draw_filter(
+        self_: *mut Fl_Text_Editor,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Text_Editor_set_draw_over(
+        self_: *mut Fl_Text_Editor,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Text_Editor_set_trigger(arg1: *mut Fl_Text_Editor, arg2: ::std::os::raw::c_int);
 }
@@ -1127,6 +1363,21 @@ extern "C" {
         sz: ::std::os::raw::c_int,
     );
 }
+extern "C" {
+    pub fn Fl_Text_Editor_set_style_table_entry_ext(
+        self_: *mut Fl_Text_Editor,
+        sbuf: *mut ::std::os::raw::c_void,
+        color: *mut ::std::os::raw::c_uint,
+        font: *mut ::std::os::raw::c_int,
+        fontsz: *mut ::std::os::raw::c_int,
+        attr: *mut ::std::os::raw::c_uint,
+        bgcolor: *mut ::std::os::raw::c_uint,
+        sz: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Text_Editor_clear_style_table(self_: *mut Fl_Text_Editor);
+}
 extern "C" {
     pub fn Fl_Text_Editor_set_cursor_style(arg1: *mut Fl_Text_Editor, style: ::std::os::raw::c_int);
 }
@@ -1286,6 +1537,31 @@ extern "C" {
         y: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_Text_Editor_linenumber_position(
+        self_: *const Fl_Text_Editor,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Text_Editor_show_insert_position(self_: *mut Fl_Text_Editor);
+}
+extern "C" {
+    pub fn Fl_Text_Editor_set_secondary_selection_color(
+        self_: *mut Fl_Text_Editor,
+        color: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn Fl_Text_Editor_secondary_selection_color(self_: *const Fl_Text_Editor) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn Fl_Text_Editor_set_highlight_color(self_: *mut Fl_Text_Editor, color: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn Fl_Text_Editor_highlight_color(self_: *const Fl_Text_Editor) -> ::std::os::raw::c_uint;
+}
 extern "C" {
     pub fn kf_copy(e: *mut Fl_Text_Editor) -> ::std::os::raw::c_int;
 }
@@ -1298,6 +1574,51 @@ extern "C" {
 extern "C" {
     pub fn kf_undo(e: *mut Fl_Text_Editor) -> ::std::os::raw::c_int;
 }
+pub type Fl_Text_Editor_Key_Func = ::std::option::Option<
+    unsafe extern "C" fn(
+        key: ::std::os::raw::c_int,
+        arg1: *mut Fl_Text_Editor,
+        data: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int,
+>;
+extern "C" {
+    pub fn Fl_Text_Editor_add_key_binding(
+        self_: *mut Fl_Text_Editor,
+        key: ::std::os::raw::c_int,
+        state: ::std::os::raw::c_int,
+        cb: Fl_Text_Editor_Key_Func,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Text_Editor_remove_key_binding(
+        self_: *mut Fl_Text_Editor,
+        key: ::std::os::raw::c_int,
+        state: ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Text_Editor_protect_range(
+        self_: *mut Fl_Text_Editor,
+        start: ::std::os::raw::c_int,
+        end: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Text_Editor_clear_protections(self_: *mut Fl_Text_Editor);
+}
+extern "C" {
+    pub fn Fl_Text_Editor_edits_protected(
+        self_: *mut Fl_Text_Editor,
+        event: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Text_Editor_insert_mode(self_: *mut Fl_Text_Editor) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Text_Editor_set_insert_mode(self_: *mut Fl_Text_Editor, b: ::std::os::raw::c_int);
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Fl_Simple_Terminal {
@@ -1475,6 +1796,20 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
+extern "C" {
+    pub fn Fl_Simple_Terminal_set_draw_filter(
+        self_: *mut Fl_Simple_Terminal,
+        cb: custom_filter_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_set_draw_over(
+        self_: *mut Fl_Simple_Terminal,
+        cb: custom_overlay_callback,
+        data: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void;
+}
 extern "C" {
     pub fn Fl_Simple_Terminal_set_trigger(
         arg1: *mut Fl_Simple_Terminal,
@@ -1630,6 +1965,21 @@ extern "C" {
         sz: ::std::os::raw::c_int,
     );
 }
+extern "C" {
+    pub fn Fl_Simple_Terminal_set_style_table_entry_ext(
+        self_: *mut Fl_Simple_Terminal,
+        sbuf: *mut ::std::os::raw::c_void,
+        color: *mut ::std::os::raw::c_uint,
+        font: *mut ::std::os::raw::c_int,
+        fontsz: *mut ::std::os::raw::c_int,
+        attr: *mut ::std::os::raw::c_uint,
+        bgcolor: *mut ::std::os::raw::c_uint,
+        sz: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_clear_style_table(self_: *mut Fl_Simple_Terminal);
+}
 extern "C" {
     pub fn Fl_Simple_Terminal_set_cursor_style(
         arg1: *mut Fl_Simple_Terminal,
@@ -1807,3 +2157,71 @@ extern "C" {
         y: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn Fl_Simple_Terminal_linenumber_position(
+        self_: *const Fl_Simple_Terminal,
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_show_insert_position(self_: *mut Fl_Simple_Terminal);
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_set_secondary_selection_color(
+        self_: *mut Fl_Simple_Terminal,
+        color: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_secondary_selection_color(self_: *const Fl_Simple_Terminal) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_set_highlight_color(self_: *mut Fl_Simple_Terminal, color: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_highlight_color(self_: *const Fl_Simple_Terminal) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_ansi(self_: *const Fl_Simple_Terminal) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_set_ansi(self_: *mut Fl_Simple_Terminal, val: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_append(
+        self_: *mut Fl_Simple_Terminal,
+        s: *const ::std::os::raw::c_char,
+    );
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_history_lines(
+        self_: *const Fl_Simple_Terminal,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_set_history_lines(
+        self_: *mut Fl_Simple_Terminal,
+        val: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_stay_at_bottom(
+        self_: *const Fl_Simple_Terminal,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_set_stay_at_bottom(
+        self_: *mut Fl_Simple_Terminal,
+        val: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn Fl_Simple_Terminal_append_styled(
+        self_: *mut Fl_Simple_Terminal,
+        s: *const ::std::os::raw::c_char,
+        color: ::std::os::raw::c_uint,
+        font: ::std::os::raw::c_int,
+        size: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
@@ -0,0 +1,49 @@
+//! Runs random edits on a TextBuffer and checks that its text stays valid UTF-8,
+//! run it using: cargo fuzz run text_buffer
+#![no_main]
+use arbitrary::Arbitrary;
+use fltk::text::TextBuffer;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Insert(u16, String),
+    Remove(u16, u16),
+    Replace(u16, u16, String),
+    Select(u16, u16),
+    Unselect,
+    Undo,
+}
+
+fuzz_target!(|input: (String, Vec<Op>)| {
+    let (initial, ops) = input;
+    // Interior nul bytes can't cross the FFI boundary
+    if initial.contains('\0') {
+        return;
+    }
+    let mut buf = TextBuffer::with_text(&initial);
+    for op in ops {
+        match op {
+            Op::Insert(pos, s) if !s.contains('\0') => buf.insert(pos as u32, &s),
+            Op::Remove(start, end) => buf.remove(start as u32, end as u32),
+            Op::Replace(start, end, s) if !s.contains('\0') => {
+                buf.replace(start as u32, end as u32, &s)
+            }
+            Op::Select(start, end) => buf.select(start as u32, end as u32),
+            Op::Unselect => buf.unselect(),
+            Op::Undo => {
+                let _ = buf.undo();
+            }
+            _ => (),
+        }
+        let text = buf.text();
+        assert!(matches!(buf.text_slice(), std::borrow::Cow::Borrowed(_)));
+        assert!(text.len() == buf.length() as usize);
+        assert!(text.chars().count() == buf.char_count() as usize);
+        if let Some((start, end)) = buf.selection_range() {
+            assert!(start <= end && end <= buf.length());
+            assert!(text.is_char_boundary(start as usize) && text.is_char_boundary(end as usize));
+            assert!(buf.selection_text() == text[start as usize..end as usize]);
+        }
+    }
+});
@@ -133,10 +133,7 @@ pub fn event() -> Event {
 
 /// Returns the presed key
 pub fn event_key() -> Key {
-    unsafe {
-        let x = Fl_event_key();
-        mem::transmute(x)
-    }
+    unsafe { Key::from_i32(Fl_event_key()) }
 }
 
 /// Returns a textual representation of the latest event
@@ -190,7 +187,7 @@ pub fn event_length() -> u32 {
 
 /// Returns the state of the event
 pub fn event_state() -> Shortcut {
-    unsafe { mem::transmute(Fl_event_state()) }
+    unsafe { Shortcut::from_i32(Fl_event_state()) }
 }
 
 /// Returns a pair of the width and height of the screen
@@ -203,6 +200,36 @@ pub fn screen_size() -> (f64, f64) {
     }
 }
 
+/// Returns the x, y, width and height of the usable area of the screen containing the point,
+/// excluding task bars and docks
+pub fn screen_work_area(x: i32, y: i32) -> (i32, i32, i32, i32) {
+    let mut wx = 0;
+    let mut wy = 0;
+    let mut ww = 0;
+    let mut wh = 0;
+    unsafe { Fl_screen_work_area(&mut wx, &mut wy, &mut ww, &mut wh, x, y) }
+    (wx, wy, ww, wh)
+}
+
+/// Sets a callback called when monitors are added or removed or the screen resolution changes,
+/// replacing any previously set callback
+pub fn on_screen_change(cb: Box<dyn FnMut()>) {
+    unsafe {
+        unsafe extern "C" fn shim(data: *mut raw::c_void) {
+            let a: *mut Box<dyn FnMut()> = mem::transmute(data);
+            let f: &mut (dyn FnMut()) = &mut **a;
+            f();
+        }
+        let a: *mut Box<dyn FnMut()> = Box::into_raw(Box::new(cb));
+        let data: *mut raw::c_void = mem::transmute(a);
+        let callback: Fl_Screen_Change_Handler = Some(shim);
+        let old = Fl_set_screen_change_callback(callback, data);
+        if !old.is_null() {
+            let _ = Box::from_raw(old as *mut Box<dyn FnMut()>);
+        }
+    }
+}
+
 /// Used for widgets implementing the InputExt, pastes content from the clipboard
 pub fn paste<T>(widget: &T)
 where
@@ -222,15 +249,11 @@ where
         widget.top_window().unwrap().takes_events() && widget.takes_events(),
         "Handling events requires that the window and widget be active!"
     );
+    crate::widget::register_data_deleter();
     unsafe {
-        unsafe extern "C" fn shim(_wid: *mut fltk_sys::widget::Fl_Widget, data: *mut raw::c_void) {
-            let a: *mut Box<dyn FnMut()> = mem::transmute(data);
-            let f: &mut (dyn FnMut()) = &mut **a;
-            f();
-        }
         let a: *mut Box<dyn FnMut()> = Box::into_raw(Box::new(cb));
         let data: *mut raw::c_void = mem::transmute(a);
-        let callback: fltk_sys::widget::Fl_Callback = Some(shim);
+        let callback: fltk_sys::widget::Fl_Callback = Some(crate::widget::callback_shim);
         fltk_sys::widget::Fl_Widget_callback_with_captures(widget.as_widget_ptr(), callback, data);
     }
 }
@@ -296,6 +319,23 @@ pub fn add_handler(cb: fn(Event) -> bool) {
     }
 }
 
+/// Adds a handler receiving the platform's native events before FLTK processes them,
+/// as a MSG pointer on Windows, an XEvent pointer on X11 and an NSEvent pointer on MacOS.
+/// Returning true stops FLTK from processing the event. Requires the system-events feature
+#[cfg(feature = "system-events")]
+pub fn add_system_handler(cb: Box<dyn FnMut(*mut raw::c_void) -> bool>) {
+    unsafe {
+        unsafe extern "C" fn shim(event: *mut raw::c_void, data: *mut raw::c_void) -> raw::c_int {
+            let a: *mut Box<dyn FnMut(*mut raw::c_void) -> bool> = mem::transmute(data);
+            let f: &mut (dyn FnMut(*mut raw::c_void) -> bool) = &mut **a;
+            f(event) as raw::c_int
+        }
+        let a: *mut Box<dyn FnMut(*mut raw::c_void) -> bool> = Box::into_raw(Box::new(cb));
+        let data: *mut raw::c_void = mem::transmute(a);
+        Fl_add_system_handler(Some(shim), data);
+    }
+}
+
 fn wait() -> bool {
     unsafe {
         match Fl_wait() {
@@ -473,6 +513,283 @@ pub fn remove_timeout(cb: Box<dyn FnMut()>) {
     }
 }
 
+/// Defines the conditions on which a file descriptor handler is called
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FdWhen {
+    /// The file descriptor has data to read
+    Read = 1,
+    /// The file descriptor can be written to
+    Write = 4,
+    /// An exception occured on the file descriptor
+    Except = 8,
+}
+
+type FdHandler = std::rc::Rc<std::cell::RefCell<Box<dyn FnMut(i32)>>>;
+
+thread_local! {
+    static FD_HANDLERS: std::cell::RefCell<Vec<(i32, FdWhen, FdHandler)>> =
+        std::cell::RefCell::new(vec![]);
+}
+
+unsafe extern "C" fn fd_shim(fd: raw::c_int, data: *mut raw::c_void) {
+    let when: FdWhen = mem::transmute(data as i32);
+    let handler = FD_HANDLERS.with(|h| {
+        h.borrow()
+            .iter()
+            .find(|(f, w, _)| *f == fd && *w == when)
+            .map(|(_, _, cb)| cb.clone())
+    });
+    // The handler is kept alive while running, it might remove itself
+    if let Some(handler) = handler {
+        if let Ok(mut cb) = handler.try_borrow_mut() {
+            (*cb)(fd);
+        }
+    }
+}
+
+/// Calls `cb` with the file descriptor from within the event loop whenever the condition
+/// is met, replacing any handler previously added for the same descriptor and condition.
+/// Any file descriptor can be used on Unix, only sockets are supported on Windows
+pub fn add_fd(fd: i32, when: FdWhen, cb: Box<dyn FnMut(i32)>) {
+    remove_fd_handler(fd, when);
+    FD_HANDLERS.with(|h| {
+        h.borrow_mut()
+            .push((fd, when, std::rc::Rc::new(std::cell::RefCell::new(cb))))
+    });
+    unsafe { Fl_add_fd(fd, when as i32, Some(fd_shim), when as i32 as *mut raw::c_void) }
+}
+
+/// Removes all handlers of the file descriptor
+pub fn remove_fd(fd: i32) {
+    for when in &[FdWhen::Read, FdWhen::Write, FdWhen::Except] {
+        remove_fd_handler(fd, *when);
+    }
+}
+
+fn remove_fd_handler(fd: i32, when: FdWhen) {
+    let removed = FD_HANDLERS.with(|h| {
+        let mut h = h.borrow_mut();
+        let len = h.len();
+        h.retain(|(f, w, _)| !(*f == fd && *w == when));
+        len != h.len()
+    });
+    if removed {
+        unsafe { Fl_remove_fd(fd, when as i32) }
+    }
+}
+
+/// Defines the priority of an idle task, higher priority tasks run first
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TaskPriority {
+    Low,
+    Normal,
+    High,
+}
+
+/// Defines whether an idle task has more work to do
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TaskState {
+    Continue,
+    Done,
+}
+
+struct IdleTask {
+    priority: TaskPriority,
+    work: Box<dyn FnMut() -> TaskState>,
+}
+
+thread_local! {
+    static IDLE_TASKS: std::cell::RefCell<Vec<IdleTask>> = std::cell::RefCell::new(vec![]);
+}
+
+/// The time spent running idle tasks before yielding to the event loop
+const IDLE_SLICE: std::time::Duration = std::time::Duration::from_millis(10);
+
+unsafe extern "C" fn run_idle_tasks(_data: *mut raw::c_void) {
+    let start = std::time::Instant::now();
+    while start.elapsed() < IDLE_SLICE {
+        // Tasks of equal priority take turns, since a continuing task goes to the back
+        let task = IDLE_TASKS.with(|tasks| {
+            let mut tasks = tasks.borrow_mut();
+            let idx = (0..tasks.len()).max_by_key(|&i| (tasks[i].priority, std::cmp::Reverse(i)));
+            idx.map(|i| tasks.remove(i))
+        });
+        let mut task = match task {
+            Some(task) => task,
+            None => break,
+        };
+        if (task.work)() == TaskState::Continue {
+            IDLE_TASKS.with(|tasks| tasks.borrow_mut().push(task));
+        }
+    }
+    if IDLE_TASKS.with(|tasks| tasks.borrow().is_empty()) {
+        Fl_remove_idle(Some(run_idle_tasks), std::ptr::null_mut());
+    }
+}
+
+/// Spawns a task running in chunks while the event loop is idle, the task is called repeatedly
+/// until it returns TaskState::Done. Each chunk should be short so the UI stays responsive
+pub fn spawn_idle_task(priority: TaskPriority, task: Box<dyn FnMut() -> TaskState>) {
+    IDLE_TASKS.with(|tasks| {
+        tasks.borrow_mut().push(IdleTask {
+            priority,
+            work: task,
+        })
+    });
+    unsafe {
+        if Fl_has_idle(Some(run_idle_tasks), std::ptr::null_mut()) == 0 {
+            Fl_add_idle(Some(run_idle_tasks), std::ptr::null_mut());
+        }
+    }
+}
+
+struct Animation {
+    tracker: *mut raw::c_void,
+    widget: *mut fltk_sys::fl::Fl_Widget,
+    interval: f64,
+    next: std::time::Instant,
+    last: Option<std::time::Instant>,
+    cb: Box<dyn FnMut(f64)>,
+    stopped: std::rc::Rc<std::cell::Cell<bool>>,
+}
+
+/// Controls an animation started by add_animation
+#[derive(Debug, Clone)]
+pub struct AnimationHandle {
+    stopped: std::rc::Rc<std::cell::Cell<bool>>,
+}
+
+impl AnimationHandle {
+    /// Stops the animation, its callback isn't called anymore
+    pub fn stop(&self) {
+        self.stopped.set(true);
+    }
+
+    /// Returns whether the animation was stopped or its widget deleted
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.get()
+    }
+}
+
+unsafe extern "C" fn animation_tick(data: *mut raw::c_void) {
+    let anim = data as *mut Animation;
+    if (*anim).stopped.get() || Fl_Widget_Tracker_deleted((*anim).tracker) != 0 {
+        (*anim).stopped.set(true);
+        Fl_Widget_Tracker_delete((*anim).tracker);
+        let _ = Box::from_raw(anim);
+        return;
+    }
+    let now = std::time::Instant::now();
+    let interval = std::time::Duration::from_secs_f64((*anim).interval);
+    if Fl_widget_visible_r((*anim).widget) == 0 {
+        // Paused while hidden, the elapsed time isn't reported once shown again
+        (*anim).last = None;
+        (*anim).next = now + interval;
+        Fl_add_timeout((*anim).interval, Some(animation_tick), data);
+        return;
+    }
+    let dt = match (*anim).last {
+        Some(last) => now.duration_since(last).as_secs_f64(),
+        None => (*anim).interval,
+    };
+    (*anim).last = Some(now);
+    ((*anim).cb)(dt);
+    let mut wid =
+        crate::widget::Widget::from_widget_ptr((*anim).widget as *mut fltk_sys::widget::Fl_Widget);
+    wid.redraw();
+    (*anim).next += interval;
+    if (*anim).next < now {
+        // Frames were missed, skip them instead of firing in a burst
+        (*anim).next = now + interval;
+        Fl_add_timeout((*anim).interval, Some(animation_tick), data);
+    } else {
+        Fl_repeat_timeout((*anim).interval, Some(animation_tick), data);
+    }
+}
+
+/// Drives an animation at the given frame rate, calling `cb` with the seconds elapsed since the
+/// previous frame then redrawing the widget. The animation pauses while the widget isn't visible,
+/// and stops once the widget is deleted or the returned handle is stopped
+pub fn add_animation<W: WidgetExt>(
+    widget: &W,
+    fps: u32,
+    cb: Box<dyn FnMut(f64)>,
+) -> AnimationHandle {
+    assert!(fps > 0, "fps must be greater than 0!");
+    let interval = 1.0 / fps as f64;
+    let stopped = std::rc::Rc::new(std::cell::Cell::new(false));
+    unsafe {
+        let ptr = widget.as_widget_ptr() as *mut fltk_sys::fl::Fl_Widget;
+        let anim = Box::new(Animation {
+            tracker: Fl_Widget_Tracker_new(ptr),
+            widget: ptr,
+            interval,
+            next: std::time::Instant::now() + std::time::Duration::from_secs_f64(interval),
+            last: None,
+            cb,
+            stopped: stopped.clone(),
+        });
+        Fl_add_timeout(interval, Some(animation_tick), Box::into_raw(anim) as *mut raw::c_void);
+    }
+    AnimationHandle { stopped }
+}
+
+/// Sets whether widgets are added to the current group on construction, which is the default.
+/// Without implicit parenting, constructed widgets have no parent and groups don't become
+/// current, widgets are placed using GroupExt::add or WidgetExt::set_parent_explicit
+pub fn set_implicit_parenting(flag: bool) {
+    unsafe { fltk_sys::widget::Fl_Widget_set_implicit_parenting(flag as i32) }
+}
+
+/// Returns whether widgets are added to the current group on construction
+pub fn implicit_parenting() -> bool {
+    unsafe {
+        match fltk_sys::widget::Fl_Widget_implicit_parenting() {
+            0 => false,
+            _ => true,
+        }
+    }
+}
+
+/// Enables touch mode for kiosk and touchscreen deployments, doubling the scrollbar width
+/// and enlarging the menu items and valuator handles of widgets constructed afterwards
+pub fn set_touch_mode(flag: bool) {
+    unsafe { fltk_sys::widget::Fl_Widget_set_touch_mode(flag as i32) }
+}
+
+/// Returns whether touch mode is enabled
+pub fn touch_mode() -> bool {
+    unsafe {
+        match fltk_sys::widget::Fl_Widget_touch_mode() {
+            0 => false,
+            _ => true,
+        }
+    }
+}
+
+/// Sets a callback called with an input widget or text editor and whether it gained or lost
+/// focus, e.g. to show and hide an on-screen keyboard
+pub fn set_input_focus_callback(cb: Box<dyn FnMut(crate::widget::Widget, bool)>) {
+    unsafe {
+        unsafe extern "C" fn shim(
+            wid: *mut fltk_sys::widget::Fl_Widget,
+            focused: raw::c_int,
+            data: *mut raw::c_void,
+        ) {
+            let a: *mut Box<dyn FnMut(crate::widget::Widget, bool)> = mem::transmute(data);
+            let f: &mut (dyn FnMut(crate::widget::Widget, bool)) = &mut **a;
+            f(crate::widget::Widget::from_raw(wid), focused != 0);
+        }
+        let a: *mut Box<dyn FnMut(crate::widget::Widget, bool)> = Box::into_raw(Box::new(cb));
+        let data: *mut raw::c_void = mem::transmute(a);
+        let old = fltk_sys::widget::Fl_Widget_set_input_focus_callback(Some(shim), data);
+        if !old.is_null() {
+            let _ = Box::from_raw(old as *mut Box<dyn FnMut(crate::widget::Widget, bool)>);
+        }
+    }
+}
+
 /// Returns whether a quit signal was sent
 pub fn should_program_quit() -> bool {
     unsafe {
@@ -524,6 +841,22 @@ pub fn event_inside(x: i32, y: i32, w: i32, h: i32) -> bool {
 //     }
 // }
 
+/// Returns whether selected text can be dragged out of text widgets
+pub fn dnd_text_ops() -> bool {
+    unsafe {
+        match Fl_dnd_text_ops() {
+            0 => false,
+            _ => true,
+        }
+    }
+}
+
+/// Sets whether selected text can be dragged out of text widgets, which is the default.
+/// See TextEditor::set_dnd for changing it per editor
+pub fn set_dnd_text_ops(flag: bool) {
+    unsafe { Fl_set_dnd_text_ops(flag as i32) }
+}
+
 /// Safe widget deletion during event handling. Deletes widgets and their children.
 pub fn delete_widget<Wid: WidgetExt>(wid: &Wid) {
     unsafe {
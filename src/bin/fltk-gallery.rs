@@ -0,0 +1,253 @@
+//! Shows every widget, one page each, along with the source of the page.
+//! Doubles as a manual check of the API surface, run it using:
+//! cargo run --features examples-gallery --bin fltk-gallery
+
+use fltk::{
+    app::{App, AppScheme},
+    browser::*,
+    button::*,
+    code_editor::*,
+    frame::*,
+    group::*,
+    input::*,
+    menu::*,
+    misc::*,
+    output::*,
+    table::*,
+    text::*,
+    tree::*,
+    valuator::*,
+    window::*,
+};
+
+const SOURCE: &str = include_str!("fltk-gallery.rs");
+
+// Pages are drawn in the demo area starting at these coordinates
+const X: i32 = 200;
+const Y: i32 = 10;
+
+macro_rules! pages {
+    ($($title:expr => $page:ident),* $(,)?) => {
+        vec![$(($title, stringify!($page), $page as fn())),*]
+    };
+}
+
+fn buttons() {
+    let _but = Button::new(X + 20, Y + 20, 120, 30, "Button");
+    let _ret = ReturnButton::new(X + 20, Y + 60, 120, 30, "Return");
+    let _rep = RepeatButton::new(X + 20, Y + 100, 120, 30, "Repeat");
+    let _tog = ToggleButton::new(X + 20, Y + 140, 120, 30, "Toggle");
+    let _round = RoundButton::new(X + 180, Y + 20, 120, 30, "Round");
+    let _check = CheckButton::new(X + 180, Y + 60, 120, 30, "Check");
+    let _light = LightButton::new(X + 180, Y + 100, 120, 30, "Light");
+    let _radio1 = RadioRoundButton::new(X + 340, Y + 20, 120, 30, "Radio 1");
+    let _radio2 = RadioRoundButton::new(X + 340, Y + 60, 120, 30, "Radio 2");
+    let _radio3 = RadioLightButton::new(X + 340, Y + 100, 120, 30, "Radio 3");
+}
+
+fn inputs() {
+    let mut inp = Input::new(X + 120, Y + 20, 200, 25, "Input");
+    inp.set_value("Hello");
+    let _int = IntInput::new(X + 120, Y + 50, 200, 25, "IntInput");
+    let _float = FloatInput::new(X + 120, Y + 80, 200, 25, "FloatInput");
+    let _secret = SecretInput::new(X + 120, Y + 110, 200, 25, "SecretInput");
+    let mut out = Output::new(X + 120, Y + 140, 200, 25, "Output");
+    out.set_value("Read only");
+    let mut hotkey = HotkeyInput::new(X + 120, Y + 170, 200, 25);
+    hotkey.input().set_label("HotkeyInput");
+    let _multi = MultilineInput::new(X + 120, Y + 200, 200, 60, "Multiline");
+}
+
+fn valuators() {
+    let _slider = Slider::new(X + 20, Y + 20, 30, 200, "Slider");
+    let _fill = FillSlider::new(X + 70, Y + 20, 30, 200, "Fill");
+    let _roller = Roller::new(X + 120, Y + 20, 30, 200, "Roller");
+    let _scroll = Scrollbar::new(X + 170, Y + 20, 20, 200, "Scroll");
+    let _dial = Dial::new(X + 220, Y + 20, 80, 80, "Dial");
+    let _line_dial = LineDial::new(X + 320, Y + 20, 80, 80, "LineDial");
+    let _hor = HorNiceSlider::new(X + 220, Y + 130, 200, 25, "HorNiceSlider");
+    let _value = HorValueSlider::new(X + 220, Y + 180, 200, 25, "HorValueSlider");
+    let _counter = Counter::new(X + 440, Y + 20, 200, 25, "Counter");
+    let _adjuster = Adjuster::new(X + 440, Y + 70, 80, 25, "Adjuster");
+    let _value_input = ValueInput::new(X + 440, Y + 120, 80, 25, "ValueInput");
+    let _value_output = ValueOutput::new(X + 440, Y + 170, 80, 25, "ValueOutput");
+}
+
+fn menus() {
+    let mut bar = MenuBar::new(X, Y, 680, 25, "");
+    bar.add(
+        "File/New",
+        Shortcut::Ctrl + 'n',
+        MenuFlag::Normal,
+        Box::new(|| ()),
+    );
+    bar.add(
+        "File/Quit",
+        Shortcut::Ctrl + 'q',
+        MenuFlag::Normal,
+        Box::new(|| ()),
+    );
+    bar.add(
+        "Edit/Toggle",
+        Shortcut::None,
+        MenuFlag::Toggle,
+        Box::new(|| ()),
+    );
+    let mut button = MenuButton::new(X + 20, Y + 60, 120, 30, "MenuButton");
+    button.add_choice("One|Two|Three");
+    let mut choice = Choice::new(X + 220, Y + 60, 120, 30, "Choice");
+    choice.add_choice("Red|Green|Blue");
+    choice.set_value(0);
+}
+
+fn browsers() {
+    let mut hold = HoldBrowser::new(X + 20, Y + 20, 200, 200, "HoldBrowser");
+    let mut multi = MultiBrowser::new(X + 240, Y + 20, 200, 200, "MultiBrowser");
+    for i in 1..=20 {
+        hold.add(&format!("Item {}", i));
+        multi.add(&format!("Item {}", i));
+    }
+    let mut tree = Tree::new(X + 460, Y + 20, 200, 200, "Tree");
+    tree.set_root_label("Root");
+    tree.add("Fruits/Apple");
+    tree.add("Fruits/Orange");
+    tree.add("Vegetables/Carrot");
+}
+
+fn groups() {
+    let tabs = Tabs::new(X + 20, Y + 20, 300, 220, "");
+    let first = Group::new(X + 20, Y + 45, 300, 195, "First");
+    let _frame = Frame::new(X + 40, Y + 60, 260, 30, "A tab");
+    first.end();
+    let second = Group::new(X + 20, Y + 45, 300, 195, "Second");
+    let _button = Button::new(X + 40, Y + 60, 120, 30, "Inside");
+    second.end();
+    tabs.end();
+    let mut pack = Pack::new(X + 360, Y + 20, 150, 220, "");
+    pack.set_spacing(5);
+    for label in ["Packed", "Vertically", "With spacing"].iter() {
+        let _button = Button::new(0, 0, 0, 30, label);
+    }
+    pack.end();
+    let mut table = Table::new(X + 530, Y + 20, 150, 220, "Table");
+    table.set_rows(10);
+    table.set_cols(3);
+    table.set_col_header(true);
+    table.end();
+}
+
+fn misc() {
+    let mut spinner = Spinner::new(X + 90, Y + 20, 100, 25, "Spinner");
+    spinner.set_range(0.0, 10.0);
+    let mut progress = Progress::new(X + 90, Y + 60, 200, 25, "Progress");
+    progress.set_maximum(100.0);
+    progress.set_value(40.0);
+    let _clock = Clock::new(X + 20, Y + 110, 120, 120, "Clock");
+    let mut chart = Chart::new(X + 160, Y + 110, 200, 120, "Chart");
+    chart.set_type(ChartType::PieChart);
+    chart.add(3.0, "Red", Color::Red as u32);
+    chart.add(2.0, "Green", Color::Green as u32);
+    chart.add(1.0, "Blue", Color::Blue as u32);
+    let _chooser = ColorChooser::new(X + 400, Y + 20, 200, 100, "");
+}
+
+fn text() {
+    let mut buf = TextBuffer::with_text("A TextDisplay");
+    let _display = TextDisplay::new(X + 20, Y + 20, 200, 100, &mut buf);
+    let mut buf = TextBuffer::with_text("A TextEditor\n");
+    let mut editor = TextEditor::new(X + 240, Y + 20, 200, 100, &mut buf);
+    editor.set_auto_indent(true, "{:");
+    let mut buf = TextBuffer::default();
+    let mut term = SimpleTerminal::new(X + 460, Y + 20, 200, 100, &mut buf);
+    term.append("A SimpleTerminal\n");
+    let mut code = CodeEditor::new(X + 20, Y + 140, 640, 120);
+    code.set_highlighter(Box::new(RustHighlighter)).unwrap();
+    code.buffer()
+        .set_text("fn main() {\n    println!(\"A CodeEditor\");\n}\n");
+}
+
+/// Returns the source of the page function called name
+fn page_source(name: &str) -> &'static str {
+    let start = match SOURCE.find(&format!("fn {}()", name)) {
+        Some(start) => start,
+        None => return "",
+    };
+    let end = SOURCE[start..]
+        .find("\n}\n")
+        .map_or(SOURCE.len(), |end| start + end + 2);
+    &SOURCE[start..end]
+}
+
+fn main() {
+    let app = App::default().set_scheme(AppScheme::Gtk);
+    let mut wind = Window::new(100, 100, 900, 600, "Gallery");
+
+    let pages = pages![
+        "Buttons" => buttons,
+        "Inputs" => inputs,
+        "Valuators" => valuators,
+        "Menus" => menus,
+        "Browsers and trees" => browsers,
+        "Groups and tables" => groups,
+        "Miscellaneous" => misc,
+        "Text" => text,
+    ];
+
+    let mut list = HoldBrowser::new(10, 10, 180, 580, "");
+    let mut page_groups = vec![];
+    for (title, _, page) in pages.iter() {
+        list.add(title);
+        let mut grp = Group::new(X, Y, 690, 270, "");
+        page();
+        grp.end();
+        grp.hide();
+        page_groups.push(grp);
+    }
+
+    let mut source = TextBuffer::default();
+    let mut styles = TextBuffer::default();
+    let mut display = TextDisplay::new(X, Y + 280, 690, 300, &mut source);
+    display.set_text_font(Font::Courrier);
+    let mut highlighter = RustHighlighter;
+    let entries: Vec<StyleTableEntryExt> = highlighter
+        .styles()
+        .iter()
+        .map(|e| StyleTableEntryExt {
+            color: e.color,
+            font: e.font,
+            size: e.size,
+            attr: TextAttr::None,
+            bgcolor: Color::White,
+        })
+        .collect();
+    display.set_style_table_entry_ext(&mut styles, &entries);
+
+    wind.make_resizable(true);
+    wind.end();
+    wind.show();
+
+    let mut lst = list.clone();
+    list.set_callback(Box::new(move || {
+        let selected = (1..=lst.size()).find(|line| lst.selected(*line));
+        for (i, grp) in page_groups.iter_mut().enumerate() {
+            if selected == Some(i as u32 + 1) {
+                grp.show();
+            } else {
+                grp.hide();
+            }
+        }
+        let text = selected.map_or("", |line| page_source(pages[line as usize - 1].1));
+        let style: String = highlighter
+            .highlight(text)
+            .iter()
+            .map(|s| *s as char)
+            .collect();
+        source.set_text(text);
+        styles.set_text(&style);
+        display.redraw();
+    }));
+    list.select(1);
+    list.do_callback();
+
+    app.run().unwrap();
+}
@@ -220,4 +220,21 @@ mod button {
         but.set_tooltip("tooltip");
         assert!(but.tooltip().unwrap() == "tooltip");
     }
+    #[test]
+    fn callback_dropped_on_clear() {
+        let mut win = crate::window::Window::default();
+        let mut but = Button::new(0, 0, 0, 0, "hello");
+        win.end();
+        let rc = std::rc::Rc::new(());
+        let r = rc.clone();
+        but.set_callback(Box::new(move || {
+            let _ = &r;
+        }));
+        assert!(std::rc::Rc::strong_count(&rc) == 2);
+        win.clear();
+        // The closure is dropped once the event being handled is done
+        assert!(std::rc::Rc::strong_count(&rc) == 2);
+        unsafe { fltk_sys::widget::Fl_Widget_free_pending_data() }
+        assert!(std::rc::Rc::strong_count(&rc) == 1);
+    }
 }
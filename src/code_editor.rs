@@ -0,0 +1,717 @@
+pub use crate::prelude::*;
+use crate::text::*;
+use std::{cell::RefCell, os::raw, rc::Rc};
+
+/// The most styles a Highlighter can have, style bytes going from b'A' to b'~' with the
+/// last one used for matching brackets
+pub const MAX_STYLES: usize = (b'~' - b'A') as usize;
+
+/// Highlights the text of a CodeEditor, implemented per language
+pub trait Highlighter {
+    /// Returns the style table, the style at index i is referred to by the style byte b'A' + i.
+    /// It has at most MAX_STYLES entries
+    fn styles(&self) -> Vec<StyleTableEntry>;
+    /// Returns the style byte of every byte of the text
+    fn highlight(&mut self, text: &str) -> Vec<u8>;
+    /// Returns whether the text following a newline given the first style is highlighted
+    /// the same whatever comes before it. The editor then highlights again only the lines
+    /// around an edit instead of the whole text
+    fn is_line_based(&self) -> bool {
+        false
+    }
+}
+
+struct CodeEditorState {
+    highlighter: Option<Box<dyn Highlighter>>,
+    style_buf: TextBuffer,
+    styles: Vec<u8>,
+    style_count: u8,
+    brackets: Option<(u32, u32)>,
+    bracket_color: Color,
+    watched: Option<(TextBuffer, *mut raw::c_void)>,
+}
+
+impl CodeEditorState {
+    fn unwatch(&mut self) {
+        if let Some((mut buf, data)) = self.watched.take() {
+            buf.remove_modify_callback_data(data);
+        }
+    }
+}
+
+impl Drop for CodeEditorState {
+    fn drop(&mut self) {
+        self.unwatch();
+    }
+}
+
+/// Creates a code editor, a TextEditor with syntax highlighting through a Highlighter,
+/// line numbers and matching bracket highlighting. Like other widgets handling events,
+/// it should be created inside a window
+#[derive(Clone)]
+pub struct CodeEditor {
+    editor: TextEditor,
+    state: Rc<RefCell<CodeEditorState>>,
+}
+
+impl CodeEditor {
+    /// Creates a new CodeEditor widget
+    pub fn new(x: i32, y: i32, w: i32, h: i32) -> CodeEditor {
+        let mut buf = TextBuffer::default();
+        let mut editor = TextEditor::new(x, y, w, h, &mut buf);
+        editor.set_text_font(Font::Courrier);
+        let state = CodeEditorState {
+            highlighter: None,
+            style_buf: TextBuffer::default(),
+            styles: vec![],
+            style_count: 0,
+            brackets: None,
+            bracket_color: Color::Light2,
+            watched: None,
+        };
+        let mut code = CodeEditor {
+            editor,
+            state: Rc::new(RefCell::new(state)),
+        };
+        code.show_line_numbers(true);
+        code.apply_styles();
+        code.rehighlight();
+        // The callbacks don't own the state, which is dropped with the editor
+        let weak = Rc::downgrade(&code.state);
+        let editor = code.editor.clone();
+        let data = buf.add_removable_modify_callback(Box::new(
+            move |pos, inserted, deleted, _, _| {
+                if inserted == 0 && deleted == 0 {
+                    return;
+                }
+                if let Some(state) = weak.upgrade() {
+                    let mut code = CodeEditor {
+                        editor: editor.clone(),
+                        state,
+                    };
+                    code.restyle(pos, inserted, deleted);
+                }
+            },
+        ));
+        code.state.borrow_mut().watched = Some((buf, data));
+        let weak = Rc::downgrade(&code.state);
+        let editor = code.editor.clone();
+        crate::widget::intercept_events(
+            unsafe { code.editor.as_widget_ptr() },
+            "brackets",
+            Box::new(move |ev| {
+                match ev {
+                    Event::KeyDown | Event::Push | Event::Drag | Event::Released => {
+                        if let Some(state) = weak.upgrade() {
+                            let mut code = CodeEditor {
+                                editor: editor.clone(),
+                                state,
+                            };
+                            code.update_brackets();
+                        }
+                    }
+                    _ => (),
+                }
+                false
+            }),
+        );
+        let state = code.state.clone();
+        crate::widget::on_delete(
+            unsafe { code.editor.as_widget_ptr() },
+            Box::new(move || state.borrow_mut().unwatch()),
+        );
+        code
+    }
+
+    /// Creates a default and zero initialized CodeEditor
+    pub fn default() -> CodeEditor {
+        CodeEditor::new(0, 0, 0, 0)
+    }
+
+    /// Returns the underlying TextEditor, useful for positioning and styling
+    pub fn editor(&self) -> TextEditor {
+        self.editor.clone()
+    }
+
+    /// Returns the buffer holding the code
+    pub fn buffer(&self) -> TextBuffer {
+        self.editor.buffer().unwrap()
+    }
+
+    /// Sets the highlighter styling the code. A highlighter with more than MAX_STYLES styles
+    /// returns FltkErrorKind::FailedOperation and isn't set
+    pub fn set_highlighter(&mut self, highlighter: Box<dyn Highlighter>) -> Result<(), FltkError> {
+        if highlighter.styles().len() > MAX_STYLES {
+            return Err(FltkError::Internal(FltkErrorKind::FailedOperation));
+        }
+        self.state.borrow_mut().highlighter = Some(highlighter);
+        self.apply_styles();
+        self.rehighlight();
+        Ok(())
+    }
+
+    /// Removes the highlighter, the code is then drawn with the editor's text style
+    pub fn clear_highlighter(&mut self) {
+        self.state.borrow_mut().highlighter = None;
+        self.apply_styles();
+        self.rehighlight();
+    }
+
+    /// Shows or hides the line numbers margin
+    pub fn show_line_numbers(&mut self, flag: bool) {
+        self.editor.set_linenumber_width(if flag { 40 } else { 0 });
+        self.editor.redraw();
+    }
+
+    /// Sets the background color of matching brackets
+    pub fn set_bracket_color(&mut self, color: Color) {
+        self.state.borrow_mut().bracket_color = color;
+        self.apply_styles();
+        self.rehighlight();
+    }
+
+    /// Highlights the whole text again, useful when the highlighter's state changed
+    pub fn rehighlight(&mut self) {
+        let text = self.buffer().text();
+        {
+            let mut state = self.state.borrow_mut();
+            let state = &mut *state;
+            let styles = state.highlight(&text);
+            let style_text: String = styles.iter().map(|&s| s as char).collect();
+            state.style_buf.set_text(&style_text);
+            state.styles = styles;
+            state.brackets = None;
+        }
+        self.update_brackets();
+        self.editor.redraw();
+    }
+
+    /// Highlights again the text around an edit of the buffer at pos
+    fn restyle(&mut self, pos: u32, inserted: u32, deleted: u32) {
+        let buf = self.buffer();
+        {
+            let mut state = self.state.borrow_mut();
+            let state = &mut *state;
+            state.clear_brackets();
+            let (pos, inserted, deleted) = (pos as usize, inserted as usize, deleted as usize);
+            let line_based = state.highlighter.as_ref().map_or(true, |h| h.is_line_based());
+            let (start, end, styles) = if line_based {
+                // Restarts after a plain newline before the edit and stops after a plain
+                // newline following it which was plain before the edit, the text after it
+                // being highlighted the same
+                let mut start = buf.line_start(pos as u32) as usize;
+                while start > 0 && state.styles.get(start - 1).map_or(false, |&s| s != b'A') {
+                    start = buf.line_start(start as u32 - 1) as usize;
+                }
+                let len = buf.length() as usize;
+                let mut end = pos + inserted;
+                loop {
+                    end = (buf.line_end(end as u32) as usize + 1).min(len);
+                    let text = buf.text_range(start as u32, end as u32).unwrap_or_default();
+                    let styles = state.highlight(&text);
+                    let old = (end + deleted).checked_sub(inserted + 1);
+                    let converged = end == len
+                        || (styles.last() == Some(&b'A')
+                            && old.and_then(|o| state.styles.get(o)) == Some(&b'A'));
+                    if converged {
+                        break (start, end + deleted - inserted, styles);
+                    }
+                    // Highlights twice as much text before checking again
+                    end = (2 * end - start).min(len);
+                }
+            } else {
+                let styles = state.highlight(&buf.text());
+                (0, state.styles.len(), styles)
+            };
+            let style_text: String = styles.iter().map(|&s| s as char).collect();
+            state.style_buf.replace(start as u32, end as u32, &style_text);
+            state.styles.splice(start..end, styles);
+        }
+        self.update_brackets();
+        self.editor.redraw();
+    }
+
+    fn apply_styles(&mut self) {
+        let plain = StyleTableEntry {
+            color: self.editor.text_color(),
+            font: self.editor.text_font(),
+            size: self.editor.text_size(),
+        };
+        let mut state = self.state.borrow_mut();
+        let state = &mut *state;
+        let mut styles = match state.highlighter.as_ref() {
+            Some(h) => h.styles(),
+            None => vec![],
+        };
+        styles.truncate(MAX_STYLES);
+        if styles.is_empty() {
+            styles.push(plain);
+        }
+        let mut entries: Vec<StyleTableEntryExt> = styles
+            .iter()
+            .map(|e| StyleTableEntryExt {
+                color: e.color,
+                font: e.font,
+                size: e.size,
+                attr: TextAttr::None,
+                bgcolor: state.bracket_color,
+            })
+            .collect();
+        let mut bracket = entries[0];
+        bracket.attr = TextAttr::BgColor;
+        entries.push(bracket);
+        state.style_count = styles.len() as u8;
+        self.editor
+            .set_style_table_entry_ext(&mut state.style_buf, &entries);
+    }
+
+    fn update_brackets(&mut self) {
+        let text = self.buffer().text();
+        let found = matching_brackets(text.as_bytes(), self.editor.insert_position() as usize);
+        let mut state = self.state.borrow_mut();
+        let state = &mut *state;
+        if found == state.brackets {
+            return;
+        }
+        state.clear_brackets();
+        if let Some((a, b)) = found {
+            let style = ((b'A' + state.style_count) as char).to_string();
+            for &p in [a, b].iter() {
+                state.style_buf.replace(p, p + 1, &style);
+            }
+        }
+        state.brackets = found;
+        self.editor.redraw();
+    }
+}
+
+impl CodeEditorState {
+    /// Highlights the text, replacing the style bytes out of the style table by the first style
+    fn highlight(&mut self, text: &str) -> Vec<u8> {
+        let mut styles = match self.highlighter.as_mut() {
+            Some(h) => h.highlight(text),
+            None => vec![],
+        };
+        styles.resize(text.len(), b'A');
+        let last = b'A' + self.style_count - 1;
+        for s in styles.iter_mut() {
+            if *s < b'A' || *s > last {
+                *s = b'A';
+            }
+        }
+        styles
+    }
+
+    /// Gives the matching brackets their highlighted style back
+    fn clear_brackets(&mut self) {
+        if let Some((a, b)) = self.brackets.take() {
+            for &p in [a, b].iter() {
+                if let Some(&s) = self.styles.get(p as usize) {
+                    self.style_buf.replace(p, p + 1, &(s as char).to_string());
+                }
+            }
+        }
+    }
+}
+
+/// Finds the bracket at or before pos and its match, returning their positions
+fn matching_brackets(text: &[u8], pos: usize) -> Option<(u32, u32)> {
+    const PAIRS: [(u8, u8); 3] = [(b'(', b')'), (b'[', b']'), (b'{', b'}')];
+    let candidates = [Some(pos), pos.checked_sub(1)];
+    for &p in candidates.iter() {
+        let p = match p {
+            Some(p) if p < text.len() => p,
+            _ => continue,
+        };
+        for &(open, close) in PAIRS.iter() {
+            let mut depth = 0;
+            if text[p] == open {
+                for (i, &c) in text.iter().enumerate().skip(p) {
+                    if c == open {
+                        depth += 1;
+                    } else if c == close {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some((p as u32, i as u32));
+                        }
+                    }
+                }
+            } else if text[p] == close {
+                for i in (0..=p).rev() {
+                    if text[i] == close {
+                        depth += 1;
+                    } else if text[i] == open {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some((i as u32, p as u32));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+const PLAIN: u8 = b'A';
+const COMMENT: u8 = b'B';
+const STRING: u8 = b'C';
+const KEYWORD: u8 = b'D';
+const NUMBER: u8 = b'E';
+const SPECIAL: u8 = b'F';
+const NAME: u8 = b'G';
+
+/// The style table shared by the built-in highlighters, indexed by the constants above
+fn code_styles() -> Vec<StyleTableEntry> {
+    let entry = |color, font| StyleTableEntry {
+        color,
+        font,
+        size: 14,
+    };
+    vec![
+        entry(Color::Black, Font::Courrier),
+        entry(Color::DarkGreen, Font::CourrierItalic),
+        entry(Color::DarkRed, Font::Courrier),
+        entry(Color::DarkBlue, Font::CourrierBold),
+        entry(Color::DarkMagenta, Font::Courrier),
+        entry(Color::DarkCyan, Font::Courrier),
+        entry(Color::DarkYellow, Font::Courrier),
+    ]
+}
+
+fn line_end(b: &[u8], i: usize) -> usize {
+    b[i..]
+        .iter()
+        .position(|&c| c == b'\n')
+        .map_or(b.len(), |p| i + p)
+}
+
+fn word_end(b: &[u8], i: usize) -> usize {
+    let mut j = i;
+    while j < b.len() && (b[j].is_ascii_alphanumeric() || b[j] == b'_' || b[j] >= 0x80) {
+        j += 1;
+    }
+    j
+}
+
+/// Returns the end of a quoted string whose contents start at i, honoring backslash escapes
+fn quoted_end(b: &[u8], mut i: usize, quote: u8) -> usize {
+    while i < b.len() {
+        if b[i] == b'\\' {
+            i += 2;
+            continue;
+        }
+        if b[i] == quote {
+            return i + 1;
+        }
+        i += 1;
+    }
+    b.len()
+}
+
+fn find_end(b: &[u8], i: usize, pat: &[u8]) -> usize {
+    b[i..]
+        .windows(pat.len())
+        .position(|w| w == pat)
+        .map_or(b.len(), |p| i + p + pat.len())
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+/// Highlights Rust code: comments, strings, keywords, numbers, macros and attributes, and types
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RustHighlighter;
+
+impl RustHighlighter {
+    fn block_comment_end(b: &[u8], mut i: usize) -> usize {
+        let mut depth = 0;
+        while i < b.len() {
+            if b[i..].starts_with(b"/*") {
+                depth += 1;
+                i += 2;
+            } else if b[i..].starts_with(b"*/") {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return i;
+                }
+            } else {
+                i += 1;
+            }
+        }
+        b.len()
+    }
+
+    fn raw_string_end(b: &[u8], i: usize) -> Option<usize> {
+        if b[i] != b'r' {
+            return None;
+        }
+        let mut j = i + 1;
+        while b.get(j) == Some(&b'#') {
+            j += 1;
+        }
+        if b.get(j) != Some(&b'"') {
+            return None;
+        }
+        let mut closing = vec![b'"'];
+        closing.extend(std::iter::repeat(b'#').take(j - i - 1));
+        Some(find_end(b, j + 1, &closing))
+    }
+
+    fn char_literal_end(b: &[u8], i: usize) -> Option<usize> {
+        let mut j = i + 1;
+        if b.get(j) == Some(&b'\\') {
+            j += 2;
+            while j < b.len() && b[j] != b'\'' && j - i < 12 {
+                j += 1;
+            }
+        } else {
+            j += 1;
+            while j < b.len() && (b[j] & 0xC0) == 0x80 {
+                j += 1;
+            }
+        }
+        if b.get(j) == Some(&b'\'') {
+            Some(j + 1)
+        } else {
+            None
+        }
+    }
+
+    fn attribute_end(b: &[u8], i: usize) -> usize {
+        let mut depth = 0;
+        for (j, &c) in b.iter().enumerate().skip(i) {
+            if c == b'[' {
+                depth += 1;
+            } else if c == b']' {
+                depth -= 1;
+                if depth == 0 {
+                    return j + 1;
+                }
+            }
+        }
+        b.len()
+    }
+}
+
+impl Highlighter for RustHighlighter {
+    fn styles(&self) -> Vec<StyleTableEntry> {
+        code_styles()
+    }
+
+    fn is_line_based(&self) -> bool {
+        true
+    }
+
+    fn highlight(&mut self, text: &str) -> Vec<u8> {
+        let b = text.as_bytes();
+        let mut out = vec![PLAIN; b.len()];
+        let mut i = 0;
+        while i < b.len() {
+            let c = b[i];
+            let (end, style) = if b[i..].starts_with(b"//") {
+                (line_end(b, i), COMMENT)
+            } else if b[i..].starts_with(b"/*") {
+                (RustHighlighter::block_comment_end(b, i), COMMENT)
+            } else if let Some(end) = RustHighlighter::raw_string_end(b, i) {
+                (end, STRING)
+            } else if c == b'"' {
+                (quoted_end(b, i + 1, b'"'), STRING)
+            } else if c == b'\'' {
+                match RustHighlighter::char_literal_end(b, i) {
+                    Some(end) => (end, STRING),
+                    // A lifetime
+                    None => (i + 1, PLAIN),
+                }
+            } else if b[i..].starts_with(b"#[") || b[i..].starts_with(b"#![") {
+                (RustHighlighter::attribute_end(b, i), SPECIAL)
+            } else if c.is_ascii_digit() {
+                let mut end = word_end(b, i);
+                while b.get(end) == Some(&b'.') && b.get(end + 1).map_or(false, u8::is_ascii_digit)
+                {
+                    end = word_end(b, end + 1);
+                }
+                (end, NUMBER)
+            } else if c.is_ascii_alphabetic() || c == b'_' || c >= 0x80 {
+                let end = word_end(b, i);
+                let word = &text[i..end];
+                if b.get(end) == Some(&b'!') && b.get(end + 1) != Some(&b'=') {
+                    (end + 1, SPECIAL)
+                } else if RUST_KEYWORDS.contains(&word) {
+                    (end, KEYWORD)
+                } else if c.is_ascii_uppercase() {
+                    (end, NAME)
+                } else {
+                    (end, PLAIN)
+                }
+            } else {
+                (i + 1, PLAIN)
+            };
+            for s in &mut out[i..end] {
+                *s = style;
+            }
+            i = end;
+        }
+        out
+    }
+}
+
+/// Highlights TOML: comments, table headers, keys, strings, numbers and dates, and booleans
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TomlHighlighter;
+
+impl Highlighter for TomlHighlighter {
+    fn styles(&self) -> Vec<StyleTableEntry> {
+        code_styles()
+    }
+
+    fn is_line_based(&self) -> bool {
+        true
+    }
+
+    fn highlight(&mut self, text: &str) -> Vec<u8> {
+        let b = text.as_bytes();
+        let mut out = vec![PLAIN; b.len()];
+        let mut i = 0;
+        let mut line_start = true;
+        while i < b.len() {
+            let c = b[i];
+            if c == b'\n' {
+                line_start = true;
+                i += 1;
+                continue;
+            }
+            if c == b' ' || c == b'\t' || c == b'\r' {
+                i += 1;
+                continue;
+            }
+            let (end, style) = if c == b'#' {
+                (line_end(b, i), COMMENT)
+            } else if line_start && c == b'[' {
+                let end = line_end(b, i);
+                let close = b[i..end].iter().rposition(|&c| c == b']');
+                (close.map_or(end, |p| i + p + 1), SPECIAL)
+            } else if b[i..].starts_with(b"\"\"\"") {
+                (find_end(b, i + 3, b"\"\"\""), STRING)
+            } else if b[i..].starts_with(b"'''") {
+                (find_end(b, i + 3, b"'''"), STRING)
+            } else if c == b'"' {
+                (quoted_end(b, i + 1, b'"'), STRING)
+            } else if c == b'\'' {
+                (find_end(b, i + 1, b"'"), STRING)
+            } else if line_start {
+                // A key, up to the equal sign without the trailing whitespace
+                let end = line_end(b, i);
+                let mut key_end = b[i..end]
+                    .iter()
+                    .position(|&c| c == b'=')
+                    .map_or(end, |p| i + p);
+                while key_end > i && (b[key_end - 1] == b' ' || b[key_end - 1] == b'\t') {
+                    key_end -= 1;
+                }
+                (key_end, NAME)
+            } else if c.is_ascii_digit()
+                || ((c == b'+' || c == b'-') && b.get(i + 1).map_or(false, u8::is_ascii_digit))
+            {
+                let mut end = i + 1;
+                while end < b.len()
+                    && (b[end].is_ascii_alphanumeric() || b"-_:.+".contains(&b[end]))
+                {
+                    end += 1;
+                }
+                (end, NUMBER)
+            } else if c.is_ascii_alphabetic() {
+                let end = word_end(b, i);
+                match &text[i..end] {
+                    "true" | "false" => (end, KEYWORD),
+                    _ => (end, PLAIN),
+                }
+            } else {
+                (i + 1, PLAIN)
+            };
+            for s in &mut out[i..end] {
+                *s = style;
+            }
+            line_start = false;
+            i = std::cmp::max(end, i + 1);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod code_editor {
+    use super::*;
+    #[test]
+    fn rust_highlighting() {
+        let code = "fn main() { // hi\n    println!(\"{}\", 'c'); }";
+        let styles = RustHighlighter.highlight(code);
+        let style_at = |pat: &str| styles[code.find(pat).unwrap()];
+        assert!(styles.len() == code.len());
+        assert!(style_at("fn") == KEYWORD);
+        assert!(style_at("main") == PLAIN);
+        assert!(style_at("// hi") == COMMENT);
+        assert!(style_at("println!") == SPECIAL);
+        assert!(style_at("\"{}\"") == STRING);
+        assert!(style_at("'c'") == STRING);
+    }
+    #[test]
+    fn toml_highlighting() {
+        let code = "[package]\nname = \"fltk\" # crate\nversion = 5\n";
+        let styles = TomlHighlighter.highlight(code);
+        let style_at = |pat: &str| styles[code.find(pat).unwrap()];
+        assert!(style_at("[package]") == SPECIAL);
+        assert!(style_at("name") == NAME);
+        assert!(style_at("\"fltk\"") == STRING);
+        assert!(style_at("# crate") == COMMENT);
+        assert!(style_at("5") == NUMBER);
+    }
+    #[test]
+    fn brackets() {
+        let code = b"f(a[0], {b})";
+        assert!(matching_brackets(code, 1) == Some((1, 11)));
+        assert!(matching_brackets(code, 12) == Some((1, 11)));
+        assert!(matching_brackets(code, 4) == Some((3, 5)));
+        assert!(matching_brackets(code, 0) == None);
+    }
+    #[test]
+    fn incremental_highlighting() {
+        let mut win = crate::window::Window::default();
+        let mut code = CodeEditor::default();
+        win.end();
+        code.set_highlighter(Box::new(RustHighlighter)).unwrap();
+        let mut buf = code.buffer();
+        buf.set_text("fn a() {}\nlet b = 1;\n// c\nlet d = \"e\";\n");
+        buf.insert(10, "/* ");
+        buf.append("*/ let f = 2;");
+        buf.remove(0, 3);
+        buf.replace(2, 4, "\"x\n");
+        let text = buf.text();
+        assert!(code.state.borrow().styles == RustHighlighter.highlight(&text));
+        let weak = Rc::downgrade(&code.state);
+        drop(code);
+        win.clear();
+        assert!(weak.upgrade().is_none());
+    }
+    #[test]
+    fn too_many_styles() {
+        struct Many;
+        impl Highlighter for Many {
+            fn styles(&self) -> Vec<StyleTableEntry> {
+                vec![code_styles()[0]; MAX_STYLES + 1]
+            }
+            fn highlight(&mut self, text: &str) -> Vec<u8> {
+                vec![b'~'; text.len()]
+            }
+        }
+        let mut code = CodeEditor::default();
+        assert!(code.set_highlighter(Box::new(Many)).is_err());
+        code.buffer().set_text("x");
+        assert!(code.state.borrow().styles == b"A");
+    }
+}
@@ -1,6 +1,8 @@
 pub use crate::prelude::*;
 use fltk_sys::dialog::*;
 use std::{
+    cell::RefCell,
+    collections::HashMap,
     ffi::{CStr, CString},
     mem,
     os::raw,
@@ -388,4 +390,104 @@ pub fn beep(tp: BeepType) {
     unsafe {
         Fl_beep(tp as i32)
     }
-}
\ No newline at end of file
+}
+
+type HelpProvider = Box<dyn FnMut(&str, &mut HelpDialog) -> bool>;
+
+/// Holds the widgets' help ids and the provider showing their topics
+struct ContextHelp {
+    ids: HashMap<usize, String>,
+    provider: Option<HelpProvider>,
+    dialog: Option<HelpDialog>,
+}
+
+thread_local! {
+    static CONTEXT_HELP: RefCell<ContextHelp> = RefCell::new(ContextHelp {
+        ids: HashMap::new(),
+        provider: None,
+        dialog: None,
+    });
+}
+
+pub(crate) fn set_widget_help_id(wid: *mut fltk_sys::widget::Fl_Widget, id: &str) {
+    let added = CONTEXT_HELP.with(|h| {
+        h.borrow_mut()
+            .ids
+            .insert(wid as usize, id.to_string())
+            .is_none()
+    });
+    if added {
+        crate::widget::on_delete(
+            wid,
+            Box::new(move || {
+                CONTEXT_HELP.with(|h| h.borrow_mut().ids.remove(&(wid as usize)));
+            }),
+        );
+    }
+}
+
+pub(crate) fn widget_help_id(wid: *mut fltk_sys::widget::Fl_Widget) -> Option<String> {
+    CONTEXT_HELP.with(|h| h.borrow().ids.get(&(wid as usize)).cloned())
+}
+
+unsafe extern "C" fn context_help_shim(wid: *mut fltk_sys::fl::Fl_Widget, _data: *mut raw::c_void) {
+    if !wid.is_null() {
+        let wid = crate::widget::Widget::from_raw(wid as *mut fltk_sys::widget::Fl_Widget);
+        show_context_help(&wid);
+    }
+}
+
+/// Sets the help provider, which loads the topic of a help id into the help dialog using
+/// HelpDialog::load() or HelpDialog::set_value(), returning false if there's no such topic.
+/// Also enables F1, which requests help for the focused widget or the one under the mouse
+pub fn set_help_provider(cb: Box<dyn FnMut(&str, &mut HelpDialog) -> bool>) {
+    CONTEXT_HELP.with(|h| h.borrow_mut().provider = Some(cb));
+    unsafe {
+        fltk_sys::fl::Fl_set_context_help_callback(Some(context_help_shim), std::ptr::null_mut());
+    }
+}
+
+/// Enters What's This mode, the next click requests help for the clicked widget
+/// instead of being delivered to it. Escape leaves the mode. Requires a help provider
+pub fn whats_this() {
+    unsafe { fltk_sys::fl::Fl_start_whats_this() }
+}
+
+/// Shows the help topic of the widget, or that of its closest parent having a help id.
+/// Returns whether a topic was shown
+pub fn show_context_help<W: WidgetExt>(wid: &W) -> bool {
+    let mut id = wid.help_id();
+    let mut parent = wid.parent();
+    while id.is_none() {
+        match parent {
+            Some(p) => {
+                id = p.help_id();
+                parent = p.parent();
+            }
+            None => return false,
+        }
+    }
+    let id = id.unwrap();
+    // The provider is taken out of the state since it might set help ids
+    let (provider, dialog) = CONTEXT_HELP.with(|h| {
+        let mut h = h.borrow_mut();
+        (h.provider.take(), h.dialog.take())
+    });
+    let mut provider = match provider {
+        Some(provider) => provider,
+        None => return false,
+    };
+    let mut dialog = dialog.unwrap_or_else(HelpDialog::default);
+    let shown = provider(&id, &mut dialog);
+    if shown {
+        dialog.show();
+    }
+    CONTEXT_HELP.with(|h| {
+        let mut h = h.borrow_mut();
+        if h.provider.is_none() {
+            h.provider = Some(provider);
+        }
+        h.dialog = Some(dialog);
+    });
+    shown
+}
@@ -604,7 +604,7 @@ pub fn can_do_alpha_blending() -> bool {
 /// Get a human-readable string from a shortcut value
 pub fn shortcut_label(shortcut: Shortcut) -> String {
     unsafe {
-        let x = cfl_shortcut_label(shortcut as u32);
+        let x = cfl_shortcut_label(shortcut.bits() as u32);
         assert!(!x.is_null());
         CStr::from_ptr(x as *mut raw::c_char)
             .to_string_lossy()
@@ -79,15 +79,42 @@ pub enum FrameType {
     FreeBoxType,
 }
 
-/// Defines alignment rules used by FLTK for labels
-#[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub enum Align {
-    AlignCenter = 0,
-    AlignTop = 1,
-    AlignBottom = 2,
-    AlignLeft = 4,
-    AlignRight = 8,
+/// Defines alignment rules used by FLTK for labels, the sides combine with the flags
+/// placing the label inside the widget and relative to the widget's image
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Align(i32);
+
+#[allow(non_upper_case_globals)]
+impl Align {
+    pub const AlignCenter: Align = Align(0);
+    pub const AlignTop: Align = Align(1);
+    pub const AlignBottom: Align = Align(2);
+    pub const AlignLeft: Align = Align(4);
+    pub const AlignRight: Align = Align(8);
+    pub const AlignInside: Align = Align(16);
+    pub const AlignTextOverImage: Align = Align(0x20);
+    pub const AlignImageOverText: Align = Align(0);
+    pub const AlignClip: Align = Align(0x40);
+    pub const AlignWrap: Align = Align(0x80);
+    pub const AlignImageNextToText: Align = Align(0x100);
+    pub const AlignTextNextToImage: Align = Align(0x120);
+    pub const AlignImageBackdrop: Align = Align(0x200);
+
+    /// Creates an alignment from its raw value
+    pub fn from_i32(val: i32) -> Align {
+        Align(val)
+    }
+
+    /// Returns the raw value of the alignment
+    pub fn bits(self) -> i32 {
+        self.0
+    }
+
+    /// Returns whether all the bits of other are set in the alignment
+    pub fn contains(self, other: Align) -> bool {
+        self.0 & other.0 == other.0
+    }
 }
 
 /// Defines fonts used by FLTK
@@ -224,59 +251,76 @@ pub enum Event {
     ZoomEvent,
 }
 
-/// Defines the inputted virtual keycode
-#[repr(i32)]
-#[derive(Copy, Clone, PartialEq)]
-pub enum Key {
-    None = 0,
-    Button = 0xfee8,
-    BackSpace = 0xff08,
-    Tab = 0xff09,
-    IsoKey = 0xff0c,
-    Enter = 0xff0d,
-    Pause = 0xff13,
-    ScrollLock = 0xff14,
-    Escape = 0xff1b,
-    Kana = 0xff2e,
-    Eisu = 0xff2f,
-    Yen = 0xff30,
-    JISUnderscore = 0xff31,
-    Home = 0xff50,
-    Left = 0xff51,
-    Up = 0xff52,
-    Right = 0xff53,
-    Down = 0xff54,
-    PageUp = 0xff55,
-    PageDown = 0xff56,
-    End = 0xff57,
-    Print = 0xff61,
-    Insert = 0xff63,
-    Menu = 0xff67,
-    Help = 0xff68,
-    NumLock = 0xff7f,
-    KP = 0xff80,
-    KPEnter = 0xff8d,
-    KPLast = 0xffbd,
-    FLast = 0xffe0,
-    ShiftL = 0xffe1,
-    ShiftR = 0xffe2,
-    ControlL = 0xffe3,
-    ControlR = 0xffe4,
-    CapsLock = 0xffe5,
-    MetaL = 0xffe7,
-    MetaR = 0xffe8,
-    AltL = 0xffe9,
-    AltR = 0xffea,
-    Delete = 0xffff,
-}
+/// Defines the inputted virtual keycode, character keys having the code of the character
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Key(i32);
 
+#[allow(non_upper_case_globals)]
 impl Key {
+    pub const None: Key = Key(0);
+    pub const Button: Key = Key(0xfee8);
+    pub const BackSpace: Key = Key(0xff08);
+    pub const Tab: Key = Key(0xff09);
+    pub const IsoKey: Key = Key(0xff0c);
+    pub const Enter: Key = Key(0xff0d);
+    pub const Pause: Key = Key(0xff13);
+    pub const ScrollLock: Key = Key(0xff14);
+    pub const Escape: Key = Key(0xff1b);
+    pub const Kana: Key = Key(0xff2e);
+    pub const Eisu: Key = Key(0xff2f);
+    pub const Yen: Key = Key(0xff30);
+    pub const JISUnderscore: Key = Key(0xff31);
+    pub const Home: Key = Key(0xff50);
+    pub const Left: Key = Key(0xff51);
+    pub const Up: Key = Key(0xff52);
+    pub const Right: Key = Key(0xff53);
+    pub const Down: Key = Key(0xff54);
+    pub const PageUp: Key = Key(0xff55);
+    pub const PageDown: Key = Key(0xff56);
+    pub const End: Key = Key(0xff57);
+    pub const Print: Key = Key(0xff61);
+    pub const Insert: Key = Key(0xff63);
+    pub const Menu: Key = Key(0xff67);
+    pub const Help: Key = Key(0xff68);
+    pub const NumLock: Key = Key(0xff7f);
+    pub const KP: Key = Key(0xff80);
+    pub const KPEnter: Key = Key(0xff8d);
+    pub const KPLast: Key = Key(0xffbd);
+    pub const FLast: Key = Key(0xffe0);
+    pub const ShiftL: Key = Key(0xffe1);
+    pub const ShiftR: Key = Key(0xffe2);
+    pub const ControlL: Key = Key(0xffe3);
+    pub const ControlR: Key = Key(0xffe4);
+    pub const CapsLock: Key = Key(0xffe5);
+    pub const MetaL: Key = Key(0xffe7);
+    pub const MetaR: Key = Key(0xffe8);
+    pub const AltL: Key = Key(0xffe9);
+    pub const AltR: Key = Key(0xffea);
+    pub const Delete: Key = Key(0xffff);
+
+    /// Creates a key from its raw key code
     pub fn from_i32(val: i32) -> Key {
-        unsafe { std::mem::transmute(val) }
+        Key(val)
+    }
+
+    /// Returns the raw key code of the key
+    pub fn bits(self) -> i32 {
+        self.0
+    }
+
+    /// Returns the key code of a character key, useful for key bindings
+    pub fn from_char(c: char) -> Key {
+        Key(c as i32)
+    }
+
+    /// Returns the function key Fn, from F1 to F35, whose codes follow KPLast
+    pub fn fn_key(n: i32) -> Key {
+        debug_assert!((1..=35).contains(&n), "Function keys go from F1 to F35!");
+        Key(Key::KPLast.0 + n)
     }
 }
 
-#[allow(unreachable_patterns)]
 impl std::fmt::Debug for Key {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self {
@@ -320,26 +364,101 @@ impl std::fmt::Debug for Key {
             Key::AltL => write!(f, "AltL"),
             Key::AltR => write!(f, "AltR"),
             Key::Delete => write!(f, "Delete"),
-            _ => write!(f, "0x{:02x}", *self as i32),
+            k if k.0 > Key::KPLast.0 && k.0 <= Key::KPLast.0 + 35 => {
+                write!(f, "F{}", k.0 - Key::KPLast.0)
+            }
+            _ => write!(f, "0x{:02x}", self.0),
         }
     }
 }
 
-/// Defines the modifiers of virtual keycodes
-#[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub enum Shortcut {
-    None = 0,
-    Shift = 0x00010000,
-    CapsLock = 0x00020000,
-    Ctrl = 0x00040000,
-    Alt = 0x00080000,
-}
+/// Defines the modifiers of virtual keycodes, which combine with a key or a character into a shortcut
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut(i32);
 
+#[allow(non_upper_case_globals)]
 impl Shortcut {
+    pub const None: Shortcut = Shortcut(0);
+    pub const Shift: Shortcut = Shortcut(0x00010000);
+    pub const CapsLock: Shortcut = Shortcut(0x00020000);
+    pub const Ctrl: Shortcut = Shortcut(0x00040000);
+    pub const Alt: Shortcut = Shortcut(0x00080000);
+    pub const Meta: Shortcut = Shortcut(0x00400000);
+
+    /// Creates a shortcut from its raw value, the modifiers combined with a key
+    pub fn from_i32(val: i32) -> Shortcut {
+        Shortcut(val)
+    }
+
+    /// Returns the raw value of the shortcut
+    pub fn bits(self) -> i32 {
+        self.0
+    }
+
+    /// Returns whether all the bits of other are set in the shortcut
+    pub fn contains(self, other: Shortcut) -> bool {
+        self.0 & other.0 == other.0
+    }
+
     pub fn from_char(c: char) -> Shortcut {
         Shortcut::None + c
     }
+
+    /// Parses an accelerator as displayed in menus, like "Ctrl+S" or "Ctrl+Shift+F5".
+    /// Returns None if a modifier or the key isn't recognized
+    pub fn parse(text: &str) -> Option<Shortcut> {
+        let text = text.trim();
+        let (mods, key) = if text.ends_with("++") {
+            (&text[..text.len() - 2], "+")
+        } else {
+            match text.rfind('+') {
+                Some(pos) => (&text[..pos], &text[pos + 1..]),
+                None => ("", text),
+            }
+        };
+        let mut shortcut = Shortcut::None;
+        for modifier in mods.split('+').map(|m| m.trim()).filter(|m| !m.is_empty()) {
+            shortcut = shortcut
+                | match modifier.to_lowercase().as_str() {
+                    "ctrl" | "control" => Shortcut::Ctrl,
+                    "alt" | "option" => Shortcut::Alt,
+                    "shift" => Shortcut::Shift,
+                    "meta" | "cmd" | "command" | "super" => Shortcut::Meta,
+                    _ => return None,
+                };
+        }
+        let key = key.trim();
+        let mut chars = key.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            // Letter shortcuts are lowercase, shift is a modifier
+            return Some(shortcut + c.to_ascii_lowercase());
+        }
+        let lower = key.to_lowercase();
+        let key = match lower.as_str() {
+            "enter" | "return" => Key::Enter,
+            "tab" => Key::Tab,
+            "backspace" => Key::BackSpace,
+            "escape" | "esc" => Key::Escape,
+            "delete" | "del" => Key::Delete,
+            "insert" | "ins" => Key::Insert,
+            "home" => Key::Home,
+            "end" => Key::End,
+            "pageup" | "pgup" => Key::PageUp,
+            "pagedown" | "pgdn" => Key::PageDown,
+            "left" => Key::Left,
+            "right" => Key::Right,
+            "up" => Key::Up,
+            "down" => Key::Down,
+            "space" => return Some(shortcut + ' '),
+            _ if lower.starts_with('f') => match lower[1..].parse::<i32>() {
+                Ok(n) if (1..=35).contains(&n) => Key::fn_key(n),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(shortcut + key)
+    }
 }
 
 /// Defines the types of triggers for widget callback functions
@@ -440,7 +559,21 @@ pub trait WidgetType {
 impl std::ops::Add<char> for Shortcut {
     type Output = Shortcut;
     fn add(self, other: char) -> Self::Output {
-        unsafe { std::mem::transmute(self as i32 + other as i32) }
+        Shortcut(self.0 + other as i32)
+    }
+}
+
+impl std::ops::Add<Key> for Shortcut {
+    type Output = Shortcut;
+    fn add(self, other: Key) -> Self::Output {
+        Shortcut(self.0 + other.0)
+    }
+}
+
+impl std::ops::BitOr<Shortcut> for Shortcut {
+    type Output = Shortcut;
+    fn bitor(self, rhs: Shortcut) -> Self::Output {
+        Shortcut(self.0 | rhs.0)
     }
 }
 
@@ -454,7 +587,7 @@ impl std::ops::BitOr<CallbackTrigger> for CallbackTrigger {
 impl std::ops::BitOr<Align> for Align {
     type Output = Align;
     fn bitor(self, rhs: Align) -> Self::Output {
-        unsafe { std::mem::transmute(self as i32 | rhs as i32) }
+        Align(self.0 | rhs.0)
     }
 }
 
@@ -482,7 +615,7 @@ impl std::ops::BitOr<Event> for Event {
 impl std::ops::BitOr<Key> for Key {
     type Output = Key;
     fn bitor(self, rhs: Key) -> Self::Output {
-        unsafe { std::mem::transmute(self as i32 | rhs as i32) }
+        Key(self.0 | rhs.0)
     }
 }
 
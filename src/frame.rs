@@ -7,8 +7,43 @@ use std::{
     os::raw,
 };
 
-/// Creates a new frame, an equivalent of Fl_Box
+/// Creates a new frame, an equivalent of Fl_Box.
+/// Used for static labels, spacers and image holders, styled through WidgetExt's
+/// set_align(), set_frame(), set_label_type() and set_image()
 #[derive(WidgetExt, Debug)]
 pub struct Frame {
     _inner: *mut Fl_Box,
 }
+
+impl Frame {
+    /// Sets the image shown while the frame is deactivated, a copy of the image is used
+    pub fn set_deimage<Img: ImageExt>(&mut self, image: &Img) {
+        unsafe { Fl_Box_set_deimage(self._inner, image.as_ptr()) }
+    }
+
+    /// Returns the image shown while the frame is deactivated
+    pub fn deimage(&self) -> Option<Image> {
+        unsafe {
+            let image_ptr = Fl_Box_deimage(self._inner);
+            if image_ptr.is_null() {
+                None
+            } else {
+                Some(Image::from_raw(image_ptr as *mut fltk_sys::image::Fl_Image))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod frame {
+    use super::*;
+    #[test]
+    fn align() {
+        let mut frame = Frame::new(0, 0, 100, 30, "label");
+        let align = Align::AlignTop | Align::AlignLeft | Align::AlignInside | Align::AlignWrap;
+        frame.set_align(align);
+        assert!(frame.align() == align);
+        assert!(frame.align().contains(Align::AlignInside));
+        assert!(!frame.align().contains(Align::AlignClip));
+    }
+}
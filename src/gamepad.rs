@@ -0,0 +1,143 @@
+//! Routes gamepad input into the event loop, requires the gilrs feature.
+//! Controllers are polled from a timeout, their buttons are translated into key events
+//! delivered to the focused window, or handled by a callback
+
+pub use crate::prelude::*;
+use fltk_sys::fl::*;
+pub use gilrs::{Button, EventType, GamepadId};
+use std::{cell::RefCell, collections::HashMap, os::raw};
+
+type GamepadCallback = Box<dyn FnMut(GamepadId, EventType) -> bool>;
+
+struct Router {
+    gilrs: gilrs::Gilrs,
+    keys: HashMap<Button, Key>,
+    cb: Option<GamepadCallback>,
+    interval: f64,
+    active: bool,
+}
+
+thread_local! {
+    static ROUTER: RefCell<Option<Router>> = RefCell::new(None);
+}
+
+unsafe extern "C" fn poll(_data: *mut raw::c_void) {
+    let (events, cb) = ROUTER.with(|r| {
+        let mut r = r.borrow_mut();
+        match r.as_mut() {
+            Some(router) if router.active => {
+                let mut events = vec![];
+                while let Some(gilrs::Event { id, event, .. }) = router.gilrs.next_event() {
+                    events.push((id, event));
+                }
+                (events, router.cb.take())
+            }
+            _ => (vec![], None),
+        }
+    });
+    // The callback is taken out of the state since it might change the mapping
+    let mut cb = cb;
+    for (id, event) in events {
+        if let Some(cb) = cb.as_mut() {
+            if cb(id, event) {
+                continue;
+            }
+        }
+        let (button, down) = match event {
+            EventType::ButtonPressed(button, _) => (button, true),
+            EventType::ButtonReleased(button, _) => (button, false),
+            _ => continue,
+        };
+        let key = ROUTER.with(|r| {
+            r.borrow()
+                .as_ref()
+                .and_then(|r| r.keys.get(&button).copied())
+        });
+        if let Some(key) = key {
+            Fl_send_key(key.bits(), down as i32);
+        }
+    }
+    let interval = ROUTER.with(|r| {
+        let mut r = r.borrow_mut();
+        match r.as_mut() {
+            Some(router) if router.active => {
+                if router.cb.is_none() {
+                    router.cb = cb;
+                }
+                Some(router.interval)
+            }
+            _ => None,
+        }
+    });
+    if let Some(interval) = interval {
+        Fl_repeat_timeout(interval, Some(poll), std::ptr::null_mut());
+    }
+}
+
+/// Starts polling the connected controllers every interval seconds.
+/// The directional pad is mapped to the arrow keys, South to Enter and East to Escape
+pub fn enable(interval: f64) -> Result<(), FltkError> {
+    let gilrs = gilrs::Gilrs::new().map_err(|e| FltkError::Unknown(e.to_string()))?;
+    let mut keys = HashMap::new();
+    keys.insert(Button::DPadUp, Key::Up);
+    keys.insert(Button::DPadDown, Key::Down);
+    keys.insert(Button::DPadLeft, Key::Left);
+    keys.insert(Button::DPadRight, Key::Right);
+    keys.insert(Button::South, Key::Enter);
+    keys.insert(Button::East, Key::Escape);
+    let was_active = ROUTER.with(|r| {
+        let mut r = r.borrow_mut();
+        let was_active = r.as_ref().map_or(false, |r| r.active);
+        let cb = r.as_mut().and_then(|r| r.cb.take());
+        *r = Some(Router {
+            gilrs,
+            keys,
+            cb,
+            interval,
+            active: true,
+        });
+        was_active
+    });
+    if !was_active {
+        unsafe { Fl_add_timeout(interval, Some(poll), std::ptr::null_mut()) }
+    }
+    Ok(())
+}
+
+/// Stops polling the controllers
+pub fn disable() {
+    ROUTER.with(|r| {
+        if let Some(router) = r.borrow_mut().as_mut() {
+            router.active = false;
+        }
+    });
+    unsafe { Fl_remove_timeout(Some(poll), std::ptr::null_mut()) }
+}
+
+/// Maps a button to the key delivered when it's pressed and released, requires enable()
+pub fn map_button(button: Button, key: Key) {
+    ROUTER.with(|r| {
+        if let Some(router) = r.borrow_mut().as_mut() {
+            router.keys.insert(button, key);
+        }
+    });
+}
+
+/// Removes the key mapped to a button
+pub fn unmap_button(button: Button) {
+    ROUTER.with(|r| {
+        if let Some(router) = r.borrow_mut().as_mut() {
+            router.keys.remove(&button);
+        }
+    });
+}
+
+/// Sets a callback receiving every controller event before the button mapping,
+/// returning true stops the event from being delivered as a key. Requires enable()
+pub fn set_callback(cb: Box<dyn FnMut(GamepadId, EventType) -> bool>) {
+    ROUTER.with(|r| {
+        if let Some(router) = r.borrow_mut().as_mut() {
+            router.cb = Some(cb);
+        }
+    });
+}
@@ -3,9 +3,12 @@ pub use crate::prelude::*;
 use crate::widget::*;
 use fltk_sys::group::*;
 use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
     ffi::{CStr, CString},
     mem,
     os::raw,
+    rc::Rc,
 };
 
 /// Creates an widget group
@@ -20,12 +23,35 @@ pub struct Pack {
     _inner: *mut Fl_Pack,
 }
 
+/// Defines the direction in which a pack lays out its children, set using set_type()
+#[repr(i32)]
+#[derive(WidgetType, Debug, Copy, Clone, PartialEq)]
+pub enum PackType {
+    Vertical = 0,
+    Horizontal = 1,
+}
+
 /// Creates a scroll group
 #[derive(WidgetExt, GroupExt, Debug)]
 pub struct Scroll {
     _inner: *mut Fl_Scroll,
 }
 
+/// Defines the scrollbars shown by a scroll, set using set_type().
+/// By default both are shown when the contents don't fit
+#[repr(i32)]
+#[derive(WidgetType, Debug, Copy, Clone, PartialEq)]
+pub enum ScrollType {
+    None = 0,
+    Horizontal = 1,
+    Vertical = 2,
+    Both = 3,
+    AlwaysOn = 4,
+    HorizontalAlways = 5,
+    VerticalAlways = 6,
+    BothAlways = 7,
+}
+
 /// Creates a tab which can contain widgets
 #[derive(WidgetExt, GroupExt, Debug)]
 pub struct Tabs {
@@ -44,6 +70,68 @@ pub struct Wizard {
     _inner: *mut Fl_Wizard,
 }
 
+/// Iterates over the children of a group, created by GroupExt::iter
+#[derive(Debug)]
+pub struct GroupIterator {
+    group: Group,
+    idx: u32,
+}
+
+impl GroupIterator {
+    /// The pointer has to be a group, all groups can be accessed as an Fl_Group
+    pub(crate) unsafe fn new(ptr: *mut fltk_sys::widget::Fl_Widget) -> GroupIterator {
+        GroupIterator {
+            group: Group::from_widget_ptr(ptr),
+            idx: 0,
+        }
+    }
+}
+
+impl Iterator for GroupIterator {
+    type Item = Widget;
+    fn next(&mut self) -> Option<Self::Item> {
+        let child = self.group.child(self.idx)?;
+        self.idx += 1;
+        Some(child)
+    }
+}
+
+impl Group {
+    /// Returns the current group, which widgets are added to on construction
+    pub fn current() -> Option<Group> {
+        unsafe {
+            let ptr = Fl_Group_current();
+            if ptr.is_null() {
+                None
+            } else {
+                Some(Group::from_widget_ptr(ptr as *mut fltk_sys::widget::Fl_Widget))
+            }
+        }
+    }
+
+    /// Makes a group the current group, which widgets are added to on construction
+    pub fn set_current<G: GroupExt>(grp: &G) {
+        unsafe { Fl_Group_set_current(grp.as_widget_ptr() as *mut Fl_Group) }
+    }
+
+    /// Unsets the current group, widgets constructed afterwards have no parent
+    pub fn unset_current() {
+        unsafe { Fl_Group_set_current(std::ptr::null_mut()) }
+    }
+
+    /// Runs f with no current group, so the widgets it constructs have no parent,
+    /// then restores the current group
+    pub fn without_current<T, F: FnOnce() -> T>(f: F) -> T {
+        unsafe {
+            let current = Fl_Group_current();
+            Fl_Group_set_current(std::ptr::null_mut());
+            let ret = f();
+            Fl_Group_set_current(current);
+            ret
+        }
+    }
+}
+
 impl Wizard {
     /// Gets the next view of the wizard
     pub fn next(&mut self) {
@@ -72,6 +160,36 @@ impl Wizard {
             Fl_Wizard_set_value(self._inner, w.as_widget_ptr() as *mut fltk_sys::group::Fl_Widget)
         }
     }
+
+    /// Returns the number of pages of the wizard
+    pub fn page_count(&self) -> u32 {
+        self.children()
+    }
+
+    /// Returns the index of the current page, None for an empty wizard
+    pub fn current_index(&self) -> Option<u32> {
+        unsafe {
+            let ptr = Fl_Wizard_value(self._inner);
+            if ptr.is_null() {
+                return None;
+            }
+            let current = Widget::from_raw(ptr as *mut fltk_sys::widget::Fl_Widget);
+            match self.find(&current) {
+                idx if idx < self.children() => Some(idx),
+                _ => None,
+            }
+        }
+    }
+
+    /// Shows the page at an index, an index past the last page
+    /// returns FltkErrorKind::ResourceNotFound
+    pub fn set_current_index(&mut self, idx: u32) -> Result<(), FltkError> {
+        let page = self
+            .child(idx)
+            .ok_or(FltkError::Internal(FltkErrorKind::ResourceNotFound))?;
+        self.set_current_widget(&page);
+        Ok(())
+    }
 }
 
 /// Creates a color chooser widget
@@ -80,6 +198,20 @@ pub struct ColorChooser {
     _inner: *mut Fl_Color_Chooser,
 }
 
+/// Defines how a color chooser displays the values of the color
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ColorChooserMode {
+    /// Red, green and blue from 0 to 1
+    Rgb = 0,
+    /// Red, green and blue from 0 to 255
+    Byte,
+    /// A hex value like 0xff8800
+    Hex,
+    /// Hue, saturation and value
+    Hsv,
+}
+
 impl ColorChooser {
     pub fn rgb_color(&self) -> (u8, u8, u8) {
         unsafe {
@@ -94,6 +226,256 @@ impl ColorChooser {
         let x = Color::from_rgb(c.0, c.1, c.2);
         x.to_u32()
     }
+
+    /// Sets the color shown by the chooser
+    pub fn set_rgb(&mut self, r: u8, g: u8, b: u8) {
+        unsafe {
+            Fl_Color_Chooser_set_rgb(
+                self._inner,
+                r as f64 / 255.0,
+                g as f64 / 255.0,
+                b as f64 / 255.0,
+            );
+        }
+    }
+
+    /// Sets the color shown by the chooser from a hex value like 0xff8800
+    pub fn set_hex(&mut self, hex: u32) {
+        self.set_rgb((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+    }
+
+    /// Returns how the chooser displays the values of the color
+    pub fn mode(&self) -> ColorChooserMode {
+        unsafe { mem::transmute(Fl_Color_Chooser_mode(self._inner)) }
+    }
+
+    /// Sets how the chooser displays the values of the color
+    pub fn set_mode(&mut self, mode: ColorChooserMode) {
+        unsafe { Fl_Color_Chooser_set_mode(self._inner, mode as i32) }
+    }
+
+    /// Sets a callback run with the red, green and blue values of the color
+    /// whenever the user changes it. This replaces the callback of the chooser
+    pub fn set_color_changed_callback(&mut self, mut cb: Box<dyn FnMut(u8, u8, u8)>) {
+        let chooser = self.clone();
+        self.set_callback(Box::new(move || {
+            let (r, g, b) = chooser.rgb_color();
+            cb(r, g, b)
+        }));
+    }
+}
+
+impl Scroll {
+    /// Returns the horizontal scrolling position of the contents
+    pub fn xposition(&self) -> i32 {
+        unsafe { Fl_Scroll_xposition(self._inner) }
+    }
+
+    /// Returns the vertical scrolling position of the contents
+    pub fn yposition(&self) -> i32 {
+        unsafe { Fl_Scroll_yposition(self._inner) }
+    }
+
+    /// Scrolls the contents so that the position x, y is at the top left of the scroll,
+    /// relative to the contents' top left
+    pub fn scroll_to(&mut self, x: i32, y: i32) {
+        unsafe { Fl_Scroll_scroll_to(self._inner, x, y) }
+    }
+
+    /// Returns the vertical scrollbar of the scroll
+    pub fn scrollbar(&self) -> crate::valuator::Scrollbar {
+        unsafe {
+            let ptr = Fl_Scroll_scrollbar(self._inner);
+            assert!(!ptr.is_null());
+            crate::valuator::Scrollbar::from_widget_ptr(ptr as *mut fltk_sys::widget::Fl_Widget)
+        }
+    }
+
+    /// Returns the horizontal scrollbar of the scroll
+    pub fn hscrollbar(&self) -> crate::valuator::Scrollbar {
+        unsafe {
+            let ptr = Fl_Scroll_hscrollbar(self._inner);
+            assert!(!ptr.is_null());
+            crate::valuator::Scrollbar::from_widget_ptr(ptr as *mut fltk_sys::widget::Fl_Widget)
+        }
+    }
+
+    /// Returns the width of the scrollbars, 0 meaning the global scrollbar size is used
+    pub fn scrollbar_size(&self) -> i32 {
+        unsafe { Fl_Scroll_scrollbar_size(self._inner) }
+    }
+
+    /// Sets the width of the scrollbars, 0 uses the global scrollbar size
+    pub fn set_scrollbar_size(&mut self, size: u32) {
+        debug_assert!(
+            size <= std::i32::MAX as u32,
+            "u32 entries have to be < std::i32::MAX for compatibility!"
+        );
+        unsafe { Fl_Scroll_set_scrollbar_size(self._inner, size as i32) }
+    }
+}
+
+impl Tabs {
+    /// Returns the visible tab
+    pub fn value(&self) -> Option<Group> {
+        unsafe {
+            let ptr = Fl_Tabs_value(self._inner);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(Group::from_widget_ptr(ptr as *mut fltk_sys::widget::Fl_Widget))
+            }
+        }
+    }
+
+    /// Makes a tab the visible tab, a widget which isn't a child of the tabs
+    /// returns FltkErrorKind::FailedOperation
+    pub fn set_value<G: GroupExt>(&mut self, tab: &G) -> Result<(), FltkError> {
+        // FLTK hides every tab when passed another widget
+        if self.find(tab) == self.children() {
+            return Err(FltkError::Internal(FltkErrorKind::FailedOperation));
+        }
+        unsafe {
+            Fl_Tabs_set_value(
+                self._inner,
+                tab.as_widget_ptr() as *mut fltk_sys::group::Fl_Widget,
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the x, y, width and height of the area left for the tabs' children,
+    /// below or above the tab bar. A tab_height of 0 measures the tab bar from the labels
+    /// of the children, a positive one puts the tab bar at the top and a negative one at the bottom
+    pub fn client_area(&self, tab_height: i32) -> (i32, i32, i32, i32) {
+        let (mut x, mut y, mut w, mut h) = (0, 0, 0, 0);
+        unsafe { Fl_Tabs_client_area(self._inner, &mut x, &mut y, &mut w, &mut h, tab_height) }
+        (x, y, w, h)
+    }
+
+    /// Sets a callback run with the newly visible tab when the user changes tabs.
+    /// This replaces the callback of the tabs
+    pub fn set_tab_changed_callback(&mut self, mut cb: Box<dyn FnMut(Group)>) {
+        let tabs = self.clone();
+        self.set_callback(Box::new(move || {
+            if let Some(tab) = tabs.value() {
+                cb(tab)
+            }
+        }));
+    }
+
+    /// Pops up a menu at x and y listing the labels of the tabs, the visible one highlighted,
+    /// which reaches tabs whose labels don't fit in the tab bar. The chosen tab becomes
+    /// the visible tab, running the callback of the tabs, and is returned
+    pub fn popup_tab_list(&mut self, x: i32, y: i32) -> Option<Group> {
+        let tabs: Vec<Group> = self
+            .iter()
+            .map(|w| unsafe { Group::from_widget_ptr(w.as_widget_ptr()) })
+            .collect();
+        if tabs.is_empty() {
+            return None;
+        }
+        let labels: Vec<String> = tabs.iter().map(|t| t.label()).collect();
+        let mut menu = crate::menu::MenuItem::new(labels.iter().map(|l| l.as_str()).collect());
+        let mut picked = None;
+        let current = self.value().map(|t| unsafe { t.as_widget_ptr() });
+        for (i, tab) in tabs.iter().enumerate() {
+            if let Some(mut item) = menu.next(i as u32) {
+                item.set_user_data(i);
+                if current == Some(unsafe { tab.as_widget_ptr() }) {
+                    picked = Some(item);
+                }
+            }
+        }
+        let idx = menu
+            .pulldown(x, y, 0, 0, picked.as_ref())
+            .and_then(|item| item.user_data::<usize>());
+        unsafe { crate::menu::delete_menu_items(menu, tabs.len() as u32) }
+        let tab = tabs[idx?].clone();
+        if current != Some(unsafe { tab.as_widget_ptr() }) {
+            let _ = self.set_value(&tab);
+            self.do_callback();
+        }
+        Some(tab)
+    }
+
+    /// Pops up the tab list, see popup_tab_list, when the tab bar is right clicked,
+    /// instead of switching to the clicked tab
+    pub fn enable_tab_list(&mut self) {
+        let mut tabs = self.clone();
+        crate::widget::intercept_events(
+            unsafe { self.as_widget_ptr() },
+            "tab_list",
+            Box::new(move |ev| match ev {
+                Event::Push if crate::app::event_button() == 3 => {
+                    let (x, y) = crate::app::event_coords();
+                    let (_, cy, _, ch) = tabs.client_area(0);
+                    if y >= cy && y < cy + ch {
+                        return false;
+                    }
+                    tabs.popup_tab_list(x, y);
+                    true
+                }
+                _ => false,
+            }),
+        );
+    }
+}
+
+impl Tile {
+    /// Moves the dividers at old_x and old_y to new_x and new_y, resizing the panes
+    /// on both sides as if the user dragged them. A divider isn't moved along an axis
+    /// whose old and new positions are equal
+    pub fn position(&mut self, old_x: i32, old_y: i32, new_x: i32, new_y: i32) {
+        unsafe { Fl_Tile_position(self._inner, old_x, old_y, new_x, new_y) }
+        self.redraw();
+    }
+
+    /// Stops the user from dragging the dividers closer than min_w and min_h to the
+    /// edges of the tile, keeping the panes next to the edges at least that large.
+    /// An invisible frame is added to the tile as its resizable widget, call this after
+    /// adding the panes. Calling it again resizes that frame
+    pub fn set_min_pane_size(&mut self, min_w: u32, min_h: u32) {
+        debug_assert!(
+            min_w <= std::i32::MAX as u32 && min_h <= std::i32::MAX as u32,
+            "u32 entries have to be < std::i32::MAX for compatibility!"
+        );
+        let (min_w, min_h) = (min_w as i32, min_h as i32);
+        let (x, y) = (self.x() + min_w, self.y() + min_h);
+        let (w, h) = ((self.width() - 2 * min_w).max(0), (self.height() - 2 * min_h).max(0));
+        let tile = self._inner as usize;
+        if let Some(frame) = TILE_LIMITS.with(|l| l.borrow().get(&tile).copied()) {
+            let mut limits = unsafe {
+                crate::frame::Frame::from_widget_ptr(frame as *mut fltk_sys::widget::Fl_Widget)
+            };
+            if self.find(&limits) < self.children() {
+                limits.resize(x, y, w, h);
+                self.set_resizable(&limits);
+                return;
+            }
+        }
+        let limits = Group::without_current(|| crate::frame::Frame::new(x, y, w, h, ""));
+        self.add(&limits);
+        self.set_resizable(&limits);
+        let frame = unsafe { limits.as_widget_ptr() };
+        TILE_LIMITS.with(|l| l.borrow_mut().insert(tile, frame as usize));
+        crate::widget::on_delete(
+            frame,
+            Box::new(move || {
+                TILE_LIMITS.with(|l| {
+                    let mut l = l.borrow_mut();
+                    if l.get(&tile) == Some(&(frame as usize)) {
+                        l.remove(&tile);
+                    }
+                })
+            }),
+        );
+    }
+}
+
+thread_local! {
+    // The frame added by set_min_pane_size to each tile, dropped once the frame is deleted
+    static TILE_LIMITS: RefCell<HashMap<usize, usize>> = RefCell::new(HashMap::new());
 }
 
 impl Pack {
@@ -104,4 +486,631 @@ impl Pack {
     pub fn set_spacing(&mut self, spacing: i32) {
         unsafe { Fl_Pack_set_spacing(self._inner, spacing); }
     }
+
+    /// Lays out the children one after the other in the direction of the pack,
+    /// separated by the spacing and stretched across the pack. The children are laid out
+    /// again each time the pack is resized, keeping their sizes in its direction
+    /// instead of scaling them
+    pub fn auto_layout(&mut self) {
+        self.layout_children();
+        let pack = self._inner as usize;
+        if !AUTO_LAYOUT_PACKS.with(|p| p.borrow_mut().insert(pack)) {
+            return;
+        }
+        let wid = unsafe { self.as_widget_ptr() };
+        crate::widget::on_resize(
+            wid,
+            Box::new(move || unsafe { Pack::from_widget_ptr(wid).layout_children() }),
+        );
+        crate::widget::on_delete(
+            wid,
+            Box::new(move || {
+                AUTO_LAYOUT_PACKS.with(|p| p.borrow_mut().remove(&pack));
+            }),
+        );
+    }
+
+    fn layout_children(&mut self) {
+        let spacing = self.spacing();
+        let horizontal = self.get_type::<PackType>() == PackType::Horizontal;
+        let (mut x, mut y) = (self.x(), self.y());
+        for mut child in self.iter() {
+            if horizontal {
+                child.resize(x, self.y(), child.width(), self.height());
+                x += child.width() + spacing;
+            } else {
+                child.resize(self.x(), y, self.width(), child.height());
+                y += child.height() + spacing;
+            }
+        }
+        self.clear_resizable();
+        self.redraw();
+    }
+}
+
+thread_local! {
+    // The packs laid out again when resized, see Pack::auto_layout
+    static AUTO_LAYOUT_PACKS: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+}
+
+struct ClosableTab {
+    header: Group,
+    button: crate::button::Button,
+    page: Group,
+}
+
+struct ClosableTabsState {
+    tabs: Vec<ClosableTab>,
+    current: Option<usize>,
+    tab_width: i32,
+    close_cb: Option<Box<dyn FnMut(Group)>>,
+    changed_cb: Option<Box<dyn FnMut(Group)>>,
+}
+
+/// Creates a tab container whose tabs have a close button.
+/// Pages are added using add_tab, and closing a tab removes it
+/// unless a close requested callback is set
+pub struct ClosableTabs {
+    grp: Group,
+    bar: Group,
+    content: Group,
+    state: Rc<RefCell<ClosableTabsState>>,
+}
+
+impl ClosableTabs {
+    /// The height of the tab bar
+    const TAB_HEIGHT: i32 = 25;
+
+    /// Creates a new ClosableTabs widget
+    pub fn new(x: i32, y: i32, w: i32, h: i32, label: &str) -> ClosableTabs {
+        let mut grp = Group::new(x, y, w, h, label);
+        let mut bar = Group::new(x, y, w, ClosableTabs::TAB_HEIGHT, "");
+        bar.end();
+        bar.clear_resizable();
+        let content = Group::new(
+            x,
+            y + ClosableTabs::TAB_HEIGHT,
+            w,
+            h - ClosableTabs::TAB_HEIGHT,
+            "",
+        );
+        content.end();
+        grp.end();
+        grp.set_resizable(&content);
+        let state = Rc::new(RefCell::new(ClosableTabsState {
+            tabs: vec![],
+            current: None,
+            tab_width: 120,
+            close_cb: None,
+            changed_cb: None,
+        }));
+        ClosableTabs {
+            grp,
+            bar,
+            content,
+            state,
+        }
+    }
+
+    /// Creates a default and zero initialized ClosableTabs
+    pub fn default() -> ClosableTabs {
+        ClosableTabs::new(0, 0, 0, 0, "")
+    }
+
+    /// Returns the group holding the tab bar and the pages, useful for positioning and styling
+    pub fn group(&self) -> Group {
+        self.grp.clone()
+    }
+
+    /// Adds a tab and returns its page, which is ended. Use begin and end to fill it.
+    /// The new tab becomes the visible tab
+    pub fn add_tab(&mut self, label: &str) -> Group {
+        let (x, y) = (self.bar.x(), self.bar.y());
+        let th = ClosableTabs::TAB_HEIGHT;
+        let (tw, idx) = {
+            let state = self.state.borrow();
+            (state.tab_width, state.tabs.len() as i32)
+        };
+        let (mut header, mut button, mut close, page) = Group::without_current(|| {
+            let header = Group::new(x + idx * tw, y, tw, th, "");
+            let button = crate::button::Button::new(x + idx * tw, y, tw - th, th, label);
+            let close = crate::button::Button::new(x + idx * tw + tw - th, y, th, th, "x");
+            header.end();
+            let page = Group::new(
+                self.content.x(),
+                self.content.y(),
+                self.content.width(),
+                self.content.height(),
+                "",
+            );
+            page.end();
+            (header, button, close, page)
+        });
+        header.add(&button);
+        header.add(&close);
+        header.set_resizable(&button);
+        self.bar.add(&header);
+        self.content.add(&page);
+        let state = self.state.clone();
+        let pg = page.clone();
+        button.set_callback(Box::new(move || {
+            if let Some(idx) = ClosableTabs::index_of(&state, &pg) {
+                ClosableTabs::select(&state, idx, true);
+            }
+        }));
+        let mut tabs = self.clone();
+        let pg = page.clone();
+        close.set_callback(Box::new(move || {
+            // The callback is taken out since it's likely to remove tabs
+            let cb = mem::take(&mut tabs.state.borrow_mut().close_cb);
+            match cb {
+                Some(mut cb) => {
+                    cb(pg.clone());
+                    let mut state = tabs.state.borrow_mut();
+                    if state.close_cb.is_none() {
+                        state.close_cb = Some(cb);
+                    }
+                }
+                None => {
+                    let _ = tabs.remove_tab(&pg);
+                }
+            }
+        }));
+        self.state.borrow_mut().tabs.push(ClosableTab {
+            header,
+            button,
+            page: page.clone(),
+        });
+        ClosableTabs::select(&self.state, idx as usize, false);
+        self.grp.redraw();
+        page
+    }
+
+    /// Removes the tab of a page and deletes the page.
+    /// If it was visible, the next tab becomes visible and the tab changed callback is run.
+    /// A widget which isn't a page returns FltkErrorKind::ResourceNotFound
+    pub fn remove_tab<G: GroupExt>(&mut self, page: &G) -> Result<(), FltkError> {
+        let idx = ClosableTabs::index_of(&self.state, page)
+            .ok_or(FltkError::Internal(FltkErrorKind::ResourceNotFound))?;
+        let (tab, next) = {
+            let mut state = self.state.borrow_mut();
+            let tab = state.tabs.remove(idx);
+            let next = match state.current {
+                Some(current) if current == idx => {
+                    state.current = None;
+                    if state.tabs.is_empty() {
+                        None
+                    } else {
+                        Some(idx.min(state.tabs.len() - 1))
+                    }
+                }
+                Some(current) if current > idx => {
+                    state.current = Some(current - 1);
+                    None
+                }
+                _ => None,
+            };
+            (tab, next)
+        };
+        self.bar.remove(&tab.header);
+        self.content.remove(&tab.page);
+        crate::app::delete_widget(&tab.header);
+        crate::app::delete_widget(&tab.page);
+        self.relayout();
+        if let Some(next) = next {
+            ClosableTabs::select(&self.state, next, true);
+        }
+        self.grp.redraw();
+        Ok(())
+    }
+
+    /// Returns the pages of the tabs, in order
+    pub fn pages(&self) -> Vec<Group> {
+        self.state.borrow().tabs.iter().map(|t| t.page.clone()).collect()
+    }
+
+    /// Returns the page of the visible tab
+    pub fn value(&self) -> Option<Group> {
+        let state = self.state.borrow();
+        state.current.map(|idx| state.tabs[idx].page.clone())
+    }
+
+    /// Makes the tab of a page the visible tab,
+    /// a widget which isn't a page returns FltkErrorKind::ResourceNotFound
+    pub fn set_value<G: GroupExt>(&mut self, page: &G) -> Result<(), FltkError> {
+        let idx = ClosableTabs::index_of(&self.state, page)
+            .ok_or(FltkError::Internal(FltkErrorKind::ResourceNotFound))?;
+        ClosableTabs::select(&self.state, idx, false);
+        Ok(())
+    }
+
+    /// Sets the label of the tab of a page,
+    /// a widget which isn't a page returns FltkErrorKind::ResourceNotFound
+    pub fn set_tab_label<G: GroupExt>(&mut self, page: &G, label: &str) -> Result<(), FltkError> {
+        let idx = ClosableTabs::index_of(&self.state, page)
+            .ok_or(FltkError::Internal(FltkErrorKind::ResourceNotFound))?;
+        self.state.borrow_mut().tabs[idx].button.set_label(label);
+        Ok(())
+    }
+
+    /// Sets the width of the tabs, labels which don't fit are clipped
+    pub fn set_tab_width(&mut self, width: u32) {
+        debug_assert!(
+            width <= std::i32::MAX as u32,
+            "u32 entries have to be < std::i32::MAX for compatibility!"
+        );
+        self.state.borrow_mut().tab_width = width as i32;
+        self.relayout();
+        self.grp.redraw();
+    }
+
+    /// Sets a callback run with the page of a tab whose close button was clicked,
+    /// the tab is then only removed if the callback calls remove_tab
+    pub fn set_close_requested_callback(&mut self, cb: Box<dyn FnMut(Group)>) {
+        self.state.borrow_mut().close_cb = Some(cb);
+    }
+
+    /// Sets a callback run with the page of the newly visible tab
+    /// when the user changes tabs or the visible tab is closed
+    pub fn set_tab_changed_callback(&mut self, cb: Box<dyn FnMut(Group)>) {
+        self.state.borrow_mut().changed_cb = Some(cb);
+    }
+
+    fn index_of<G: GroupExt>(state: &Rc<RefCell<ClosableTabsState>>, page: &G) -> Option<usize> {
+        let ptr = unsafe { page.as_widget_ptr() };
+        state
+            .borrow()
+            .tabs
+            .iter()
+            .position(|t| unsafe { t.page.as_widget_ptr() } == ptr)
+    }
+
+    fn select(state: &Rc<RefCell<ClosableTabsState>>, idx: usize, notify: bool) {
+        let page = {
+            let mut state = state.borrow_mut();
+            if state.current == Some(idx) {
+                return;
+            }
+            state.current = Some(idx);
+            for (i, tab) in state.tabs.iter_mut().enumerate() {
+                if i == idx {
+                    tab.page.show();
+                    tab.button.set_frame(FrameType::DownBox);
+                } else {
+                    tab.page.hide();
+                    tab.button.set_frame(FrameType::UpBox);
+                }
+                tab.header.redraw();
+            }
+            state.tabs[idx].page.clone()
+        };
+        if !notify {
+            return;
+        }
+        let cb = mem::take(&mut state.borrow_mut().changed_cb);
+        if let Some(mut cb) = cb {
+            cb(page);
+            let mut state = state.borrow_mut();
+            if state.changed_cb.is_none() {
+                state.changed_cb = Some(cb);
+            }
+        }
+    }
+
+    fn relayout(&mut self) {
+        let (x, y) = (self.bar.x(), self.bar.y());
+        let th = ClosableTabs::TAB_HEIGHT;
+        let mut state = self.state.borrow_mut();
+        let tw = state.tab_width;
+        for (i, tab) in state.tabs.iter_mut().enumerate() {
+            tab.header.resize(x + i as i32 * tw, y, tw, th);
+        }
+    }
+}
+
+impl Clone for ClosableTabs {
+    fn clone(&self) -> ClosableTabs {
+        ClosableTabs {
+            grp: self.grp.clone(),
+            bar: self.bar.clone(),
+            content: self.content.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+struct GridCell {
+    widget: Widget,
+    row: usize,
+    col: usize,
+    row_span: usize,
+    col_span: usize,
+}
+
+struct GridState {
+    row_weights: Vec<u32>,
+    col_weights: Vec<u32>,
+    gap: i32,
+    margin: i32,
+    cells: Vec<GridCell>,
+}
+
+/// Creates a container laying out its children in rows and columns.
+/// Rows and columns share the space according to their weights, 1 by default,
+/// and a child can span several of them. The children are laid out again when the grid
+/// is resized
+pub struct Grid {
+    grp: Group,
+    state: Rc<RefCell<GridState>>,
+}
+
+impl Grid {
+    /// Creates a new Grid widget, with one row and one column
+    pub fn new(x: i32, y: i32, w: i32, h: i32, label: &str) -> Grid {
+        let grp = Group::new(x, y, w, h, label);
+        grp.end();
+        let state = Rc::new(RefCell::new(GridState {
+            row_weights: vec![1],
+            col_weights: vec![1],
+            gap: 0,
+            margin: 0,
+            cells: vec![],
+        }));
+        let wid = unsafe { grp.as_widget_ptr() };
+        let st = state.clone();
+        crate::widget::on_resize(
+            wid,
+            Box::new(move || {
+                // Resizes done while the grid is being changed are followed by a layout anyway
+                if let Ok(mut state) = st.try_borrow_mut() {
+                    Grid::layout_cells(unsafe { &mut Group::from_widget_ptr(wid) }, &mut state);
+                }
+            }),
+        );
+        Grid { grp, state }
+    }
+
+    /// Creates a default and zero initialized Grid
+    pub fn default() -> Grid {
+        Grid::new(0, 0, 0, 0, "")
+    }
+
+    /// Returns the group holding the children, useful for positioning and styling
+    pub fn group(&self) -> Group {
+        self.grp.clone()
+    }
+
+    /// Sets the number of rows and columns, resetting their weights to 1.
+    /// Children placed outside of the new bounds are removed from the grid.
+    /// A grid without rows or columns returns FltkErrorKind::FailedOperation
+    pub fn set_layout(&mut self, rows: u32, cols: u32) -> Result<(), FltkError> {
+        if rows == 0 || cols == 0 {
+            return Err(FltkError::Internal(FltkErrorKind::FailedOperation));
+        }
+        let outside: Vec<Widget> = {
+            let mut state = self.state.borrow_mut();
+            state.row_weights = vec![1; rows as usize];
+            state.col_weights = vec![1; cols as usize];
+            state
+                .cells
+                .iter()
+                .filter(|c| {
+                    c.row + c.row_span > rows as usize || c.col + c.col_span > cols as usize
+                })
+                .map(|c| c.widget.clone())
+                .collect()
+        };
+        for widget in outside.iter() {
+            let _ = self.remove_widget(widget);
+        }
+        self.layout();
+        Ok(())
+    }
+
+    /// Sets the share of the height a row takes relative to the other rows,
+    /// a row past the last returns FltkErrorKind::ResourceNotFound
+    pub fn set_row_weight(&mut self, row: u32, weight: u32) -> Result<(), FltkError> {
+        match self.state.borrow_mut().row_weights.get_mut(row as usize) {
+            Some(w) => *w = weight,
+            None => return Err(FltkError::Internal(FltkErrorKind::ResourceNotFound)),
+        }
+        self.layout();
+        Ok(())
+    }
+
+    /// Sets the share of the width a column takes relative to the other columns,
+    /// a column past the last returns FltkErrorKind::ResourceNotFound
+    pub fn set_col_weight(&mut self, col: u32, weight: u32) -> Result<(), FltkError> {
+        match self.state.borrow_mut().col_weights.get_mut(col as usize) {
+            Some(w) => *w = weight,
+            None => return Err(FltkError::Internal(FltkErrorKind::ResourceNotFound)),
+        }
+        self.layout();
+        Ok(())
+    }
+
+    /// Sets the space between rows and columns
+    pub fn set_gap(&mut self, gap: u32) {
+        debug_assert!(
+            gap <= std::i32::MAX as u32,
+            "u32 entries have to be < std::i32::MAX for compatibility!"
+        );
+        self.state.borrow_mut().gap = gap as i32;
+        self.layout();
+    }
+
+    /// Sets the space between the edges of the grid and its children
+    pub fn set_margin(&mut self, margin: u32) {
+        debug_assert!(
+            margin <= std::i32::MAX as u32,
+            "u32 entries have to be < std::i32::MAX for compatibility!"
+        );
+        self.state.borrow_mut().margin = margin as i32;
+        self.layout();
+    }
+
+    /// Places a widget in the cell at row and col, adding it to the grid if needed
+    pub fn set_widget<W: WidgetExt>(
+        &mut self,
+        widget: &W,
+        row: u32,
+        col: u32,
+    ) -> Result<(), FltkError> {
+        self.set_widget_span(widget, row, col, 1, 1)
+    }
+
+    /// Places a widget in the cells starting at row and col, spanning row_span rows
+    /// and col_span columns, adding it to the grid if needed.
+    /// Cells outside of the grid return FltkErrorKind::FailedOperation
+    pub fn set_widget_span<W: WidgetExt>(
+        &mut self,
+        widget: &W,
+        row: u32,
+        col: u32,
+        row_span: u32,
+        col_span: u32,
+    ) -> Result<(), FltkError> {
+        let (row, col) = (row as usize, col as usize);
+        let (row_span, col_span) = (row_span as usize, col_span as usize);
+        {
+            let state = self.state.borrow();
+            if row_span == 0
+                || col_span == 0
+                || row + row_span > state.row_weights.len()
+                || col + col_span > state.col_weights.len()
+            {
+                return Err(FltkError::Internal(FltkErrorKind::FailedOperation));
+            }
+        }
+        let widget = unsafe { Widget::from_raw(widget.as_widget_ptr()) };
+        if self.grp.find(&widget) == self.grp.children() {
+            self.grp.add(&widget);
+        }
+        let ptr = unsafe { widget.as_widget_ptr() };
+        let mut state = self.state.borrow_mut();
+        state.cells.retain(|c| unsafe { c.widget.as_widget_ptr() } != ptr);
+        state.cells.push(GridCell {
+            widget,
+            row,
+            col,
+            row_span,
+            col_span,
+        });
+        drop(state);
+        self.layout();
+        Ok(())
+    }
+
+    /// Removes a widget from the grid, without deleting it.
+    /// A widget which isn't in the grid returns FltkErrorKind::ResourceNotFound
+    pub fn remove_widget<W: WidgetExt>(&mut self, widget: &W) -> Result<(), FltkError> {
+        let ptr = unsafe { widget.as_widget_ptr() };
+        let cell = {
+            let mut state = self.state.borrow_mut();
+            let idx = state
+                .cells
+                .iter()
+                .position(|c| unsafe { c.widget.as_widget_ptr() } == ptr)
+                .ok_or(FltkError::Internal(FltkErrorKind::ResourceNotFound))?;
+            state.cells.remove(idx)
+        };
+        self.grp.remove(&cell.widget);
+        self.grp.redraw();
+        Ok(())
+    }
+
+    /// Positions the children in their cells, done after every change to the grid
+    /// and when it's resized
+    pub fn layout(&mut self) {
+        Grid::layout_cells(&mut self.grp, &mut self.state.borrow_mut());
+    }
+
+    fn layout_cells(grp: &mut Group, state: &mut GridState) {
+        let cols = Grid::edges(
+            &state.col_weights,
+            grp.x() + state.margin,
+            grp.width() - 2 * state.margin,
+            state.gap,
+        );
+        let rows = Grid::edges(
+            &state.row_weights,
+            grp.y() + state.margin,
+            grp.height() - 2 * state.margin,
+            state.gap,
+        );
+        let gap = state.gap;
+        for cell in state.cells.iter_mut() {
+            let x = cols[cell.col];
+            let y = rows[cell.row];
+            let w = cols[cell.col + cell.col_span] - gap - x;
+            let h = rows[cell.row + cell.row_span] - gap - y;
+            cell.widget.resize(x, y, w.max(0), h.max(0));
+        }
+        grp.redraw();
+    }
+
+    /// Returns the start of each track followed by the end of the last one plus the gap,
+    /// the tracks sharing the length left by the gaps according to their weights
+    fn edges(weights: &[u32], start: i32, len: i32, gap: i32) -> Vec<i32> {
+        let total: u64 = weights.iter().map(|w| *w as u64).sum();
+        let avail = (len - gap * (weights.len() as i32 - 1)).max(0) as u64;
+        let mut edges = Vec::with_capacity(weights.len() + 1);
+        let mut acc = 0;
+        for (i, w) in weights.iter().enumerate() {
+            let offset = if total == 0 { 0 } else { avail * acc / total };
+            edges.push(start + offset as i32 + i as i32 * gap);
+            acc += *w as u64;
+        }
+        let offset = if total == 0 { 0 } else { avail * acc / total };
+        edges.push(start + offset as i32 + weights.len() as i32 * gap);
+        edges
+    }
+}
+
+#[cfg(test)]
+mod group {
+    use super::*;
+    #[test]
+    fn auto_layout() {
+        let _win = crate::window::Window::default();
+        let mut pack = Pack::new(0, 0, 100, 100, "");
+        pack.set_spacing(10);
+        let first = crate::frame::Frame::new(0, 0, 20, 30, "");
+        let second = crate::frame::Frame::new(0, 0, 20, 40, "");
+        pack.end();
+        pack.auto_layout();
+        pack.auto_layout();
+        assert!((second.y(), second.width(), second.height()) == (40, 100, 40));
+        // Resizing the pack lays the children out again
+        pack.resize(0, 0, 200, 300);
+        assert!((first.y(), first.width(), first.height()) == (0, 200, 30));
+        assert!((second.y(), second.width(), second.height()) == (40, 200, 40));
+    }
+
+    #[test]
+    fn grid_edges() {
+        assert!(Grid::edges(&[1, 1], 0, 100, 0) == vec![0, 50, 100]);
+        assert!(Grid::edges(&[1, 3], 0, 100, 0) == vec![0, 25, 100]);
+        assert!(Grid::edges(&[1, 2, 0], 0, 90, 0) == vec![0, 30, 90, 90]);
+        // The end of the last track is followed by a gap, like the others
+        assert!(Grid::edges(&[1, 1], 10, 110, 10) == vec![10, 70, 130]);
+        assert!(Grid::edges(&[0, 0], 0, 100, 5) == vec![0, 5, 10]);
+        assert!(Grid::edges(&[1, 1], 0, -20, 5) == vec![0, 5, 10]);
+    }
+
+    #[test]
+    fn grid() {
+        let _win = crate::window::Window::default();
+        let mut grid = Grid::new(0, 0, 100, 100, "");
+        assert!(grid.set_layout(0, 2).is_err());
+        assert!(grid.set_layout(2, 0).is_err());
+        grid.set_layout(1, 2).unwrap();
+        let frame = crate::frame::Frame::default();
+        grid.set_widget(&frame, 0, 1).unwrap();
+        assert!((frame.x(), frame.width(), frame.height()) == (50, 50, 100));
+        // Resizing the grid lays the children out again
+        grid.set_gap(10);
+        grid.group().resize(0, 0, 210, 50);
+        assert!((frame.x(), frame.width(), frame.height()) == (110, 100, 50));
+    }
 }
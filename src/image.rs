@@ -353,6 +353,107 @@ impl RgbImage {
         (self.to_rgb(), w, h)
     }
 
+    /// Returns the number of bytes per pixel
+    pub fn depth(&self) -> u32 {
+        unsafe { Fl_RGB_Image_depth(self._inner) as u32 }
+    }
+
+    /// Returns the pixel at x, y as rgba, grayscale pixels are expanded and missing alpha is 255
+    pub fn pixel(&self, x: i32, y: i32) -> (u8, u8, u8, u8) {
+        let d = self.depth() as usize;
+        let row = self.row(y);
+        assert!(
+            x >= 0 && (x as usize) < row.len() / d,
+            "Pixel out of bounds!"
+        );
+        RgbImage::read_pixel(&row[x as usize * d..x as usize * d + d])
+    }
+
+    /// Sets the pixel at x, y from rgba, for grayscale images only the red channel is used
+    pub fn set_pixel(&mut self, x: i32, y: i32, rgba: (u8, u8, u8, u8)) {
+        let d = self.depth() as usize;
+        let row = self.row_mut(y);
+        assert!(
+            x >= 0 && (x as usize) < row.len() / d,
+            "Pixel out of bounds!"
+        );
+        RgbImage::write_pixel(&mut row[x as usize * d..x as usize * d + d], rgba);
+    }
+
+    /// Returns the raw data of a row of pixels
+    pub fn row(&self, y: i32) -> &[u8] {
+        let (w, h) = self.data_size();
+        assert!(y >= 0 && y < h, "Row out of bounds!");
+        unsafe {
+            let pixels = Fl_RGB_Image_pixels(self._inner);
+            assert!(!pixels.is_null());
+            let ld = Fl_RGB_Image_line_size(self._inner) as usize;
+            std::slice::from_raw_parts(
+                pixels.add(y as usize * ld),
+                w as usize * self.depth() as usize,
+            )
+        }
+    }
+
+    /// Returns the raw data of a row of pixels for modification
+    pub fn row_mut(&mut self, y: i32) -> &mut [u8] {
+        let (w, h) = self.data_size();
+        assert!(y >= 0 && y < h, "Row out of bounds!");
+        unsafe {
+            let pixels = Fl_RGB_Image_pixels(self._inner);
+            assert!(!pixels.is_null());
+            Fl_RGB_Image_uncache(self._inner);
+            let ld = Fl_RGB_Image_line_size(self._inner) as usize;
+            std::slice::from_raw_parts_mut(
+                pixels.add(y as usize * ld),
+                w as usize * self.depth() as usize,
+            )
+        }
+    }
+
+    /// Transforms every pixel of the image, the closure receiving and returning rgba values
+    pub fn map_pixels(&mut self, mut cb: Box<dyn FnMut((u8, u8, u8, u8)) -> (u8, u8, u8, u8)>) {
+        let (_, h) = self.data_size();
+        let d = self.depth() as usize;
+        for y in 0..h {
+            for p in self.row_mut(y).chunks_mut(d) {
+                let rgba = cb(RgbImage::read_pixel(p));
+                RgbImage::write_pixel(p, rgba);
+            }
+        }
+    }
+
+    /// Returns the size of the pixel data, which differs from the drawing size of a scaled image
+    fn data_size(&self) -> (i32, i32) {
+        unsafe {
+            (
+                Fl_RGB_Image_data_w(self._inner),
+                Fl_RGB_Image_data_h(self._inner),
+            )
+        }
+    }
+
+    fn read_pixel(p: &[u8]) -> (u8, u8, u8, u8) {
+        match p.len() {
+            1 => (p[0], p[0], p[0], 255),
+            2 => (p[0], p[0], p[0], p[1]),
+            3 => (p[0], p[1], p[2], 255),
+            _ => (p[0], p[1], p[2], p[3]),
+        }
+    }
+
+    fn write_pixel(p: &mut [u8], rgba: (u8, u8, u8, u8)) {
+        match p.len() {
+            1 => p[0] = rgba.0,
+            2 => {
+                p[0] = rgba.0;
+                p[1] = rgba.3;
+            }
+            3 => p.copy_from_slice(&[rgba.0, rgba.1, rgba.2]),
+            _ => p.copy_from_slice(&[rgba.0, rgba.1, rgba.2, rgba.3]),
+        }
+    }
+
     /// Transforms the RgbImage to a PngImage
     pub fn into_png_image(self) -> Result<PngImage, FltkError> {
         let path = std::path::PathBuf::from("_internal_temp_fltk_file.png");
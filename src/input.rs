@@ -2,9 +2,10 @@ use crate::image::Image;
 pub use crate::prelude::*;
 use fltk_sys::input::*;
 use std::{
+    cell::RefCell,
     ffi::{CStr, CString},
-    mem,
     os::raw,
+    rc::Rc,
 };
 
 /// Creates an input widget
@@ -56,3 +57,134 @@ pub struct FileInput {
 pub struct SecretInput {
     _inner: *mut Fl_Secret_Input,
 }
+
+/// Creates a hotkey input, a read-only field capturing the next key chord pressed while it has
+/// focus and displaying it like Ctrl+Shift+K. Tab keeps navigating between widgets,
+/// BackSpace and Delete without modifiers clear the hotkey
+#[derive(Clone)]
+pub struct HotkeyInput {
+    input: Input,
+    state: Rc<RefCell<HotkeyState>>,
+}
+
+struct HotkeyState {
+    shortcut: Option<Shortcut>,
+    reserved: Vec<Shortcut>,
+    on_change: Option<Box<dyn FnMut(Option<Shortcut>)>>,
+}
+
+impl HotkeyInput {
+    /// Creates a new HotkeyInput widget
+    pub fn new(x: i32, y: i32, w: i32, h: i32) -> HotkeyInput {
+        let mut input = Input::new(x, y, w, h, "");
+        input.set_readonly(true);
+        let state = HotkeyState {
+            shortcut: None,
+            reserved: vec![],
+            on_change: None,
+        };
+        let mut hotkey = HotkeyInput {
+            input,
+            state: Rc::new(RefCell::new(state)),
+        };
+        let mut capture = hotkey.clone();
+        hotkey.input.handle(Box::new(move |ev| match ev {
+            Event::KeyDown => capture.capture(crate::app::event_key(), crate::app::event_state()),
+            _ => false,
+        }));
+        hotkey
+    }
+
+    /// Creates a default and zero initialized HotkeyInput
+    pub fn default() -> HotkeyInput {
+        HotkeyInput::new(0, 0, 0, 0)
+    }
+
+    /// Returns the underlying Input, useful for positioning and styling
+    pub fn input(&self) -> Input {
+        self.input.clone()
+    }
+
+    /// Returns the captured hotkey, a Shortcut combining the modifiers and the key
+    pub fn shortcut(&self) -> Option<Shortcut> {
+        self.state.borrow().shortcut
+    }
+
+    /// Sets the hotkey, it's displayed but not checked against the reserved hotkeys
+    pub fn set_shortcut(&mut self, shortcut: Option<Shortcut>) {
+        self.state.borrow_mut().shortcut = shortcut;
+        let label = shortcut.map_or(String::new(), crate::draw::shortcut_label);
+        self.input.set_value(&label);
+    }
+
+    /// Sets the hotkeys which can't be captured, like the application's fixed shortcuts.
+    /// Pressing a reserved hotkey beeps and keeps the current one
+    pub fn set_reserved(&mut self, reserved: &[Shortcut]) {
+        self.state.borrow_mut().reserved = reserved.to_vec();
+    }
+
+    /// Sets the callback called with the new hotkey after one is captured or cleared
+    pub fn on_change(&mut self, cb: Box<dyn FnMut(Option<Shortcut>)>) {
+        self.state.borrow_mut().on_change = Some(cb);
+    }
+
+    fn capture(&mut self, key: Key, state: Shortcut) -> bool {
+        let mods = state.bits()
+            & (Shortcut::Shift | Shortcut::Ctrl | Shortcut::Alt | Shortcut::Meta).bits();
+        let shortcut = match key {
+            Key::ShiftL
+            | Key::ShiftR
+            | Key::ControlL
+            | Key::ControlR
+            | Key::AltL
+            | Key::AltR
+            | Key::MetaL
+            | Key::MetaR
+            | Key::CapsLock => return true,
+            Key::Tab if mods == 0 => return false,
+            Key::BackSpace | Key::Delete if mods == 0 => None,
+            _ => Some(Shortcut::from_i32(mods) + key),
+        };
+        if let Some(shortcut) = shortcut {
+            if self.state.borrow().reserved.contains(&shortcut) {
+                crate::dialog::beep(crate::dialog::BeepType::Error);
+                return true;
+            }
+        }
+        self.set_shortcut(shortcut);
+        // Taken out during the call, so the callback can use this widget
+        let cb = self.state.borrow_mut().on_change.take();
+        if let Some(mut cb) = cb {
+            cb(shortcut);
+            let mut state = self.state.borrow_mut();
+            if state.on_change.is_none() {
+                state.on_change = Some(cb);
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod hotkey {
+    use super::*;
+    #[test]
+    fn capture() {
+        let _win = crate::window::Window::default();
+        let mut hotkey = HotkeyInput::default();
+        hotkey.set_reserved(&[Shortcut::Ctrl + 'q']);
+        let seen = Rc::new(RefCell::new(vec![]));
+        let s = seen.clone();
+        hotkey.on_change(Box::new(move |sc| s.borrow_mut().push(sc)));
+        assert!(hotkey.capture(Key::ControlL, Shortcut::Ctrl));
+        assert!(hotkey.shortcut().is_none());
+        assert!(hotkey.capture(Key::from_char('k'), Shortcut::Ctrl | Shortcut::Shift));
+        assert!(hotkey.shortcut() == Some(Shortcut::Ctrl | Shortcut::Shift + 'k'));
+        assert!(hotkey.capture(Key::from_char('q'), Shortcut::Ctrl | Shortcut::CapsLock));
+        assert!(hotkey.shortcut() == Some(Shortcut::Ctrl | Shortcut::Shift + 'k'));
+        assert!(!hotkey.capture(Key::Tab, Shortcut::None));
+        assert!(hotkey.capture(Key::BackSpace, Shortcut::None));
+        assert!(hotkey.shortcut().is_none());
+        assert!(*seen.borrow() == vec![Some(Shortcut::Ctrl | Shortcut::Shift + 'k'), None]);
+    }
+}
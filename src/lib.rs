@@ -178,6 +178,10 @@
 //! - system-zlib: Uses the system zlib
 //! - legacy-opengl: Support of Lagacy OpenGL, the crate uses GLVND by default
 //! - fltk-bundled: Support for bundled versions of cfltk and fltk on selected platforms
+//! - regex: Enables regex search in TextBuffer
+//! - gilrs: Enables routing gamepad input into the event loop, see the gamepad module
+//! - system-events: Exposes the platform's native events through app::add_system_handler
+//! - examples-gallery: Builds the fltk-gallery binary, showing every widget along with its source
 //!
 //! ## FAQ
 //!
@@ -186,10 +190,13 @@
 pub mod app;
 pub mod browser;
 pub mod button;
+pub mod code_editor;
 pub mod dialog;
 pub mod draw;
 pub mod enums;
 pub mod frame;
+#[cfg(feature = "gilrs")]
+pub mod gamepad;
 pub mod gl;
 pub mod group;
 pub mod image;
@@ -197,13 +204,22 @@ pub mod input;
 pub mod menu;
 pub mod misc;
 pub mod output;
+pub mod popup;
 pub mod prelude;
 pub mod table;
 pub mod text;
 pub mod tree;
+#[doc(hidden)]
+pub mod ui_support;
 pub mod valuator;
 pub mod widget;
 pub mod window;
 
 #[macro_use]
 extern crate fltk_derive;
+
+// Lets the ::fltk paths generated by the macros resolve in the crate's tests
+#[cfg(test)]
+extern crate self as fltk;
+
+pub use fltk_derive::{menu, ui};
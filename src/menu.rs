@@ -2,9 +2,13 @@ use crate::image::Image;
 pub use crate::prelude::*;
 use fltk_sys::menu::*;
 use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
     ffi::{CStr, CString},
     mem,
     os::raw,
+    rc::Rc,
 };
 
 /// Creates a menu bar
@@ -19,32 +23,134 @@ pub struct MenuButton {
     _inner: *mut Fl_Menu_Button,
 }
 
+/// Defines the mouse buttons opening a MenuButton as a popup menu, which can be changed
+/// dynamically using the set_type function(). A popup MenuButton isn't drawn, it's
+/// placed over an area to act as its context menu
+#[repr(i32)]
+#[derive(WidgetType, Debug, Copy, Clone, PartialEq)]
+pub enum MenuButtonType {
+    Popup1 = 1,
+    Popup2 = 2,
+    Popup12 = 3,
+    Popup3 = 4,
+    Popup13 = 5,
+    Popup23 = 6,
+    Popup123 = 7,
+}
+
 /// Creates a menu choice
 #[derive(WidgetExt, MenuExt, Debug)]
 pub struct Choice {
     _inner: *mut Fl_Choice,
 }
 
+/// Creates a system menu bar, placed at the top of the screen on macOS.
+/// On other platforms it behaves like a MenuBar
+#[derive(WidgetExt, MenuExt, Debug)]
+pub struct SysMenuBar {
+    _inner: *mut Fl_Sys_Menu_Bar,
+}
+
 /// Creates a menu item
 #[derive(Debug, Clone)]
 pub struct MenuItem {
     _inner: *mut Fl_Menu_Item,
 }
 
-/// Defines the menu flag for any added menu items using the add() method
-#[repr(i32)]
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub enum MenuFlag {
-    Normal = 0,
-    Inactive = 1,
-    Toggle = 2,
-    Value = 4,
-    Radio = 8,
-    Invisible = 0x10,
-    SubmenuPointer = 0x20,
-    Submenu = 0x40,
-    MenuDivider = 0x80,
-    MenuHorizontal = 0x100,
+/// Defines the menu flag for any added menu items using the add() method,
+/// flags combine using the | operator
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MenuFlag(i32);
+
+#[allow(non_upper_case_globals)]
+impl MenuFlag {
+    pub const Normal: MenuFlag = MenuFlag(0);
+    pub const Inactive: MenuFlag = MenuFlag(1);
+    pub const Toggle: MenuFlag = MenuFlag(2);
+    pub const Value: MenuFlag = MenuFlag(4);
+    pub const Radio: MenuFlag = MenuFlag(8);
+    pub const Invisible: MenuFlag = MenuFlag(0x10);
+    pub const SubmenuPointer: MenuFlag = MenuFlag(0x20);
+    pub const Submenu: MenuFlag = MenuFlag(0x40);
+    pub const MenuDivider: MenuFlag = MenuFlag(0x80);
+    pub const MenuHorizontal: MenuFlag = MenuFlag(0x100);
+
+    /// Creates flags from their raw value
+    pub fn from_i32(val: i32) -> MenuFlag {
+        MenuFlag(val)
+    }
+
+    /// Returns the raw value of the flags
+    pub fn bits(self) -> i32 {
+        self.0
+    }
+
+    /// Returns whether all the flags of other are set
+    pub fn contains(self, other: MenuFlag) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+// The flags defining the structure of the menu
+const SUBMENU_FLAGS: i32 = MenuFlag::Submenu.0 | MenuFlag::SubmenuPointer.0;
+
+impl std::ops::BitOr<MenuFlag> for MenuFlag {
+    type Output = MenuFlag;
+    fn bitor(self, rhs: MenuFlag) -> Self::Output {
+        MenuFlag(self.0 | rhs.0)
+    }
+}
+
+impl MenuBar {
+    /// Creates a menu bar holding an item per (path, shortcut, flags, message) entry,
+    /// like a static array of Fl_Menu_Item, see MenuExt::add_table
+    pub fn from_table<T: 'static + Copy + Send + Sync>(
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+        sender: crate::app::Sender<T>,
+        table: &[(&str, Shortcut, MenuFlag, T)],
+    ) -> MenuBar {
+        let mut menu = MenuBar::new(x, y, w, h, "");
+        menu.add_table(sender, table);
+        menu
+    }
+}
+
+impl MenuButton {
+    /// Opens the menu at the mouse position and returns the chosen item,
+    /// after running its callback
+    pub fn popup(&mut self) -> Option<MenuItem> {
+        unsafe {
+            let item = Fl_Menu_Button_popup(self._inner);
+            if item.is_null() {
+                None
+            } else {
+                Some(MenuItem { _inner: item })
+            }
+        }
+    }
+}
+
+impl SysMenuBar {
+    /// Sets the callback of the About item of the application menu on macOS,
+    /// the application menu also holds the Quit item
+    pub fn set_about_callback(&mut self, cb: Box<dyn FnMut()>) {
+        unsafe {
+            unsafe extern "C" fn shim(_wid: *mut Fl_Widget, data: *mut raw::c_void) {
+                let a: *mut Box<dyn FnMut()> = mem::transmute(data);
+                let f: &mut (dyn FnMut()) = &mut **a;
+                f();
+            }
+            // The previous callback is dropped once replaced
+            crate::widget::register_data_deleter();
+            let a: *mut Box<dyn FnMut()> = Box::into_raw(Box::new(cb));
+            let data: *mut raw::c_void = mem::transmute(a);
+            Fl_Sys_Menu_Bar_about(self._inner, Some(shim), data);
+        }
+    }
 }
 
 impl MenuItem {
@@ -62,7 +168,8 @@ impl MenuItem {
         }
     }
     
-    /// Creates a popup menu at the specified coordinates and returns its choice
+    /// Creates a popup menu at the specified coordinates and returns its choice,
+    /// after running the choice's callback if it has one
     pub fn popup(&mut self, x: i32, y: i32) -> Option<MenuItem> {
         if self._inner.is_null() {
             return None;
@@ -72,9 +179,38 @@ impl MenuItem {
             if item.is_null() {
                 None
             } else {
-                let item = MenuItem {
+                let mut item = MenuItem {
+                    _inner: item as *mut Fl_Menu_Item,
+                };
+                item.do_callback();
+                Some(item)
+            }
+        }
+    }
+
+    /// Opens the menu as a pulldown within the given bounds, with picked highlighted
+    /// if it's an item of the menu. Returns the choice, after running its callback
+    pub fn pulldown(
+        &mut self,
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+        picked: Option<&MenuItem>,
+    ) -> Option<MenuItem> {
+        if self._inner.is_null() {
+            return None;
+        }
+        let picked = picked.map_or(std::ptr::null(), |item| item._inner as *const _);
+        unsafe {
+            let item = Fl_Menu_Item_pulldown(self._inner, x, y, w, h, picked);
+            if item.is_null() {
+                None
+            } else {
+                let mut item = MenuItem {
                     _inner: item as *mut Fl_Menu_Item,
                 };
+                item.do_callback();
                 Some(item)
             }
         }
@@ -101,7 +237,9 @@ impl MenuItem {
         assert!(!self._inner.is_null());
         unsafe {
             let txt = CString::new(txt).unwrap();
+            let old = Fl_Menu_Item_label(self._inner);
             Fl_Menu_Item_set_label(self._inner, txt.as_ptr());
+            rekey_item_drawer(old, self._inner);
         }
     }
 
@@ -172,6 +310,24 @@ impl MenuItem {
         unsafe { Fl_Menu_Item_set(self._inner) }
     }
 
+    /// Returns the item of the array starting at this item whose shortcut matches the
+    /// current event, without running its callback
+    pub fn test_shortcut(&self) -> Option<MenuItem> {
+        assert!(!self._inner.is_null());
+        let item = unsafe { Fl_Menu_Item_test_shortcut(self._inner) };
+        if item.is_null() {
+            None
+        } else {
+            Some(MenuItem { _inner: item })
+        }
+    }
+
+    /// Sets a radio menu item, clearing the other items of its radio group
+    pub fn setonly(&mut self) {
+        assert!(!self._inner.is_null());
+        unsafe { Fl_Menu_Item_setonly(self._inner) }
+    }
+
     /// Clears the menu item
     pub fn clear(&mut self) {
         assert!(!self._inner.is_null());
@@ -223,6 +379,815 @@ impl MenuItem {
         assert!(!self._inner.is_null());
         unsafe { Fl_Menu_Item_hide(self._inner) }
     }
+
+    /// Adds a copy of an image next to the label of the menu item, on its left or right.
+    /// Items with an image can't be found by their path using find_item
+    pub fn add_image<I: ImageExt>(&mut self, image: &I, on_left: bool) {
+        assert!(!self._inner.is_null());
+        unsafe { Fl_Menu_Item_add_image(self._inner, image.as_ptr(), on_left as i32) }
+    }
+
+    /// Removes the image of the menu item, keeping its label
+    pub fn remove_image(&mut self) {
+        assert!(!self._inner.is_null());
+        unsafe { Fl_Menu_Item_add_image(self._inner, std::ptr::null_mut(), 0) }
+    }
+
+    /// Draws the menu item as it would appear in the menu, only valid while drawing,
+    /// for example within a draw callback
+    pub fn draw<M: MenuExt>(&self, x: i32, y: i32, w: i32, h: i32, menu: &M, selected: bool) {
+        assert!(!self._inner.is_null());
+        unsafe {
+            Fl_Menu_Item_draw(
+                self._inner,
+                x,
+                y,
+                w,
+                h,
+                menu.as_widget_ptr() as *mut Fl_Widget,
+                selected as i32,
+            )
+        }
+    }
+
+    /// Returns the width and height the menu item takes in the menu,
+    /// only valid while drawing, for example within a draw callback
+    pub fn measure<M: MenuExt>(&self, menu: &M) -> (i32, i32) {
+        assert!(!self._inner.is_null());
+        let mut h = 0;
+        let w = unsafe {
+            Fl_Menu_Item_measure(self._inner, &mut h, menu.as_widget_ptr() as *mut Fl_Widget)
+        };
+        (w, h)
+    }
+
+    /// Overrides how the label of the menu item is drawn and measured.
+    /// The draw closure gets the label, the bounds to draw it within and the label color,
+    /// the measure closure returns the width and height of the label.
+    /// This sets a label type reserved for these items, reported as FreeLabelType,
+    /// and removes the image of the item
+    pub fn set_drawer(
+        &mut self,
+        draw: Box<dyn FnMut(&str, i32, i32, i32, i32, Color)>,
+        measure: Box<dyn FnMut(&str) -> (i32, i32)>,
+    ) {
+        assert!(!self._inner.is_null());
+        if self.has_image() {
+            self.remove_image();
+        }
+        // Setting the label again gives the item its own copy, used as the key of the drawer
+        let label = self.label().unwrap_or_default();
+        self.set_label(&label);
+        unsafe {
+            Fl_Menu_Item_set_label_drawer(
+                self._inner,
+                Some(item_label_draw),
+                Some(item_label_measure),
+            );
+            let key = Fl_Menu_Item_label(self._inner) as usize;
+            ITEM_DRAWERS.with(|d| d.borrow_mut().insert(key, ItemDrawer { draw, measure }));
+        }
+    }
+
+    /// Returns whether the menu item has an image
+    pub fn has_image(&self) -> bool {
+        assert!(!self._inner.is_null());
+        unsafe {
+            match Fl_Menu_Item_has_image(self._inner) {
+                0 => false,
+                _ => true,
+            }
+        }
+    }
+
+    /// Returns the shortcut of the menu item, displayed next to its label
+    pub fn shortcut(&self) -> Shortcut {
+        assert!(!self._inner.is_null());
+        unsafe { Shortcut::from_i32(Fl_Menu_Item_shortcut(self._inner)) }
+    }
+
+    /// Sets the shortcut of the menu item
+    pub fn set_shortcut(&mut self, shortcut: Shortcut) {
+        assert!(!self._inner.is_null());
+        unsafe { Fl_Menu_Item_set_shortcut(self._inner, shortcut.bits()) }
+    }
+
+    /// Returns whether the menu item is a submenu
+    pub fn is_submenu(&self) -> bool {
+        assert!(!self._inner.is_null());
+        unsafe {
+            match Fl_Menu_Item_submenu(self._inner) {
+                0 => false,
+                _ => true,
+            }
+        }
+    }
+
+    /// Returns the flags of the menu item
+    pub fn flags(&self) -> MenuFlag {
+        assert!(!self._inner.is_null());
+        unsafe { MenuFlag::from_i32(Fl_Menu_Item_flags(self._inner)) }
+    }
+
+    /// Sets a flag of the menu item, like MenuFlag::Toggle or MenuFlag::MenuDivider.
+    /// Submenu flags can't be changed after an item is added and are ignored
+    pub fn set_flag(&mut self, flag: MenuFlag) {
+        assert!(!self._inner.is_null());
+        unsafe {
+            let flags = Fl_Menu_Item_flags(self._inner);
+            Fl_Menu_Item_set_flags(self._inner, flags | (flag.bits() & !SUBMENU_FLAGS));
+        }
+    }
+
+    /// Clears a flag of the menu item, submenu flags are ignored
+    pub fn clear_flag(&mut self, flag: MenuFlag) {
+        assert!(!self._inner.is_null());
+        unsafe {
+            let flags = Fl_Menu_Item_flags(self._inner);
+            Fl_Menu_Item_set_flags(self._inner, flags & !(flag.bits() & !SUBMENU_FLAGS));
+        }
+    }
+
+    /// Returns whether the menu item is a radio button
+    pub fn is_radio(&self) -> bool {
+        self.flags().contains(MenuFlag::Radio)
+    }
+
+    /// Returns whether the menu item is a checkbox, radio buttons are checkboxes too
+    pub fn is_toggle(&self) -> bool {
+        self.flags().bits() & (MenuFlag::Toggle | MenuFlag::Radio).bits() != 0
+    }
+
+    /// Returns whether the menu item is followed by a divider
+    pub fn has_divider(&self) -> bool {
+        self.flags().contains(MenuFlag::MenuDivider)
+    }
+
+    /// Returns an iterator over the items of the submenu, None if the item isn't a submenu
+    pub fn submenu(&self) -> Option<MenuItemIterator> {
+        if !self.is_submenu() {
+            return None;
+        }
+        let first = MenuItem {
+            _inner: unsafe { Fl_Menu_Item_first_child(self._inner) },
+        };
+        let current = if first._inner.is_null() || first.label().is_none() {
+            None
+        } else {
+            Some(first)
+        };
+        Some(MenuItemIterator { current })
+    }
+
+    /// Returns the direct children of a submenu, submenus' own items aren't included
+    pub fn children(&self) -> Vec<MenuItem> {
+        self.submenu().map_or(vec![], |items| items.collect())
+    }
+
+    /// Returns the number of items of the array starting at this item, counting the
+    /// terminators ending the array and each submenu
+    pub fn size(&self) -> u32 {
+        assert!(!self._inner.is_null());
+        unsafe { Fl_Menu_Item_size(self._inner) as u32 }
+    }
+
+    /// Returns the item at idx in the array starting at this item, valid indices are less
+    /// than size() - 1
+    pub fn at(&self, idx: u32) -> Option<MenuItem> {
+        assert!(!self._inner.is_null());
+        if idx + 1 >= self.size() {
+            return None;
+        }
+        unsafe {
+            Some(MenuItem {
+                _inner: Fl_Menu_Item_at(self._inner, idx as i32),
+            })
+        }
+    }
+
+    /// Returns the items of the array starting at this item, including submenus and
+    /// their items but not the terminators ending them
+    pub fn items(&self) -> Vec<MenuItem> {
+        (0..self.size())
+            .filter_map(|idx| self.at(idx))
+            .filter(|item| item.label().is_some())
+            .collect()
+    }
+
+    /// Returns the item idx places after this one at the same level, skipping submenus' items.
+    /// Useful to reach the items of a popup menu created using MenuItem::new
+    pub fn next(&self, idx: u32) -> Option<MenuItem> {
+        assert!(!self._inner.is_null());
+        debug_assert!(
+            idx <= std::i32::MAX as u32,
+            "u32 entries have to be < std::i32::MAX for compatibility!"
+        );
+        let item = MenuItem {
+            _inner: unsafe { Fl_Menu_Item_next(self._inner, idx as i32) },
+        };
+        if item._inner.is_null() || item.label().is_none() {
+            None
+        } else {
+            Some(item)
+        }
+    }
+
+    /// Sets the callback of the menu item, called with the item when it's chosen
+    /// from a menu widget or a popup menu
+    pub fn set_callback(&mut self, cb: Box<dyn FnMut(&mut MenuItem)>) {
+        assert!(!self._inner.is_null());
+        unsafe {
+            match item_data(self._inner) {
+                Some(data) => (*data).cb = ItemCallback::Item(cb),
+                None => {
+                    let data = Box::into_raw(Box::new(ItemData {
+                        cb: ItemCallback::Item(cb),
+                        user_data: None,
+                    }));
+                    Fl_Menu_Item_set_callback(self._inner, Some(menu_item_shim), data as _);
+                }
+            }
+        }
+    }
+
+    /// Returns whether the menu item has a callback set using set_callback or MenuExt::add
+    pub fn has_callback(&self) -> bool {
+        assert!(!self._inner.is_null());
+        unsafe {
+            match item_data(self._inner) {
+                Some(data) => match (*data).cb {
+                    ItemCallback::None => false,
+                    _ => true,
+                },
+                None => Fl_Menu_Item_callback(self._inner).is_some(),
+            }
+        }
+    }
+
+    /// Returns a closure running the callback of the menu item, if it was set using
+    /// set_callback or MenuExt::add. The closure shouldn't be called once the item is removed
+    pub fn callback(&self) -> Option<Box<dyn FnMut()>> {
+        assert!(!self._inner.is_null());
+        if let ItemCallback::None = unsafe { &(*item_data(self._inner)?).cb } {
+            return None;
+        }
+        let mut item = self.clone();
+        Some(Box::new(move || item.do_callback()))
+    }
+
+    /// Runs the callback of the menu item, if it was set using set_callback or MenuExt::add
+    pub fn do_callback(&mut self) {
+        assert!(!self._inner.is_null());
+        unsafe {
+            if let Some(data) = item_data(self._inner) {
+                match &mut (*data).cb {
+                    ItemCallback::None => (),
+                    ItemCallback::Plain(f) => f(),
+                    ItemCallback::Item(f) => f(self),
+                }
+            }
+        }
+    }
+
+    /// Runs the callback of the menu item as FLTK would, passing it the widget.
+    /// Unlike do_callback, this also runs callbacks set outside of the crate,
+    /// and items without a closure run the widget's callback
+    pub fn do_callback_with<W: WidgetExt>(&mut self, widget: &W) {
+        assert!(!self._inner.is_null());
+        unsafe { Fl_Menu_Item_do_callback(self._inner, widget.as_widget_ptr() as *mut Fl_Widget) }
+    }
+
+    /// Attaches data to the menu item, like an id, replacing any previous data.
+    /// It's dropped along with the item's callback, when the item is removed or replaced.
+    /// Items whose callback wasn't set by the crate can't carry data
+    pub fn set_user_data<T: 'static>(&mut self, data: T) {
+        assert!(!self._inner.is_null());
+        unsafe {
+            if let Some(item_data) = item_data(self._inner) {
+                (*item_data).user_data = Some(Box::new(data));
+            } else if Fl_Menu_Item_callback(self._inner).is_none() {
+                // The callback runs the menu's own callback, like items without one
+                let item_data = Box::into_raw(Box::new(ItemData {
+                    cb: ItemCallback::None,
+                    user_data: Some(Box::new(data)),
+                }));
+                Fl_Menu_Item_set_callback(self._inner, Some(menu_item_shim), item_data as _);
+            }
+        }
+    }
+
+    /// Returns a copy of the data attached to the menu item, None if it has no data
+    /// or its data is of another type
+    pub fn user_data<T: 'static + Clone>(&self) -> Option<T> {
+        assert!(!self._inner.is_null());
+        unsafe {
+            let data = item_data(self._inner)?;
+            (*data).user_data.as_ref()?.downcast_ref::<T>().cloned()
+        }
+    }
+}
+
+/// What the crate stores as the user data of a menu item, its closure and the data
+/// attached by MenuItem::set_user_data
+struct ItemData {
+    cb: ItemCallback,
+    user_data: Option<Box<dyn Any>>,
+}
+
+enum ItemCallback {
+    None,
+    Plain(Box<dyn FnMut()>),
+    Item(Box<dyn FnMut(&mut MenuItem)>),
+}
+
+/// Boxes the closure of a menu item added using MenuExt::add or MenuExt::insert
+pub(crate) fn new_item_data(cb: Box<dyn FnMut()>) -> *mut raw::c_void {
+    let data = ItemData {
+        cb: ItemCallback::Plain(cb),
+        user_data: None,
+    };
+    Box::into_raw(Box::new(data)) as *mut raw::c_void
+}
+
+/// Returns the data of a menu item whose callback was set by the crate
+unsafe fn item_data(item: *mut Fl_Menu_Item) -> Option<*mut ItemData> {
+    let cb = Fl_Menu_Item_callback(item).map(|f| f as usize);
+    let data = Fl_Menu_Item_user_data(item);
+    if cb == Some(menu_item_shim as usize) && !data.is_null() {
+        Some(data as *mut ItemData)
+    } else {
+        None
+    }
+}
+
+/// Calls the closure of a menu item, menu widgets pass themselves, the chosen item being
+/// their mvalue. Items without a closure run the menu's callback
+pub(crate) unsafe extern "C" fn menu_item_shim(wid: *mut Fl_Widget, data: *mut raw::c_void) {
+    let data = data as *mut ItemData;
+    match &mut (*data).cb {
+        ItemCallback::None => {
+            if !wid.is_null() {
+                fltk_sys::widget::Fl_Widget_do_callback(wid as *mut fltk_sys::widget::Fl_Widget);
+            }
+        }
+        ItemCallback::Plain(f) => f(),
+        ItemCallback::Item(f) => {
+            let item = Fl_Menu_Item_from_menu(wid);
+            if !item.is_null() {
+                f(&mut MenuItem { _inner: item });
+            }
+        }
+    }
+}
+
+/// Iterates over the items of a submenu at the same level, created by MenuItem::submenu
+#[derive(Debug)]
+pub struct MenuItemIterator {
+    current: Option<MenuItem>,
+}
+
+impl Iterator for MenuItemIterator {
+    type Item = MenuItem;
+    fn next(&mut self) -> Option<MenuItem> {
+        let item = self.current.take()?;
+        self.current = item.next(1);
+        Some(item)
+    }
+}
+
+/// Drops the closure and data owned by a menu item, items whose callback wasn't set by
+/// the crate are left untouched
+pub(crate) unsafe fn free_item_callback(item: *mut Fl_Menu_Item) {
+    if let Some(data) = item_data(item) {
+        Fl_Menu_Item_set_callback(item, None, std::ptr::null_mut());
+        let _ = Box::from_raw(data);
+    }
+}
+
+/// Drops the closures and drawers owned by the menu's items in the range and frees
+/// their images, before they're cleared
+pub(crate) unsafe fn free_menu_items<M: MenuExt>(menu: &M, start: u32, end: u32) {
+    for idx in start..end {
+        if let Some(item) = menu.at(idx) {
+            free_item_callback(item._inner);
+            free_item_drawer(item._inner);
+            Fl_Menu_Item_release_label(item._inner);
+        }
+    }
+}
+
+/// Frees an array of count items created by MenuItem::new, along with the closures
+/// and data of the items and their labels, which mustn't have been changed
+pub(crate) unsafe fn delete_menu_items(menu: MenuItem, count: u32) {
+    for idx in 0..count {
+        if let Some(item) = menu.next(idx) {
+            free_item_callback(item._inner);
+            free_item_drawer(item._inner);
+            let label = Fl_Menu_Item_label(item._inner);
+            if !label.is_null() {
+                let _ = CString::from_raw(label as *mut raw::c_char);
+            }
+        }
+    }
+    Fl_Menu_Item_delete(menu._inner);
+}
+
+thread_local! {
+    static OWNING_MENUS: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+}
+
+/// Drops the closures and images owned by the menu's items when the menu widget is deleted
+pub(crate) fn free_items_on_delete<M: MenuExt + 'static>(menu: &M) {
+    let ptr = unsafe { menu.as_widget_ptr() };
+    if !OWNING_MENUS.with(|m| m.borrow_mut().insert(ptr as usize)) {
+        return;
+    }
+    crate::widget::on_delete(
+        ptr,
+        Box::new(move || {
+            OWNING_MENUS.with(|m| m.borrow_mut().remove(&(ptr as usize)));
+            unsafe {
+                let menu = M::from_widget_ptr(ptr);
+                free_menu_items(&menu, 0, menu.size());
+            }
+        }),
+    );
+}
+
+/// Escapes a label so it's added as a single item by MenuExt::add and add_choices,
+/// like a file name in a recent files menu. '/', '\\', '_' and tabs are escaped by
+/// a backslash, '&' by doubling it
+pub fn escape_label(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for c in label.chars() {
+        match c {
+            '/' | '\\' | '_' | '\t' => escaped.push('\\'),
+            '&' => escaped.push('&'),
+            _ => (),
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Splits an accelerator following a tab off an item's name, like "File/Save\tCtrl+S".
+/// The accelerator is used when no shortcut was passed, names whose accelerator can't be
+/// parsed are kept as is
+pub(crate) fn split_accelerator(name: &str, shortcut: Shortcut) -> (&str, Shortcut) {
+    let pos = match name.rfind('\t') {
+        Some(pos) => pos,
+        None => return (name, shortcut),
+    };
+    // A tab escaped by escape_label is part of the label
+    if name[..pos].chars().rev().take_while(|c| *c == '\\').count() % 2 == 1 {
+        return (name, shortcut);
+    }
+    if shortcut != Shortcut::None {
+        return (&name[..pos], shortcut);
+    }
+    match Shortcut::parse(&name[pos + 1..]) {
+        Some(parsed) => (&name[..pos], parsed),
+        None => (name, shortcut),
+    }
+}
+
+/// Returns the index of the terminator ending the submenu at idx
+pub(crate) fn submenu_end<M: MenuExt>(menu: &M, idx: u32) -> Option<u32> {
+    if !menu.at(idx)?.is_submenu() {
+        return None;
+    }
+    let mut depth = 0;
+    for i in idx + 1..menu.size() {
+        let item = menu.at(i)?;
+        if item.label().is_none() {
+            if depth == 0 {
+                return Some(i);
+            }
+            depth -= 1;
+        } else if item.is_submenu() {
+            depth += 1;
+        }
+    }
+    None
+}
+
+struct ShortcutMenu {
+    widget: usize,
+    tracker: *mut raw::c_void,
+    tester: Box<dyn Fn() -> Option<MenuItem>>,
+}
+
+struct GlobalShortcuts {
+    installed: bool,
+    menus: Vec<ShortcutMenu>,
+}
+
+thread_local! {
+    static GLOBAL_SHORTCUTS: RefCell<GlobalShortcuts> = RefCell::new(GlobalShortcuts {
+        installed: false,
+        menus: vec![],
+    });
+}
+
+/// Drops the menus which were deleted, a new menu can reuse their address
+fn retain_live_menus(menus: &mut Vec<ShortcutMenu>) {
+    menus.retain(|menu| unsafe {
+        if fltk_sys::fl::Fl_Widget_Tracker_deleted(menu.tracker) != 0 {
+            fltk_sys::fl::Fl_Widget_Tracker_delete(menu.tracker);
+            false
+        } else {
+            true
+        }
+    });
+}
+
+/// Makes the shortcuts of a menu work in every window of the application, even when the
+/// focused widget is outside the menu's window. The matched item's callback is run.
+/// Adding a menu again has no effect
+pub fn add_global_shortcuts<M: MenuExt + 'static>(menu: &M) {
+    let ptr = unsafe { menu.as_widget_ptr() };
+    GLOBAL_SHORTCUTS.with(|shortcuts| {
+        let mut shortcuts = shortcuts.borrow_mut();
+        if !shortcuts.installed {
+            unsafe { fltk_sys::fl::Fl_add_handler(Some(global_shortcut_handler)) }
+            shortcuts.installed = true;
+        }
+        retain_live_menus(&mut shortcuts.menus);
+        if shortcuts.menus.iter().any(|m| m.widget == ptr as usize) {
+            return;
+        }
+        let tester = Box::new(move || {
+            let mut menu = unsafe { M::from_widget_ptr(ptr) };
+            menu.test_shortcut()
+        });
+        let tracker =
+            unsafe { fltk_sys::fl::Fl_Widget_Tracker_new(ptr as *mut fltk_sys::fl::Fl_Widget) };
+        shortcuts.menus.push(ShortcutMenu {
+            widget: ptr as usize,
+            tracker,
+            tester,
+        })
+    });
+}
+
+/// Offers unhandled shortcuts to the menus added using add_global_shortcuts
+unsafe extern "C" fn global_shortcut_handler(ev: raw::c_int) -> raw::c_int {
+    if ev != Event::Shortcut as i32 {
+        return 0;
+    }
+    // The menus are taken out since callbacks might add menus
+    let mut menus = GLOBAL_SHORTCUTS.with(|s| mem::take(&mut s.borrow_mut().menus));
+    retain_live_menus(&mut menus);
+    let handled = menus.iter().any(|menu| {
+        fltk_sys::fl::Fl_Widget_Tracker_deleted(menu.tracker) == 0 && (menu.tester)().is_some()
+    });
+    GLOBAL_SHORTCUTS.with(|shortcuts| {
+        let mut shortcuts = shortcuts.borrow_mut();
+        // Menus added by the callbacks which were already there are dropped
+        for menu in mem::take(&mut shortcuts.menus) {
+            if menus.iter().any(|m| m.widget == menu.widget) {
+                fltk_sys::fl::Fl_Widget_Tracker_delete(menu.tracker);
+            } else {
+                menus.push(menu);
+            }
+        }
+        shortcuts.menus = menus;
+    });
+    handled as raw::c_int
+}
+
+struct ItemDrawer {
+    draw: Box<dyn FnMut(&str, i32, i32, i32, i32, Color)>,
+    measure: Box<dyn FnMut(&str) -> (i32, i32)>,
+}
+
+thread_local! {
+    // Keyed by the address of the item's label, which stays put when the menu grows
+    static ITEM_DRAWERS: RefCell<HashMap<usize, ItemDrawer>> = RefCell::new(HashMap::new());
+}
+
+/// Moves the drawer of an item whose label was replaced to its new label
+pub(crate) unsafe fn rekey_item_drawer(old: *const raw::c_char, item: *mut Fl_Menu_Item) {
+    let new = Fl_Menu_Item_label(item);
+    if old.is_null() || old == new {
+        return;
+    }
+    ITEM_DRAWERS.with(|d| {
+        let mut d = d.borrow_mut();
+        if let Some(drawer) = d.remove(&(old as usize)) {
+            if !new.is_null() {
+                d.insert(new as usize, drawer);
+            }
+        }
+    });
+}
+
+/// Drops the drawer of an item which is being removed
+unsafe fn free_item_drawer(item: *mut Fl_Menu_Item) {
+    let label = Fl_Menu_Item_label(item);
+    if !label.is_null() {
+        ITEM_DRAWERS.with(|d| d.borrow_mut().remove(&(label as usize)));
+    }
+}
+
+/// Runs f with the drawer of the label, which is taken out since it might draw other items
+fn with_item_drawer<F: FnOnce(&str, &mut ItemDrawer)>(label: *const raw::c_char, f: F) -> bool {
+    if label.is_null() {
+        return false;
+    }
+    let key = label as usize;
+    let mut drawer = match ITEM_DRAWERS.with(|d| d.borrow_mut().remove(&key)) {
+        Some(drawer) => drawer,
+        None => return false,
+    };
+    let txt = unsafe { CStr::from_ptr(label).to_string_lossy().to_string() };
+    f(&txt, &mut drawer);
+    ITEM_DRAWERS.with(|d| d.borrow_mut().entry(key).or_insert(drawer));
+    true
+}
+
+unsafe extern "C" fn item_label_draw(
+    label: *const raw::c_char,
+    x: raw::c_int,
+    y: raw::c_int,
+    w: raw::c_int,
+    h: raw::c_int,
+    color: raw::c_uint,
+) -> raw::c_int {
+    with_item_drawer(label, |txt, drawer| {
+        (drawer.draw)(txt, x, y, w, h, mem::transmute(color))
+    }) as raw::c_int
+}
+
+unsafe extern "C" fn item_label_measure(
+    label: *const raw::c_char,
+    w: *mut raw::c_int,
+    h: *mut raw::c_int,
+) -> raw::c_int {
+    with_item_drawer(label, |txt, drawer| {
+        let (width, height) = (drawer.measure)(txt);
+        *w = width;
+        *h = height;
+    }) as raw::c_int
+}
+
+/// A menu item matching a search query
+#[derive(Clone)]
+pub struct MenuMatch {
+    /// The full path of the item, like "File/Open"
+    pub path: String,
+    /// The matching item
+    pub item: MenuItem,
+    picker: Rc<dyn Fn(&MenuItem)>,
+}
+
+impl MenuMatch {
+    /// Picks the matching item in its menu, running its callback
+    pub fn trigger(&self) {
+        (self.picker)(&self.item)
+    }
+}
+
+struct IndexedMenu {
+    tracker: *mut raw::c_void,
+    items: Box<dyn Fn() -> Vec<(String, MenuItem)>>,
+    picker: Rc<dyn Fn(&MenuItem)>,
+}
+
+thread_local! {
+    static SEARCH_INDEX: RefCell<Vec<IndexedMenu>> = RefCell::new(vec![]);
+}
+
+/// Adds a menu to the index used by search(). The items are read at search time,
+/// so later changes to the menu are found, and deleted menus are dropped from the index
+pub fn add_to_search_index<M: MenuExt + 'static>(menu: &M) {
+    let ptr = unsafe { menu.as_widget_ptr() };
+    let items = Box::new(move || {
+        let menu = unsafe { M::from_widget_ptr(ptr) };
+        let mut items = vec![];
+        for idx in 0..menu.size().saturating_sub(1) {
+            let mut item = match menu.at(idx) {
+                Some(item) => item,
+                None => continue,
+            };
+            if item.label().is_none() || item.is_submenu() || !item.visible() || !item.active() {
+                continue;
+            }
+            if let Some(path) = menu.item_pathname(&item) {
+                items.push((path.replace('&', ""), item));
+            }
+        }
+        items
+    });
+    let picker = Rc::new(move |item: &MenuItem| {
+        let mut menu = unsafe { M::from_widget_ptr(ptr) };
+        menu.pick(item);
+    });
+    let tracker =
+        unsafe { fltk_sys::fl::Fl_Widget_Tracker_new(ptr as *mut fltk_sys::fl::Fl_Widget) };
+    SEARCH_INDEX.with(|index| {
+        index.borrow_mut().push(IndexedMenu {
+            tracker,
+            items,
+            picker,
+        })
+    });
+}
+
+/// Searches the indexed menus for items whose path contains every word of the query,
+/// ignoring case. Items whose own label starts with the query come first
+pub fn search(query: &str) -> Vec<MenuMatch> {
+    let terms: Vec<String> = query.split_whitespace().map(|t| t.to_lowercase()).collect();
+    if terms.is_empty() {
+        return vec![];
+    }
+    let mut found = vec![];
+    SEARCH_INDEX.with(|index| {
+        let mut index = index.borrow_mut();
+        index.retain(|menu| unsafe {
+            if fltk_sys::fl::Fl_Widget_Tracker_deleted(menu.tracker) != 0 {
+                fltk_sys::fl::Fl_Widget_Tracker_delete(menu.tracker);
+                false
+            } else {
+                true
+            }
+        });
+        for menu in index.iter() {
+            for (path, item) in (menu.items)() {
+                let lower = path.to_lowercase();
+                if !terms.iter().all(|t| lower.contains(t.as_str())) {
+                    continue;
+                }
+                let label = lower.rsplit('/').next().unwrap_or("");
+                let score = if label.starts_with(terms[0].as_str()) {
+                    0
+                } else if terms.iter().all(|t| label.contains(t.as_str())) {
+                    1
+                } else {
+                    2
+                };
+                found.push((
+                    score,
+                    MenuMatch {
+                        path,
+                        item,
+                        picker: menu.picker.clone(),
+                    },
+                ));
+            }
+        }
+    });
+    found.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.path.cmp(&b.1.path)));
+    found.into_iter().map(|(_, m)| m).collect()
+}
+
+/// A search field over the menus added with add_to_search_index. Pressing Enter pops up
+/// the matching items below the field, choosing one triggers it
+#[derive(Debug)]
+pub struct MenuSearch {
+    input: crate::input::Input,
+}
+
+impl MenuSearch {
+    /// The maximum number of matches shown in the popup
+    const MAX_MATCHES: usize = 20;
+
+    /// Creates a new MenuSearch widget
+    pub fn new(x: i32, y: i32, w: i32, h: i32, label: &str) -> MenuSearch {
+        let mut input = crate::input::Input::new(x, y, w, h, label);
+        input.set_trigger(CallbackTrigger::EnterKeyAlways);
+        let inp = unsafe { crate::input::Input::from_widget_ptr(input.as_widget_ptr()) };
+        input.set_callback(Box::new(move || {
+            let mut matches = search(&inp.value());
+            if matches.is_empty() {
+                return;
+            }
+            matches.truncate(MenuSearch::MAX_MATCHES);
+            let paths: Vec<&str> = matches.iter().map(|m| m.path.as_str()).collect();
+            let count = paths.len() as u32;
+            let mut popup = MenuItem::new(paths);
+            let label = popup
+                .popup(inp.x(), inp.y() + inp.height())
+                .and_then(|choice| choice.label());
+            unsafe { delete_menu_items(popup, count) }
+            if let Some(label) = label {
+                if let Some(m) = matches.iter().find(|m| m.path == label) {
+                    m.trigger();
+                }
+            }
+        }));
+        MenuSearch { input }
+    }
+
+    /// Creates a default and zero initialized MenuSearch
+    pub fn default() -> MenuSearch {
+        MenuSearch::new(0, 0, 0, 0, "")
+    }
+
+    /// Returns the underlying input, useful for positioning and styling
+    pub fn input(&self) -> crate::input::Input {
+        unsafe { crate::input::Input::from_widget_ptr(self.input.as_widget_ptr()) }
+    }
 }
 
 unsafe impl Send for MenuItem {}
@@ -243,4 +1208,498 @@ mod menu {
         menu.set_tooltip("tooltip");
         assert!(menu.tooltip().unwrap() == "tooltip");
     }
+    #[test]
+    fn at() {
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add_choice("first|second");
+        assert!(menu.size() == 3);
+        assert!(menu.at(1).is_some());
+        assert!(menu.at(2).is_none());
+    }
+    #[test]
+    fn search() {
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add_choice("File/Open|File/Save|Edit/Copy");
+        add_to_search_index(&menu);
+        let found = super::search("op");
+        assert!(found.len() == 2);
+        assert!(found[0].path == "File/Open");
+        assert!(found[1].path == "Edit/Copy");
+    }
+    #[test]
+    fn closures() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        let rc = Rc::new(());
+        let cb = |rc: &Rc<()>| -> Box<dyn FnMut()> {
+            let r = rc.clone();
+            Box::new(move || {
+                let _ = &r;
+            })
+        };
+        menu.add("File/Open", Shortcut::None, MenuFlag::Normal, cb(&rc));
+        menu.add("File/Recent/First", Shortcut::None, MenuFlag::Normal, cb(&rc));
+        menu.add("Edit/Copy", Shortcut::None, MenuFlag::Normal, cb(&rc));
+        assert!(Rc::strong_count(&rc) == 4);
+        // Replacing an item drops its previous closure
+        menu.add("File/Open", Shortcut::None, MenuFlag::Normal, cb(&rc));
+        assert!(Rc::strong_count(&rc) == 4);
+        assert!(menu.clear_submenu(0).is_ok());
+        assert!(Rc::strong_count(&rc) == 2);
+        menu.clear();
+        assert!(Rc::strong_count(&rc) == 1);
+        // Deleting the menu drops the closures of its items
+        let mut win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        win.end();
+        menu.add("File/Open", Shortcut::None, MenuFlag::Normal, cb(&rc));
+        menu.add("Edit/Copy", Shortcut::None, MenuFlag::Normal, cb(&rc));
+        assert!(Rc::strong_count(&rc) == 3);
+        win.clear();
+        assert!(Rc::strong_count(&rc) == 1);
+    }
+    #[test]
+    fn find_item() {
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add_choice("File/Open|File/Recent/Clear|Edit/Copy");
+        assert!(menu.find_item("File/Recent/Missing").is_none());
+        let mut item = menu.find_item("File/Recent/Clear").unwrap();
+        item.set_label(&String::from("Clear all"));
+        item.deactivate();
+        assert!(!item.active());
+        let item = menu.find_item("File/Recent/Clear all").unwrap();
+        assert!(item.label().unwrap() == "Clear all");
+        assert!(menu.item_pathname(&item).unwrap() == "File/Recent/Clear all");
+    }
+    #[test]
+    fn items() {
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add_choice("File/Open|File/Recent/Clear|Edit/Copy");
+        let labels: Vec<String> = menu.items().iter().filter_map(|i| i.label()).collect();
+        assert!(labels == vec!["File", "Open", "Recent", "Clear", "Edit", "Copy"]);
+        let popup = MenuItem::new(vec!["first", "second"]);
+        assert!(popup.size() == 3);
+        assert!(popup.at(1).unwrap().label().unwrap() == "second");
+        assert!(popup.at(2).is_none());
+        assert!(popup.items().len() == 2);
+    }
+    #[test]
+    fn accelerators() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add("File/Save\tCtrl+S", Shortcut::None, MenuFlag::Normal, Box::new(|| ()));
+        menu.add("File/Quit\tCtrl+Q", Shortcut::Alt + 'x', MenuFlag::Normal, Box::new(|| ()));
+        menu.add("File/Tab\tNot a key", Shortcut::None, MenuFlag::Normal, Box::new(|| ()));
+        let save = menu.find_item("File/Save").unwrap();
+        assert!(save.shortcut() == Shortcut::Ctrl + 's');
+        assert!(menu.find_item("File/Quit").unwrap().shortcut() == Shortcut::Alt + 'x');
+        assert!(menu.find_item("File/Tab\tNot a key").is_some());
+        let mut item = menu.find_item("File/Quit").unwrap();
+        item.set_shortcut(Shortcut::Ctrl | Shortcut::Shift + Key::Delete);
+        assert!(item.shortcut() == Shortcut::Ctrl | Shortcut::Shift + Key::Delete);
+        let f5 = Key::fn_key(5);
+        assert!(Shortcut::parse("ctrl+shift+f5") == Some(Shortcut::Ctrl | Shortcut::Shift + f5));
+        assert!(format!("{:?}", f5) == "F5");
+        assert!(Shortcut::parse("F36").is_none());
+        assert!(Shortcut::parse("Ctrl++") == Some(Shortcut::Ctrl + '+'));
+        assert!(Shortcut::parse("Hyper+A").is_none());
+        let shortcut = Shortcut::Ctrl | Shortcut::Shift + 'k';
+        assert!(shortcut.contains(Shortcut::Ctrl) && !shortcut.contains(Shortcut::Alt));
+        assert!(Shortcut::from_i32(shortcut.bits()) == shortcut);
+    }
+    #[test]
+    fn submenus() {
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add_choice("File/Open|File/Recent/First|File/Recent/Second|File/Quit|Edit/Copy");
+        let labels = |items: Vec<MenuItem>| -> Vec<String> {
+            items.iter().filter_map(|i| i.label()).collect()
+        };
+        let file = menu.find_item("File").unwrap();
+        assert!(labels(file.children()) == vec!["Open", "Recent", "Quit"]);
+        let recent = file.submenu().unwrap().nth(1).unwrap();
+        assert!(labels(recent.children()) == vec!["First", "Second"]);
+        assert!(recent.next(1).unwrap().label().unwrap() == "Quit");
+        let open = menu.find_item("File/Open").unwrap();
+        assert!(open.submenu().is_none());
+        assert!(open.children().is_empty());
+    }
+    #[test]
+    fn images() {
+        let image = crate::image::RgbImage::new(&vec![0u8; 16 * 16 * 3], 16, 16, 3).unwrap();
+        let mut items = MenuItem::new(vec!["Open", "Save"]);
+        assert!(!items.has_image());
+        items.add_image(&image, true);
+        assert!(items.has_image());
+        assert!(items.label().unwrap() == "Open");
+        items.set_label("Open...");
+        assert!(items.label().unwrap() == "Open...");
+        let mut save = items.next(1).unwrap();
+        save.add_image(&image, false);
+        drop(image);
+        assert!(save.label().unwrap() == "Save");
+        // Replacing the image keeps the label
+        let image = crate::image::RgbImage::new(&vec![0u8; 8 * 8 * 3], 8, 8, 3).unwrap();
+        items.add_image(&image, false);
+        assert!(items.label().unwrap() == "Open...");
+        items.set_label("Open");
+        items.remove_image();
+        assert!(!items.has_image());
+        assert!(items.label().unwrap() == "Open");
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add_choice("File/Open|File/Save");
+        let idx = menu.find_index("File/Save").unwrap();
+        let save = menu.find_item("File/Save").unwrap();
+        assert!(menu.add_image("File/Save", &image, true).is_ok());
+        assert!(save.has_image());
+        assert!(menu.add_image("File/Missing", &image, true).is_err());
+        // Replacing the label drops the image
+        assert!(menu.replace(idx, "Save as").is_ok());
+        assert!(!menu.find_item("File/Save as").unwrap().has_image());
+        menu.clear();
+    }
+    #[test]
+    fn sys_menu_bar() {
+        let _win = crate::window::Window::default();
+        let mut menu = SysMenuBar::new(0, 0, 0, 0, "");
+        menu.add("File/Open\tCtrl+O", Shortcut::None, MenuFlag::Normal, Box::new(|| ()));
+        let rc = Rc::new(());
+        let r = rc.clone();
+        menu.set_about_callback(Box::new(move || {
+            let _ = &r;
+        }));
+        assert!(menu.find_item("File/Open").unwrap().shortcut() == Shortcut::Ctrl + 'o');
+        // Replacing the About callback drops the previous one
+        menu.set_about_callback(Box::new(|| ()));
+        unsafe { fltk_sys::widget::Fl_Widget_free_pending_data() }
+        assert!(Rc::strong_count(&rc) == 1);
+    }
+    #[test]
+    fn add_emit() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        let (s, _r) = crate::app::channel::<i32>();
+        menu.add_emit("File/Open\tCtrl+O", Shortcut::None, MenuFlag::Normal, s, 1);
+        let open = menu.find_item("File/Open").unwrap();
+        assert!(open.has_callback());
+        assert!(open.shortcut() == Shortcut::Ctrl + 'o');
+    }
+    #[test]
+    fn remove_replace() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        let rc = Rc::new(());
+        for path in ["File/Recent/First", "File/Recent/Second", "File/Quit", "Edit/Copy"].iter() {
+            let r = rc.clone();
+            menu.add(path, Shortcut::None, MenuFlag::Normal, Box::new(move || {
+                let _ = &r;
+            }));
+        }
+        assert!(Rc::strong_count(&rc) == 5);
+        assert!(menu.remove_by_path("File/Recent/Second").is_ok());
+        assert!(menu.find_item("File/Recent/Second").is_none());
+        assert!(Rc::strong_count(&rc) == 4);
+        assert!(menu.remove_by_path("File/Recent/Missing").is_err());
+        // Removing a submenu removes its items
+        assert!(menu.remove(1).is_ok());
+        assert!(menu.find_item("File/Recent/First").is_none());
+        assert!(Rc::strong_count(&rc) == 3);
+        assert!(menu.replace(1, "Exit").is_ok());
+        assert!(menu.find_item("File/Exit").is_some());
+        // The terminator of the File submenu
+        assert!(menu.remove(2).is_err());
+        assert!(menu.replace(2, "Terminator").is_err());
+    }
+    #[test]
+    fn insert() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add("Window/First", Shortcut::None, MenuFlag::MenuDivider, Box::new(|| ()));
+        menu.add("Window/Close all", Shortcut::None, MenuFlag::Normal, Box::new(|| ()));
+        menu.insert(1, "Window/Second", Shortcut::None, MenuFlag::MenuDivider, Box::new(|| ()));
+        let (s, _r) = crate::app::channel::<i32>();
+        menu.insert_emit(0, "Window/Zeroth", Shortcut::None, MenuFlag::Normal, s, 0);
+        let labels: Vec<String> = menu.items().iter().filter_map(|i| i.label()).collect();
+        assert!(labels == vec!["Window", "Zeroth", "First", "Second", "Close all"]);
+    }
+    #[test]
+    fn choice_value() {
+        let mut choice = Choice::new(0, 0, 0, 0, "hello");
+        choice.add_choice("Red|Green|Blue");
+        assert!(choice.set_value(1));
+        assert!(choice.value() == 1);
+        assert!(choice.choice().unwrap() == "Green");
+        assert!(!choice.set_value(3));
+        assert!(choice.value() == 1);
+        assert!(choice.set_value(-1));
+        assert!(choice.value() == -1);
+        assert!(choice.choice().is_none());
+    }
+    #[test]
+    fn add_choices() {
+        let mut choice = Choice::new(0, 0, 0, 0, "hello");
+        choice.add_choices(&["Red", "Green|Blue", "On/Off"]);
+        choice.add_choices(vec![String::from("Black & White")]);
+        let labels: Vec<String> = choice.items().iter().filter_map(|i| i.label()).collect();
+        assert!(labels == vec!["Red", "Green|Blue", "On/Off", "Black && White"]);
+        assert!(escape_label("a/b\\c&d") == "a\\/b\\\\c&&d");
+    }
+    #[test]
+    fn flags() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        let flag = MenuFlag::Radio | MenuFlag::MenuDivider | MenuFlag::Value;
+        assert!(flag.contains(MenuFlag::Radio | MenuFlag::Value));
+        assert!(!flag.contains(MenuFlag::Toggle));
+        assert!(flag.bits() == 0x8c);
+        menu.add("Mode/First", Shortcut::None, flag, Box::new(|| ()));
+        menu.add("Mode/Second", Shortcut::None, MenuFlag::Radio, Box::new(|| ()));
+        assert!(menu.find_item("Mode/First").unwrap().value());
+        assert!(!menu.find_item("Mode/Second").unwrap().value());
+    }
+    #[test]
+    fn user_data() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        let seen = Rc::new(RefCell::new(vec![]));
+        for (id, path) in ["Recent/a.txt", "Recent/b.txt"].iter().enumerate() {
+            menu.add(path, Shortcut::None, MenuFlag::Normal, Box::new(|| ()));
+            let mut item = menu.find_item(path).unwrap();
+            item.set_user_data(id as u64);
+            let s = seen.clone();
+            item.set_callback(Box::new(move |item| {
+                s.borrow_mut().push(item.user_data::<u64>().unwrap())
+            }));
+        }
+        menu.find_item("Recent/b.txt").unwrap().do_callback();
+        assert!(*seen.borrow() == vec![1]);
+        assert!(menu.find_item("Recent/a.txt").unwrap().user_data::<String>().is_none());
+        // Items without a callback can carry data too
+        menu.add_choices(&["Other"]);
+        let mut other = menu.find_item("Other").unwrap();
+        assert!(!other.has_callback());
+        other.set_user_data(String::from("path"));
+        assert!(!other.has_callback());
+        assert!(other.user_data::<String>().unwrap() == "path");
+        let rc = Rc::new(());
+        other.set_user_data(rc.clone());
+        assert!(Rc::strong_count(&rc) == 2);
+        menu.clear();
+        assert!(Rc::strong_count(&rc) == 1);
+    }
+    #[test]
+    fn edit_flags() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add_choice("View/Grid|View/Rulers");
+        let mut grid = menu.find_item("View/Grid").unwrap();
+        assert!(!grid.is_toggle() && !grid.has_divider());
+        grid.set_flag(MenuFlag::Toggle | MenuFlag::MenuDivider);
+        assert!(grid.flags().contains(MenuFlag::Toggle | MenuFlag::MenuDivider));
+        assert!(grid.is_toggle() && !grid.is_radio());
+        assert!(grid.has_divider());
+        grid.clear_flag(MenuFlag::MenuDivider);
+        assert!(!grid.has_divider());
+        let mut view = menu.find_item("View").unwrap();
+        view.clear_flag(MenuFlag::Submenu);
+        assert!(view.is_submenu());
+        grid.set_flag(MenuFlag::Radio);
+        assert!(grid.is_radio());
+    }
+    #[test]
+    fn radio_groups() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        for path in ["View/List", "View/Icons", "View/Details", "Sort"].iter() {
+            menu.add(path, Shortcut::None, MenuFlag::Radio, Box::new(|| ()));
+        }
+        assert!(menu.find_radio_selected("View").is_none());
+        menu.find_item("View/Icons").unwrap().setonly();
+        let selected = menu.find_radio_selected("View").unwrap();
+        assert!(selected.label().unwrap() == "Icons");
+        menu.find_item("View/Details").unwrap().setonly();
+        assert!(!menu.find_item("View/Icons").unwrap().value());
+        assert!(menu.find_radio_selected("View").unwrap().label().unwrap() == "Details");
+        menu.find_item("Sort").unwrap().setonly();
+        assert!(menu.find_radio_selected("").unwrap().label().unwrap() == "Sort");
+        assert!(menu.find_radio_selected("Missing").is_none());
+    }
+    #[test]
+    fn shortcuts() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add("File/Open\tCtrl+O", Shortcut::None, MenuFlag::Normal, Box::new(|| ()));
+        // No shortcut event is being handled
+        assert!(menu.test_shortcut().is_none());
+        assert!(menu.at(0).unwrap().test_shortcut().is_none());
+        add_global_shortcuts(&menu);
+        add_global_shortcuts(&menu);
+        GLOBAL_SHORTCUTS.with(|s| assert!(s.borrow().installed && s.borrow().menus.len() == 1));
+    }
+    #[test]
+    fn popup_button() {
+        let mut button = MenuButton::new(0, 0, 100, 100, "");
+        button.set_type(MenuButtonType::Popup3);
+        assert!(button.get_type::<MenuButtonType>() == MenuButtonType::Popup3);
+        button.set_type(MenuButtonType::Popup13);
+        assert!(button.get_type::<MenuButtonType>() == MenuButtonType::Popup13);
+    }
+    #[test]
+    fn text_style() {
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.set_text_font(Font::Courrier);
+        menu.set_text_size(24);
+        menu.set_text_color(Color::Red);
+        assert!(menu.text_font() == Font::Courrier);
+        assert!(menu.text_size() == 24);
+        assert!(menu.text_color() == Color::Red);
+    }
+    #[test]
+    fn literal_labels() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        let name = "/home/me/_notes\tCtrl+S.txt";
+        menu.add_literal("File/Recent/", name, Shortcut::None, MenuFlag::Normal, Box::new(|| ()));
+        menu.add_literal("", "A & B", Shortcut::None, MenuFlag::Normal, Box::new(|| ()));
+        let recent = menu.find_item("File/Recent").unwrap();
+        let item = recent.children().pop().unwrap();
+        assert!(item.label().unwrap() == name);
+        assert!(item.shortcut() == Shortcut::None);
+        assert!(menu.find_item("A && B").is_some());
+    }
+    #[test]
+    fn clear_by_path() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        let rc = Rc::new(());
+        for path in ["File/Recent/First", "File/Recent/Second", "File/Quit"].iter() {
+            let r = rc.clone();
+            menu.add(path, Shortcut::None, MenuFlag::Normal, Box::new(move || {
+                let _ = &r;
+            }));
+        }
+        assert!(menu.find_index("File/Quit") == Some(5));
+        assert!(menu.clear_submenu_by_path("File/Recent").is_ok());
+        assert!(Rc::strong_count(&rc) == 2);
+        assert!(menu.find_item("File/Recent").unwrap().children().is_empty());
+        assert!(menu.find_index("File/Quit") == Some(3));
+        assert!(menu.clear_submenu_by_path("File/Missing").is_err());
+    }
+    #[test]
+    fn menu_macro() {
+        let _win = crate::window::Window::default();
+        let menu = crate::menu! {
+            MenuBar::new(0, 0, 0, 0, "") {
+                "File" {
+                    "Open", Shortcut::Ctrl + 'o' => || ();
+                    "Recent" {
+                        "a/b_c&d.txt" => || ();
+                    }
+                    "Empty" {}
+                }
+                "Word Wrap", Shortcut::None, MenuFlag::Toggle => || ();
+            }
+        };
+        assert!(menu.find_item("File/Open").unwrap().shortcut() == Shortcut::Ctrl + 'o');
+        // Labels are escaped, the item isn't split into submenus
+        let recent = menu.find_item("File/Recent").unwrap().children();
+        assert!(recent.len() == 1);
+        assert!(recent[0].label().unwrap() == "a/b_c&&d.txt");
+        assert!(menu.find_item("File/Empty").unwrap().is_submenu());
+        assert!(menu.find_item("Word Wrap").unwrap().is_toggle());
+    }
+    #[test]
+    fn custom_drawing() {
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add_choice("File/Open");
+        let mut item = menu.find_item("File/Open").unwrap();
+        item.set_drawer(Box::new(|_, _, _, _, _, _| ()), Box::new(|_| (100, 20)));
+        assert!(item.label_type::<LabelType>() == LabelType::FreeLabelType);
+        assert!(item.label().unwrap() == "Open");
+        assert!(ITEM_DRAWERS.with(|d| d.borrow().len()) == 1);
+        // The drawer follows the label and goes away with the item
+        item.set_label("Open...");
+        item.set_drawer(Box::new(|_, _, _, _, _, _| ()), Box::new(|_| (100, 20)));
+        assert!(ITEM_DRAWERS.with(|d| d.borrow().len()) == 1);
+        menu.clear();
+        assert!(ITEM_DRAWERS.with(|d| d.borrow().is_empty()));
+    }
+    #[test]
+    fn table() {
+        let _win = crate::window::Window::default();
+        let (s, _r) = crate::app::channel::<i32>();
+        let menu = MenuBar::from_table(
+            0,
+            0,
+            0,
+            0,
+            s,
+            &[
+                ("File/Open", Shortcut::Ctrl + 'o', MenuFlag::Normal, 1),
+                ("File/Quit", Shortcut::Ctrl + 'q', MenuFlag::MenuDivider, 2),
+                ("View/Wrap", Shortcut::None, MenuFlag::Toggle, 3),
+            ],
+        );
+        assert!(menu.find_item("File/Open").unwrap().shortcut() == Shortcut::Ctrl + 'o');
+        assert!(menu.find_item("File/Quit").unwrap().has_divider());
+        assert!(menu.find_item("View/Wrap").unwrap().is_toggle());
+    }
+    #[test]
+    fn item_state_by_path() {
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        menu.add_choice("Edit/Paste|View/Wrap");
+        menu.set_item_active("Edit/Paste", false).unwrap();
+        assert!(!menu.find_item("Edit/Paste").unwrap().active());
+        menu.set_item_value("View/Wrap", true).unwrap();
+        assert!(menu.find_item("View/Wrap").unwrap().value());
+        menu.set_item_value("View/Wrap", false).unwrap();
+        assert!(!menu.find_item("View/Wrap").unwrap().value());
+        assert!(menu.set_item_active("Edit/Cut", true).is_err());
+    }
+    #[test]
+    fn callback_with_widget() {
+        let _win = crate::window::Window::default();
+        let mut menu = MenuBar::new(0, 0, 0, 0, "hello");
+        let count = Rc::new(RefCell::new(0));
+        let c = count.clone();
+        menu.add(
+            "File/Open",
+            Shortcut::None,
+            MenuFlag::Normal,
+            Box::new(move || *c.borrow_mut() += 1),
+        );
+        let mut item = menu.find_item("File/Open").unwrap();
+        item.do_callback_with(&menu);
+        assert!(*count.borrow() == 1);
+    }
+    #[test]
+    fn item_callback() {
+        let item = MenuItem::new(vec!["first", "second"]);
+        let mut second = item.next(1).unwrap();
+        assert!(second.label().unwrap() == "second");
+        assert!(item.next(2).is_none());
+        let picked = Rc::new(RefCell::new(String::new()));
+        let p = picked.clone();
+        second.set_callback(Box::new(move |item| *p.borrow_mut() = item.label().unwrap()));
+        assert!(second.has_callback());
+        assert!(!item.has_callback());
+        second.do_callback();
+        assert!(*picked.borrow() == "second");
+        picked.borrow_mut().clear();
+        let mut cb = second.callback().unwrap();
+        cb();
+        assert!(*picked.borrow() == "second");
+        assert!(item.callback().is_none());
+    }
+
+    #[test]
+    fn delete_items() {
+        let item = MenuItem::new(vec!["first", "second"]);
+        let data = Rc::new(0);
+        item.next(0).unwrap().set_user_data(data.clone());
+        item.next(1).unwrap().set_user_data(data.clone());
+        assert!(Rc::strong_count(&data) == 3);
+        unsafe { delete_menu_items(item, 2) }
+        assert!(Rc::strong_count(&data) == 1);
+    }
 }
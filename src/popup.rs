@@ -0,0 +1,87 @@
+use crate::app;
+pub use crate::prelude::*;
+
+/// Defines where a popup is placed relative to its anchor
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Placement {
+    /// Below the anchor, flipped above it if it doesn't fit on screen
+    Below,
+    /// Above the anchor, flipped below it if it doesn't fit on screen
+    Above,
+    /// Below the anchor if it fits, otherwise on the side with more room
+    Auto,
+}
+
+/// Defines what a popup is positioned against, in screen coordinates
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Anchor {
+    /// A point, like the mouse position or a text cursor
+    Point(i32, i32),
+    /// A rectangle given by its x, y, width and height
+    Rect(i32, i32, i32, i32),
+}
+
+impl Anchor {
+    /// Creates an anchor from the on-screen area of a widget
+    pub fn from_widget<W: WidgetExt>(widget: &W) -> Anchor {
+        let mut x = widget.x();
+        let mut y = widget.y();
+        let mut win = widget.window();
+        while let Some(w) = win {
+            x += w.x();
+            y += w.y();
+            win = w.window();
+        }
+        Anchor::Rect(x, y, widget.width(), widget.height())
+    }
+
+    fn rect(self) -> (i32, i32, i32, i32) {
+        match self {
+            Anchor::Point(x, y) => (x, y, 0, 0),
+            Anchor::Rect(x, y, w, h) => (x, y, w, h),
+        }
+    }
+}
+
+/// Returns the screen position of a popup of the given width and height placed next to the anchor,
+/// keeping it within the work area of the screen containing the anchor
+pub fn position_near(anchor: Anchor, size: (i32, i32), placement: Placement) -> (i32, i32) {
+    let (ax, ay, aw, ah) = anchor.rect();
+    let (w, h) = size;
+    let (sx, sy, sw, sh) = app::screen_work_area(ax + aw / 2, ay + ah / 2);
+    let below = ay + ah;
+    let above = ay - h;
+    let fits_below = below + h <= sy + sh;
+    let fits_above = above >= sy;
+    let more_room_below = sy + sh - below >= ay - sy;
+    let y = match placement {
+        Placement::Below if fits_below || !fits_above => below,
+        Placement::Above if fits_above || !fits_below => above,
+        Placement::Below | Placement::Above => {
+            if fits_below {
+                below
+            } else {
+                above
+            }
+        }
+        Placement::Auto => {
+            if fits_below || (!fits_above && more_room_below) {
+                below
+            } else {
+                above
+            }
+        }
+    };
+    (clamp(ax, sx, sx + sw - w), clamp(y, sy, sy + sh - h))
+}
+
+/// Clamps a coordinate, preferring the lower bound when the popup is larger than the screen
+fn clamp(val: i32, min: i32, max: i32) -> i32 {
+    if val > max {
+        std::cmp::max(max, min)
+    } else if val < min {
+        min
+    } else {
+        val
+    }
+}
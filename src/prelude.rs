@@ -1,6 +1,6 @@
 pub use crate::enums::*;
 use crate::image::Image;
-use crate::text::{StyleTableEntry, TextBuffer};
+use crate::text::{StyleTableEntry, StyleTableEntryExt, TextBuffer};
 use crate::widget::Widget;
 use crate::window::Window;
 use std::convert::From;
@@ -178,8 +178,19 @@ pub unsafe trait WidgetExt {
     fn set_trigger(&mut self, trigger: CallbackTrigger);
     /// Set a custom draw method
     fn draw(&mut self, cb: Box<dyn FnMut()>);
+    /// Sets a filter applied to the widget's pixels after each draw, e.g. to show a disabled state
+    fn set_draw_filter(&mut self, filter: crate::widget::Filter);
+    /// Sets a hook drawing over the widget after it and its draw filter are drawn,
+    /// receiving the widget's drawing area as x, y, w and h. Useful for badges and adorners
+    fn draw_over(&mut self, cb: Box<dyn FnMut(i32, i32, i32, i32)>);
     /// Returns the parent of the widget
     fn parent(&self) -> Option<Widget>;
+    /// Moves the widget into the group, removing it from its previous parent
+    fn set_parent_explicit<G: GroupExt>(&mut self, parent: &mut G);
+    /// Sets the id of the widget's context help topic, see dialog::set_help_provider
+    fn set_help_id(&mut self, id: &str);
+    /// Returns the id of the widget's context help topic
+    fn help_id(&self) -> Option<String>;
     /// Gets the selection color of the widget
     fn selection_color(&mut self) -> Color;
     /// Sets the selection color of the widget
@@ -217,26 +228,54 @@ pub unsafe trait ButtonExt: WidgetExt {
 
 /// Defines the methods implemented by all group widgets
 pub unsafe trait GroupExt: WidgetExt {
-    /// Begins a group, used for widgets implementing the group trait
+    /// Begins a group, making it the current group which widgets are added to on construction.
+    /// Groups begin on construction, an ended group can be reopened using begin
     fn begin(&self);
-    /// Ends a group, used for widgets implementing the group trait
+    /// Ends a group, making its parent the current group
     fn end(&self);
-    /// Find a widget within a group and return its index
+    /// Find a widget within a group and return its index,
+    /// which is the number of children if the widget isn't a child of the group
     fn find<Widget: WidgetExt>(&self, widget: &Widget) -> u32;
-    /// Add a widget to a group
+    /// Add a widget to a group, removing it from its previous group
     fn add<Widget: WidgetExt>(&mut self, widget: &Widget);
-    /// Insert a widget to a group at a certain index
+    /// Insert a widget to a group at a certain index, removing it from its previous group.
+    /// A child of the group is moved to the index, an index past the last child appends it
     fn insert<Widget: WidgetExt>(&mut self, widget: &Widget, index: u32);
-    /// Remove a widget from a group
+    /// Remove a widget from a group, the widget isn't deleted
     fn remove<Widget: WidgetExt>(&mut self, widget: &Widget);
-    /// Clear a group from all widgets
+    /// Remove the child at an index from a group, the widget isn't deleted
+    fn remove_by_index(&mut self, idx: u32);
+    /// Deletes all the children of a group. The closures set on them and their descendants
+    /// are dropped once the event being handled is done, so a child's callback can clear its group
     fn clear(&mut self);
+    /// Removes the child at an index and deletes it once the event being handled is done,
+    /// along with the closures set on it and its descendants.
+    /// An index past the last child returns FltkErrorKind::ResourceNotFound
+    fn delete_child(&mut self, idx: u32) -> Result<(), FltkError>;
     /// Return the number of children in a group
     fn children(&self) -> u32;
-    /// Return child widget by index
+    /// Return child widget by index, or None past the last child
     fn child(&self, idx: u32) -> Option<Widget>;
+    /// Returns an iterator over the children of the group, in order
+    fn iter(&self) -> crate::group::GroupIterator;
     /// Make the passed widget resizable
+    #[deprecated(note = "use set_resizable instead")]
     fn resizable<Widget: WidgetExt>(&self, widget: &mut Widget);
+    /// Makes a child the resizable region of the group, the other children keep their size
+    /// when the group is resized. Passing the group itself resizes all children
+    fn set_resizable<Widget: WidgetExt>(&mut self, widget: &Widget);
+    /// Unsets the resizable region, so the children keep their size when the group is resized
+    fn clear_resizable(&mut self);
+    /// Clips the drawing of the children to the bounds of the group
+    fn set_clip_children(&mut self, flag: bool);
+    /// Returns whether the drawing of the children is clipped to the bounds of the group
+    fn clip_children(&self) -> bool;
+    /// Draws the children of the group, only valid while drawing the group,
+    /// for example within the closure passed to override_draw
+    fn draw_children(&mut self);
+    /// Replaces how the group is drawn, neither its box nor its children being drawn
+    /// unless the closure does so, see draw_children and draw::draw_box
+    fn override_draw(&mut self, cb: Box<dyn FnMut()>);
 }
 
 /// Defines the methods implemented by all window widgets
@@ -262,6 +301,9 @@ pub unsafe trait WindowExt: GroupExt {
     /// Get the raw system handle of the window
     /// void pointer to: (Windows: HWND, X11: Xid, MacOS: NSWindow)
     unsafe fn raw_handle(&self) -> *const raw::c_void;
+    /// Sets a callback called when the window is minimized, restored, maximized, unmaximized,
+    /// gains or loses focus. Maximization changes are reported with the next event the window receives
+    fn set_state_callback(&mut self, cb: Box<dyn FnMut(crate::window::WindowState)>);
 }
 
 /// Defines the methods implemented by all input and output widgets
@@ -318,23 +360,26 @@ pub unsafe trait InputExt: WidgetExt {
 
 /// Defines the methods implemented by all menu widgets
 pub unsafe trait MenuExt: WidgetExt {
-    /// Get a menu item by name
+    /// Get a menu item by its path, like "File/Recent/Clear", so that it can be enabled,
+    /// relabeled, checked or hidden after its creation
     fn find_item(&self, name: &str) -> Option<crate::menu::MenuItem>;
     /// Set selected item
     fn set_item(&mut self, item: &crate::menu::MenuItem) -> bool;
     /// Return the text font
     fn text_font(&self) -> Font;
-    /// Sets the text font
+    /// Sets the text font of the menu's items, items with their own label font keep it
     fn set_text_font(&mut self, c: Font);
     /// Return the text size
     fn text_size(&self) -> u32;
-    /// Sets the text size
+    /// Sets the text size of the menu's items, items with their own label size keep it
     fn set_text_size(&mut self, c: u32);
     /// Return the text color
     fn text_color(&self) -> Color;
-    /// Sets the text color
+    /// Sets the text color of the menu's items
     fn set_text_color(&mut self, c: Color);
-    /// Add a menu item along with its callback
+    /// Add a menu item along with its callback.
+    /// An accelerator can follow a tab in the name, like "File/Save\tCtrl+S",
+    /// it's used as the shortcut when Shortcut::None is passed
     fn add(
         &mut self,
         name: &str,
@@ -342,7 +387,36 @@ pub unsafe trait MenuExt: WidgetExt {
         flag: crate::menu::MenuFlag,
         cb: Box<dyn FnMut()>,
     );
-    /// Inserts a menu item at an index along with its callback
+    /// Add a menu item whose label is shown as is, in the submenu at parent or at the top
+    /// level for an empty parent. Useful for labels like file names, see menu::escape_label
+    fn add_literal(
+        &mut self,
+        parent: &str,
+        label: &str,
+        shortcut: Shortcut,
+        flag: crate::menu::MenuFlag,
+        cb: Box<dyn FnMut()>,
+    );
+    /// Add a menu item which sends a message through a channel when chosen,
+    /// letting the event loop handle it
+    fn add_emit<T: 'static + Copy + Send + Sync>(
+        &mut self,
+        name: &str,
+        shortcut: Shortcut,
+        flag: crate::menu::MenuFlag,
+        sender: crate::app::Sender<T>,
+        msg: T,
+    );
+    /// Adds a menu item per (path, shortcut, flags, message) entry of the table,
+    /// each sending its message through the channel when chosen
+    fn add_table<T: 'static + Copy + Send + Sync>(
+        &mut self,
+        sender: crate::app::Sender<T>,
+        table: &[(&str, Shortcut, crate::menu::MenuFlag, T)],
+    );
+    /// Inserts a menu item at an index along with its callback.
+    /// For a path like "Window/Doc", the index is counted within the Window submenu,
+    /// an index past the last item appends it
     fn insert(
         &mut self,
         idx: u32,
@@ -351,18 +425,82 @@ pub unsafe trait MenuExt: WidgetExt {
         flag: crate::menu::MenuFlag,
         cb: Box<dyn FnMut()>,
     );
+    /// Inserts a menu item at an index which sends a message through a channel when chosen
+    fn insert_emit<T: 'static + Copy + Send + Sync>(
+        &mut self,
+        idx: u32,
+        name: &str,
+        shortcut: Shortcut,
+        flag: crate::menu::MenuFlag,
+        sender: crate::app::Sender<T>,
+        msg: T,
+    );
     /// Adds a simple text option to the Choice and MenuButton widgets
     fn add_choice(&mut self, text: &str);
-    /// Gets the user choice from the Choice and MenuButton widgets
+    /// Adds an item per choice, escaped so that '|', '/', '_' and '&' appear as is
+    fn add_choices<I>(&mut self, choices: I)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>;
+    /// Gets the label of the user choice from the Choice and MenuButton widgets,
+    /// None if no item is chosen
     fn choice(&self) -> Option<String>;
-    /// Get index into menu of the last item chosen
+    /// Get index into menu of the last item chosen, -1 if no item is chosen
     fn value(&self) -> i32;
-    /// Set index into menu of the last item chosen,return true if the new value is different than the old one
+    /// Set index into menu of the last item chosen, -1 clears the choice.
+    /// Returns true if the new value is different than the old one,
+    /// out of range indices return false
     fn set_value(&mut self,v:i32) -> bool;
     /// Clears the items in a menu
     fn clear(&mut self);
     /// Clears a submenu by index, failure return FltkErrorKind::FailedOperation
     fn clear_submenu(&mut self, idx: u32) -> Result<(), FltkError>;
+    /// Clears the submenu at a path like "File/Recent", dropping the callbacks of its items.
+    /// A missing submenu returns FltkErrorKind::ResourceNotFound
+    fn clear_submenu_by_path(&mut self, path: &str) -> Result<(), FltkError>;
+    /// Returns the index of the menu item at a path like "File/Recent/First"
+    fn find_index(&self, path: &str) -> Option<u32>;
+    /// Activates or deactivates the menu item at a path like "Edit/Paste".
+    /// A missing item returns FltkErrorKind::ResourceNotFound
+    fn set_item_active(&mut self, path: &str, active: bool) -> Result<(), FltkError>;
+    /// Shows an image next to the label of the menu item at a path like "File/Open",
+    /// replacing its previous image. A missing item returns FltkErrorKind::ResourceNotFound
+    fn add_image<I: ImageExt>(
+        &mut self,
+        path: &str,
+        image: &I,
+        on_left: bool,
+    ) -> Result<(), FltkError>;
+    /// Checks or unchecks the toggle or radio item at a path like "View/Word Wrap",
+    /// checking a radio item unchecks the others of its group.
+    /// A missing item returns FltkErrorKind::ResourceNotFound
+    fn set_item_value(&mut self, path: &str, value: bool) -> Result<(), FltkError>;
+    /// Removes the menu item at an index along with its submenu's items,
+    /// failure return FltkErrorKind::FailedOperation
+    fn remove(&mut self, idx: u32) -> Result<(), FltkError>;
+    /// Removes the menu item at a path like "File/Recent/First" along with its submenu's items,
+    /// a missing item returns FltkErrorKind::ResourceNotFound
+    fn remove_by_path(&mut self, path: &str) -> Result<(), FltkError>;
+    /// Replaces the label of the menu item at an index,
+    /// failure return FltkErrorKind::FailedOperation
+    fn replace(&mut self, idx: u32, label: &str) -> Result<(), FltkError>;
+    /// Returns the number of menu items, counting the terminators ending the menu and each submenu
+    fn size(&self) -> u32;
+    /// Get a menu item by index, valid indices are less than size() - 1
+    fn at(&self, idx: u32) -> Option<crate::menu::MenuItem>;
+    /// Runs the callback of the item whose shortcut matches the current event, returning it.
+    /// Useful in handlers of Event::Shortcut, see also menu::add_global_shortcuts
+    fn test_shortcut(&mut self) -> Option<crate::menu::MenuItem>;
+    /// Returns the set radio item among the items of the submenu at path,
+    /// or the top level items for an empty path
+    fn find_radio_selected(&self, path: &str) -> Option<crate::menu::MenuItem>;
+    /// Returns all the menu items in order, including submenus and their items
+    /// but not the terminators ending them
+    fn items(&self) -> Vec<crate::menu::MenuItem>;
+    /// Returns the full path of a menu item, like "File/Open"
+    fn item_pathname(&self, item: &crate::menu::MenuItem) -> Option<String>;
+    /// Picks a menu item as if the user chose it, running its callback
+    fn pick(&mut self, item: &crate::menu::MenuItem);
 }
 
 /// Defines the methods implemented by all valuator widgets
@@ -399,10 +537,11 @@ pub unsafe trait ValuatorExt: WidgetExt {
     fn increment(&mut self, arg2: f64, arg3: i32) -> f64;
 }
 
-/// Defines the methods implemented by TextDisplay and TextEditor
+/// Defines the methods implemented by TextDisplay and TextEditor.
+/// Selected text is drawn using WidgetExt::set_selection_color, see set_highlight_color and set_secondary_selection_color for the other ranges
 pub unsafe trait DisplayExt: WidgetExt {
-    /// Get the associated TextBuffer
-    fn buffer<'a>(&'a self) -> &'a mut TextBuffer;
+    /// Get the associated TextBuffer, which shares ownership of the buffer with the widget
+    fn buffer(&self) -> Option<TextBuffer>;
     /// Sets the associated TextBuffer
     fn set_buffer<'a>(&'a mut self, buffer: &'a mut TextBuffer);
     /// Return the text font
@@ -445,6 +584,14 @@ pub unsafe trait DisplayExt: WidgetExt {
         style_buffer: &mut TextBuffer,
         entries: &Vec<StyleTableEntry>,
     );
+    /// Sets the style of the text widget using entries supporting background colors and attributes
+    fn set_style_table_entry_ext(
+        &mut self,
+        style_buffer: &mut TextBuffer,
+        entries: &Vec<StyleTableEntryExt>,
+    );
+    /// Removes the style buffer and style table, the text is drawn using the text font, size and color again
+    fn clear_style_table(&mut self);
     /// Sets the cursor style
     fn set_cursor_style(&mut self, style: CursorStyle);
     /// Sets the cursor color
@@ -511,6 +658,31 @@ pub unsafe trait DisplayExt: WidgetExt {
     fn linenumber_align(&self) -> Align;
     /// Checks whether a pixel is within a text selection
     fn in_selection(&self, x: i32, y: i32) -> bool;
+    /// Sets a callback receiving the 1-based line clicked in the line number area,
+    /// e.g. to toggle breakpoints. Handlers set using handle() still receive other events
+    fn set_linenumber_callback(&mut self, cb: Box<dyn FnMut(u32)>);
+    /// Scrolls the display to show the insert position
+    fn show_insert_position(&mut self);
+    /// Sets the background of the secondary selection, which isn't drawn differently by default
+    fn set_secondary_selection_color(&mut self, color: Color);
+    /// Returns the background of the secondary selection
+    fn secondary_selection_color(&self) -> Color;
+    /// Sets the background of highlighted text, by default a blend of the color and selection color
+    fn set_highlight_color(&mut self, color: Color);
+    /// Returns the background of highlighted text
+    fn highlight_color(&self) -> Color;
+    /// Returns the 1-based line and column of a position, the column counts characters
+    /// and lines are the buffer's lines, regardless of wrapping
+    fn position_to_line_column(&self, pos: u32) -> (u32, u32);
+    /// Returns the position at a 1-based line and column, clamped to the line's end
+    fn line_column_to_position(&self, line: u32, col: u32) -> u32;
+    /// Highlights every occurrence of the text with a background color until cleared.
+    /// The highlights use a style buffer managed by the display, replacing its style table
+    fn highlight_occurrences(&mut self, text: &str, color: Color);
+    /// Highlights the ranges with a background color until cleared, like highlight_occurrences
+    fn highlight_ranges(&mut self, ranges: &[(u32, u32)], color: Color);
+    /// Clears the highlights set by highlight_occurrences or highlight_ranges, removing their style table
+    fn clear_occurrence_highlights(&mut self);
 }
 
 /// Defines the methods implemented by all browser types
@@ -2,20 +2,26 @@ use crate::image::Image;
 pub use crate::prelude::*;
 use fltk_sys::text::*;
 use std::{
+    borrow::Cow,
+    cell::RefCell,
+    collections::HashMap,
     ffi::{CStr, CString},
+    io::{self, Write},
     mem,
     os::raw,
+    rc::Rc,
 };
 
-/// Wraps a text buffer, Cloning a text buffer invalidates the underlying pointer, thus the no derive(Clone)
+/// Wraps a reference counted text buffer, the buffer is deleted once neither a TextBuffer
+/// nor a widget displaying it references it. Cloning a text buffer copies its text into a new buffer
 #[derive(Debug)]
 pub struct TextBuffer {
     _inner: *mut Fl_Text_Buffer,
 }
 
-impl TextBuffer {
+impl Default for TextBuffer {
     /// Initialized a default text buffer
-    pub fn default() -> Self {
+    fn default() -> Self {
         unsafe {
             let text_buffer = Fl_Text_Buffer_new();
             assert!(!text_buffer.is_null());
@@ -24,14 +30,48 @@ impl TextBuffer {
             }
         }
     }
+}
+
+impl From<&str> for TextBuffer {
+    fn from(txt: &str) -> Self {
+        TextBuffer::with_text(txt)
+    }
+}
+
+impl From<String> for TextBuffer {
+    fn from(txt: String) -> Self {
+        TextBuffer::with_text(&txt)
+    }
+}
+
+impl TextBuffer {
+    /// Initializes a text buffer holding the given text
+    pub fn with_text(txt: &str) -> Self {
+        let mut buf = TextBuffer::default();
+        buf.set_text(txt);
+        buf
+    }
 
-    /// Deletes the TextBuffer
+    /// Releases the TextBuffer's reference early, which deletes the buffer if it was the last one.
+    /// The TextBuffer can't be used afterwards
     pub unsafe fn delete(&mut self) {
-        Fl_Text_Buffer_delete(self._inner)
+        if self._inner.is_null() {
+            return;
+        }
+        if Fl_Text_Buffer_refcount(self._inner) == 1 {
+            let old =
+                Fl_Text_Buffer_set_line_ending_callback(self._inner, None, std::ptr::null_mut());
+            if !old.is_null() {
+                let _ = Box::from_raw(old as *mut Box<dyn FnMut(LineEnding)>);
+            }
+        }
+        Fl_Text_Buffer_delete(self._inner);
+        self._inner = std::ptr::null_mut();
     }
 
-    /// Initialized a text buffer from a pointer
+    /// Initialized a text buffer from a pointer, sharing ownership of the buffer
     pub unsafe fn from_ptr(ptr: *mut Fl_Text_Buffer) -> Self {
+        Fl_Text_Buffer_retain(ptr);
         TextBuffer { _inner: ptr }
     }
 
@@ -50,13 +90,23 @@ impl TextBuffer {
 
     /// Returns the text of the buffer
     pub fn text(&self) -> String {
-        unsafe {
-            let text = Fl_Text_Buffer_text(self._inner);
-            assert!(!text.is_null());
-            CString::from_raw(text as *mut raw::c_char)
-                .to_string_lossy()
-                .to_string()
+        self.text_slice().into_owned()
+    }
+
+    /// Returns the text of the buffer, replacing invalid UTF-8.
+    /// The text is borrowed in place unless the buffer's gap splits it,
+    /// the borrow shouldn't outlive modifications made through other handles
+    pub fn text_slice(&self) -> Cow<'_, str> {
+        lossy(self.range_bytes(0, self.length() as usize))
+    }
+
+    /// Returns the text between start and end, replacing invalid UTF-8.
+    /// Borrowed like text_slice(), returns None if the range is out of bounds
+    pub fn text_range_slice(&self, start: u32, end: u32) -> Option<Cow<'_, str>> {
+        if start > end || end > self.length() {
+            return None;
         }
+        Some(lossy(self.range_bytes(start as usize, end as usize)))
     }
 
     /// Appends to the buffer
@@ -81,32 +131,14 @@ impl TextBuffer {
             "u32 entries must be < std::i32::MAX for compatibility!"
         );
         unsafe {
-            Fl_Text_Buffer_remove(self._inner, start as i32, end as i32);
+            let (start, end) = (self.char_boundary(start), self.char_boundary(end));
+            Fl_Text_Buffer_remove(self._inner, start, end);
         }
     }
 
-    /// Returns the text within the range
+    /// Returns the text within the range, or None if the range is out of bounds
     pub fn text_range(&self, start: u32, end: u32) -> Option<String> {
-        debug_assert!(
-            start <= std::i32::MAX as u32,
-            "u32 entries must be < std::i32::MAX for compatibility!"
-        );
-        debug_assert!(
-            end <= std::i32::MAX as u32,
-            "u32 entries must be < std::i32::MAX for compatibility!"
-        );
-        unsafe {
-            let x = Fl_Text_Buffer_text_range(self._inner, start as i32, end as i32);
-            if x.is_null() {
-                None
-            } else {
-                Some(
-                    CString::from_raw(x as *mut raw::c_char)
-                        .to_string_lossy()
-                        .to_string(),
-                )
-            }
-        }
+        self.text_range_slice(start, end).map(Cow::into_owned)
     }
 
     /// Inserts text into a position
@@ -116,7 +148,8 @@ impl TextBuffer {
             "u32 entries must be < std::i32::MAX for compatibility!"
         );
         let text = CString::new(text).unwrap();
-        unsafe { Fl_Text_Buffer_insert(self._inner, pos as i32, text.as_ptr()) }
+        let pos = self.char_boundary(pos);
+        unsafe { Fl_Text_Buffer_insert(self._inner, pos, text.as_ptr()) }
     }
 
     /// Replaces text from position ```start``` to ```end```
@@ -130,7 +163,10 @@ impl TextBuffer {
             "u32 entries must be < std::i32::MAX for compatibility!"
         );
         let text = CString::new(text).unwrap();
-        unsafe { Fl_Text_Buffer_replace(self._inner, start as i32, end as i32, text.as_ptr()) }
+        // FLTK's replace doesn't order its range like remove does
+        let (start, end) = (std::cmp::min(start, end), std::cmp::max(start, end));
+        let (start, end) = (self.char_boundary(start), self.char_boundary(end));
+        unsafe { Fl_Text_Buffer_replace(self._inner, start, end, text.as_ptr()) }
     }
 
     /// Copies text from a source buffer into the current buffer
@@ -173,21 +209,245 @@ impl TextBuffer {
         unsafe { Fl_Text_Buffer_canUndo(self._inner, flag as i8) }
     }
 
+    /// Runs the edits made by the closure so that they're undone as a single action.
+    /// Modify callbacks see the edits as they're made. FLTK can't group undo actions, so the
+    /// edited range is then reverted and replaced in one undoable step, which the modify
+    /// callbacks added from Rust don't see
+    pub fn with_undo_group<F: FnOnce(&mut TextBuffer)>(&mut self, edits: F) {
+        let group = Rc::new(RefCell::new(UndoGroup::default()));
+        let tracker = group.clone();
+        let buf = unsafe { TextBuffer::from_ptr(self._inner) };
+        let data = self.add_removable_modify_callback(Box::new(
+            move |pos, inserted, deleted, _, deleted_text| {
+                tracker.borrow_mut().track(
+                    &buf,
+                    pos as usize,
+                    inserted as usize,
+                    deleted as usize,
+                    deleted_text.as_bytes(),
+                )
+            },
+        ));
+        edits(self);
+        self.remove_modify_callback_data(data);
+        let range = match group.borrow_mut().range.take() {
+            Some(Ok(range)) => range,
+            _ => return,
+        };
+        let after = self.range_bytes(range.start, range.end).into_owned();
+        if let (Ok(before), Ok(after)) = (String::from_utf8(range.before), String::from_utf8(after))
+        {
+            if before == after {
+                return;
+            }
+            MODIFY_CALLBACKS_SUPPRESSED.with(|s| s.set(true));
+            self.can_undo(false);
+            self.replace(range.start as u32, range.end as u32, &before);
+            self.can_undo(true);
+            self.replace(range.start as u32, (range.start + before.len()) as u32, &after);
+            MODIFY_CALLBACKS_SUPPRESSED.with(|s| s.set(false));
+        }
+    }
+
     /// Loads a file into the buffer
     pub fn load_file(&mut self, path: &std::path::Path) -> Result<(), FltkError> {
         if !path.exists() {
             return Err(FltkError::Internal(FltkErrorKind::ResourceNotFound));
         }
-        let path = path.to_str().unwrap();
+        let path = path
+            .to_str()
+            .ok_or(FltkError::Internal(FltkErrorKind::FailedOperation))?;
         let path = CString::new(path)?;
         unsafe {
             match Fl_Text_Buffer_loadfile(self._inner, path.as_ptr(), 0) {
-                0 => Err(FltkError::Internal(FltkErrorKind::ResourceNotFound)),
-                _ => Ok(()),
+                0 => (),
+                _ => return Err(FltkError::Internal(FltkErrorKind::ResourceNotFound)),
+            }
+        }
+        self.normalize_line_endings();
+        Ok(())
+    }
+
+    /// Saves the buffer into a file, using the buffer's line ending
+    pub fn save_file(&mut self, path: &std::path::Path) -> Result<(), FltkError> {
+        if self.line_ending() != LineEnding::Lf {
+            return Ok(std::fs::write(path, self.text_with_line_endings())?);
+        }
+        let path = path
+            .to_str()
+            .ok_or(FltkError::Internal(FltkErrorKind::FailedOperation))?;
+        let path = CString::new(path)?;
+        unsafe {
+            match Fl_Text_Buffer_savefile(self._inner, path.as_ptr()) {
+                0 => Ok(()),
+                _ => Err(FltkError::Internal(FltkErrorKind::FailedOperation)),
+            }
+        }
+    }
+
+    /// Loads a UTF-8 file into the buffer in chunks of chunk_size bytes, one chunk per event loop iteration,
+    /// so that the UI stays responsive. The progress callback receives the loaded fraction after each chunk,
+    /// the done callback receives the result once the file is loaded or reading it failed
+    pub fn load_file_chunked(
+        &mut self,
+        path: &std::path::Path,
+        chunk_size: usize,
+        progress: Box<dyn FnMut(f64)>,
+        done: Box<dyn FnOnce(Result<(), FltkError>)>,
+    ) -> Result<(), FltkError> {
+        if !path.exists() {
+            return Err(FltkError::Internal(FltkErrorKind::ResourceNotFound));
+        }
+        let file = std::fs::File::open(path)?;
+        let total = file.metadata()?.len();
+        self.set_text("");
+        let load = Box::new(ChunkedLoad {
+            file,
+            buf: unsafe { TextBuffer::from_ptr(self._inner) },
+            chunk: vec![0; std::cmp::max(chunk_size, 4)],
+            pending: vec![],
+            loaded: 0,
+            total,
+            ending: None,
+            progress,
+            done,
+        });
+        unsafe {
+            fltk_sys::fl::Fl_add_timeout(
+                0.0,
+                Some(load_next_chunk),
+                Box::into_raw(load) as *mut raw::c_void,
+            );
+        }
+        Ok(())
+    }
+
+    /// Loads a file in the given encoding into the buffer, converting it to UTF-8
+    pub fn load_file_with_encoding(
+        &mut self,
+        path: &std::path::Path,
+        encoding: Encoding,
+    ) -> Result<(), FltkError> {
+        if !path.exists() {
+            return Err(FltkError::Internal(FltkErrorKind::ResourceNotFound));
+        }
+        let text = encoding.decode(&std::fs::read(path)?);
+        if text.contains('\0') {
+            return Err(FltkError::Internal(FltkErrorKind::FailedOperation));
+        }
+        self.set_text(&text);
+        self.normalize_line_endings();
+        Ok(())
+    }
+
+    /// Returns the line ending used when saving the buffer,
+    /// detected when loading a file. The buffer itself always uses LF
+    pub fn line_ending(&self) -> LineEnding {
+        unsafe { mem::transmute(Fl_Text_Buffer_line_ending(self._inner)) }
+    }
+
+    /// Sets the line ending used when saving the buffer
+    pub fn set_line_ending(&mut self, ending: LineEnding) {
+        unsafe { Fl_Text_Buffer_set_line_ending(self._inner, ending as i32) }
+    }
+
+    /// Sets a callback called when the line ending of the buffer changes,
+    /// for example to update a status bar indicator
+    pub fn set_line_ending_callback(&mut self, cb: Box<dyn FnMut(LineEnding)>) {
+        unsafe {
+            unsafe extern "C" fn shim(ending: raw::c_int, data: *mut raw::c_void) {
+                let a: *mut Box<dyn FnMut(LineEnding)> = mem::transmute(data);
+                let f: &mut (dyn FnMut(LineEnding)) = &mut **a;
+                f(mem::transmute(ending))
+            }
+            let a: *mut Box<dyn FnMut(LineEnding)> = Box::into_raw(Box::new(cb));
+            let data: *mut raw::c_void = mem::transmute(a);
+            let callback: Fl_Text_Buffer_Line_Ending_Cb = Some(shim);
+            let old = Fl_Text_Buffer_set_line_ending_callback(self._inner, callback, data);
+            if !old.is_null() {
+                let _ = Box::from_raw(old as *mut Box<dyn FnMut(LineEnding)>);
             }
         }
     }
 
+    /// Detects the line ending of the text, converting it to LF
+    fn normalize_line_endings(&mut self) {
+        let text = self.text();
+        let ending = match text.find('\r') {
+            Some(idx) if text[idx + 1..].starts_with('\n') => LineEnding::CrLf,
+            Some(_) => LineEnding::Cr,
+            None => LineEnding::Lf,
+        };
+        if ending != LineEnding::Lf {
+            self.set_text(&text.replace("\r\n", "\n").replace('\r', "\n"));
+        }
+        self.set_line_ending(ending);
+    }
+
+    /// Returns the text with LF converted to the buffer's line ending
+    fn text_with_line_endings(&self) -> String {
+        let text = self.text();
+        match self.line_ending() {
+            LineEnding::Lf => text,
+            LineEnding::CrLf => text.replace('\n', "\r\n"),
+            LineEnding::Cr => text.replace('\n', "\r"),
+        }
+    }
+
+    /// Saves the buffer into a file using the given encoding,
+    /// fails if the text can't be represented in that encoding
+    pub fn save_file_with_encoding(
+        &mut self,
+        path: &std::path::Path,
+        encoding: Encoding,
+    ) -> Result<(), FltkError> {
+        match encoding.encode(&self.text_with_line_endings()) {
+            Some(bytes) => Ok(std::fs::write(path, bytes)?),
+            None => Err(FltkError::Internal(FltkErrorKind::FailedOperation)),
+        }
+    }
+
+    /// Saves the buffer into a temporary file which then replaces the file at path,
+    /// so that a crash mid-write doesn't corrupt the previous file
+    pub fn save_file_atomic(
+        &mut self,
+        path: &std::path::Path,
+        policy: BackupPolicy,
+    ) -> Result<(), FltkError> {
+        let name = match path.file_name() {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => return Err(FltkError::Internal(FltkErrorKind::ResourceNotFound)),
+        };
+        let tmp = path.with_file_name(format!(".{}.{}.tmp", name, std::process::id()));
+        let text = self.text_with_line_endings();
+        let saved = std::fs::File::create(&tmp)
+            .and_then(|mut file| {
+                file.write_all(text.as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|_| {
+                if policy == BackupPolicy::KeepBackup && path.exists() {
+                    std::fs::copy(path, path.with_file_name(format!("{}.bak", name)))?;
+                }
+                std::fs::rename(&tmp, path)
+            });
+        if let Err(e) = saved {
+            let _ = std::fs::remove_file(&tmp);
+            return Err(e.into());
+        }
+        // The rename is only durable once the directory is synced,
+        // which Windows doesn't support
+        #[cfg(unix)]
+        {
+            let dir = match path.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir,
+                _ => std::path::Path::new("."),
+            };
+            std::fs::File::open(dir)?.sync_all()?;
+        }
+        Ok(())
+    }
+
     /// Returns the tab distance for the buffer
     pub fn tab_distance(&self) -> u32 {
         unsafe { Fl_Text_Buffer_tab_distance(self._inner) as u32 }
@@ -202,7 +462,7 @@ impl TextBuffer {
         unsafe { Fl_Text_Buffer_set_tab_distance(self._inner, tab_dist as i32) }
     }
 
-    /// Selects the text from start to end
+    /// Selects the text from start to end, displays draw the selection using their selection color
     pub fn select(&mut self, start: u32, end: u32) {
         debug_assert!(
             start <= std::i32::MAX as u32,
@@ -212,7 +472,8 @@ impl TextBuffer {
             end <= std::i32::MAX as u32,
             "u32 entries must be < std::i32::MAX for compatibility!"
         );
-        unsafe { Fl_Text_Buffer_select(self._inner, start as i32, end as i32) }
+        let (start, end) = (self.char_boundary(start), self.char_boundary(end));
+        unsafe { Fl_Text_Buffer_select(self._inner, start, end) }
     }
 
     /// Returns whether text is selected
@@ -230,30 +491,62 @@ impl TextBuffer {
         unsafe { Fl_Text_Buffer_unselect(self._inner) }
     }
 
+    /// Returns the start and end of the primary selection, or None if nothing is selected
+    pub fn selection_range(&self) -> Option<(u32, u32)> {
+        let mut start: raw::c_int = 0;
+        let mut end: raw::c_int = 0;
+        let ret = unsafe { Fl_Text_Buffer_selection_position(self._inner, &mut start, &mut end) };
+        Self::range_of(ret, start, end)
+    }
+
     /// Returns the selection position
+    #[deprecated(note = "use selection_range instead")]
     pub fn selection_position(&mut self) -> Option<(u32, u32)> {
-        unsafe {
-            let start: *mut raw::c_int = std::ptr::null_mut();
-            let end: *mut raw::c_int = std::ptr::null_mut();
-            let ret = Fl_Text_Buffer_selection_position(self._inner, start, end);
-            if ret != 0 {
-                let x = (*start as u32, *end as u32);
-                Some(x)
-            } else {
-                None
-            }
+        self.selection_range()
+    }
+
+    /// Selects text as the secondary selection, displays draw it using DisplayExt::secondary_selection_color
+    pub fn secondary_select(&mut self, start: u32, end: u32) {
+        debug_assert!(
+            start <= std::i32::MAX as u32,
+            "u32 entries must be < std::i32::MAX for compatibility!"
+        );
+        debug_assert!(
+            end <= std::i32::MAX as u32,
+            "u32 entries must be < std::i32::MAX for compatibility!"
+        );
+        let (start, end) = (self.char_boundary(start), self.char_boundary(end));
+        unsafe { Fl_Text_Buffer_secondary_select(self._inner, start, end) }
+    }
+
+    /// Unselects the secondary selection
+    pub fn secondary_unselect(&mut self) {
+        unsafe { Fl_Text_Buffer_secondary_unselect(self._inner) }
+    }
+
+    /// Returns the start and end of the secondary selection, or None if there is none
+    pub fn secondary_selection_range(&self) -> Option<(u32, u32)> {
+        let mut start: raw::c_int = 0;
+        let mut end: raw::c_int = 0;
+        let ret = unsafe {
+            Fl_Text_Buffer_secondary_selection_position(self._inner, &mut start, &mut end)
+        };
+        Self::range_of(ret, start, end)
+    }
+
+    fn range_of(ret: raw::c_int, start: raw::c_int, end: raw::c_int) -> Option<(u32, u32)> {
+        if ret != 0 && start >= 0 && end >= start {
+            Some((start as u32, end as u32))
+        } else {
+            None
         }
     }
 
     /// Returns the selection text
-    pub fn selection_text(&mut self) -> String {
-        unsafe {
-            let x = Fl_Text_Buffer_selection_text(self._inner);
-            assert!(!x.is_null());
-            CString::from_raw(x as *mut raw::c_char)
-                .to_string_lossy()
-                .to_string()
-        }
+    pub fn selection_text(&self) -> String {
+        self.selection_range()
+            .and_then(|(start, end)| self.text_range(start, end))
+            .unwrap_or_default()
     }
 
     /// Removes the selection
@@ -267,7 +560,7 @@ impl TextBuffer {
         unsafe { Fl_Text_Buffer_replace_selection(self._inner, text.as_ptr()) }
     }
 
-    /// Highlights selection
+    /// Highlights selection, displays draw highlights using DisplayExt::highlight_color
     pub fn highlight(&mut self, start: u32, end: u32) {
         debug_assert!(
             start <= std::i32::MAX as u32,
@@ -277,7 +570,8 @@ impl TextBuffer {
             end <= std::i32::MAX as u32,
             "u32 entries must be < std::i32::MAX for compatibility!"
         );
-        unsafe { Fl_Text_Buffer_highlight(self._inner, start as i32, end as i32) }
+        let (start, end) = (self.char_boundary(start), self.char_boundary(end));
+        unsafe { Fl_Text_Buffer_highlight(self._inner, start, end) }
     }
 
     /// Returns whether text is highlighted
@@ -295,45 +589,40 @@ impl TextBuffer {
         unsafe { Fl_Text_Buffer_unhighlight(self._inner) }
     }
 
+    /// Returns the start and end of the highlight, or None if nothing is highlighted
+    pub fn highlight_range(&self) -> Option<(u32, u32)> {
+        let mut start: raw::c_int = 0;
+        let mut end: raw::c_int = 0;
+        let ret = unsafe { Fl_Text_Buffer_highlight_position(self._inner, &mut start, &mut end) };
+        Self::range_of(ret, start, end)
+    }
+
     /// Returns the highlight position
+    #[deprecated(note = "use highlight_range instead")]
     pub fn highlight_position(&mut self) -> Option<(u32, u32)> {
-        unsafe {
-            let start: *mut raw::c_int = std::ptr::null_mut();
-            let end: *mut raw::c_int = std::ptr::null_mut();
-            let ret = Fl_Text_Buffer_highlight_position(self._inner, start, end);
-            if ret != 0 {
-                let x = (*start as u32, *end as u32);
-                Some(x)
-            } else {
-                None
-            }
-        }
+        self.highlight_range()
     }
 
     /// Returns the highlighted text
-    pub fn highlight_text(&mut self) -> String {
-        unsafe {
-            let x = Fl_Text_Buffer_highlight_text(self._inner);
-            assert!(!x.is_null());
-            CString::from_raw(x as *mut raw::c_char)
-                .to_string_lossy()
-                .to_string()
-        }
+    pub fn highlight_text(&self) -> String {
+        self.highlight_range()
+            .and_then(|(start, end)| self.text_range(start, end))
+            .unwrap_or_default()
     }
 
     /// Returns the line at pos
     pub fn line_text(&self, pos: u32) -> String {
+        self.line_text_slice(pos).into_owned()
+    }
+
+    /// Returns the text of the line at pos, replacing invalid UTF-8. Borrowed like text_slice()
+    pub fn line_text_slice(&self, pos: u32) -> Cow<'_, str> {
         debug_assert!(
             pos <= std::i32::MAX as u32,
             "u32 entries must be < std::i32::MAX for compatibility!"
         );
-        unsafe {
-            let x = Fl_Text_Buffer_line_text(self._inner, pos as i32);
-            assert!(!x.is_null());
-            CString::from_raw(x as *mut raw::c_char)
-                .to_string_lossy()
-                .to_string()
-        }
+        self.text_range_slice(self.line_start(pos), self.line_end(pos))
+            .unwrap_or_default()
     }
 
     /// Returns the index of the line's start position at pos
@@ -345,6 +634,28 @@ impl TextBuffer {
         unsafe { Fl_Text_Buffer_line_start(self._inner, pos as i32) as u32 }
     }
 
+    /// Returns the index of the line's end position at pos, before its newline
+    pub fn line_end(&self, pos: u32) -> u32 {
+        debug_assert!(
+            pos <= std::i32::MAX as u32,
+            "u32 entries must be < std::i32::MAX for compatibility!"
+        );
+        unsafe { Fl_Text_Buffer_line_end(self._inner, pos as i32) as u32 }
+    }
+
+    /// Returns the start position of the line found after skipping lines from start
+    pub fn skip_lines(&mut self, start: u32, lines: u32) -> u32 {
+        debug_assert!(
+            start <= std::i32::MAX as u32,
+            "u32 entries must be < std::i32::MAX for compatibility!"
+        );
+        debug_assert!(
+            lines <= std::i32::MAX as u32,
+            "u32 entries must be < std::i32::MAX for compatibility!"
+        );
+        unsafe { Fl_Text_Buffer_skip_lines(self._inner, start as i32, lines as i32) as u32 }
+    }
+
     /// Returns the index of the first character of a word at pos
     pub fn word_start(&self, pos: u32) -> u32 {
         debug_assert!(
@@ -376,6 +687,198 @@ impl TextBuffer {
         unsafe { Fl_Text_Buffer_count_lines(self._inner, start as i32, end as i32) as u32 }
     }
 
+    /// Returns the number of characters in the buffer, as opposed to length() which counts bytes
+    pub fn char_count(&self) -> u32 {
+        unsafe { Fl_Text_Buffer_char_count(self._inner) as u32 }
+    }
+
+    /// Converts a character index into a byte position, indices past the end map to the buffer's length
+    pub fn char_to_byte(&self, idx: u32) -> u32 {
+        debug_assert!(
+            idx <= std::i32::MAX as u32,
+            "u32 entries must be < std::i32::MAX for compatibility!"
+        );
+        unsafe { Fl_Text_Buffer_char_to_byte(self._inner, idx as i32) as u32 }
+    }
+
+    /// Converts a byte position into the index of the character containing it,
+    /// positions within a multi-byte character give that character and positions past the end give the char count
+    pub fn byte_to_char(&self, pos: u32) -> u32 {
+        debug_assert!(
+            pos <= std::i32::MAX as u32,
+            "u32 entries must be < std::i32::MAX for compatibility!"
+        );
+        unsafe { Fl_Text_Buffer_byte_to_char(self._inner, pos as i32) as u32 }
+    }
+
+    /// Inserts text before the character at idx
+    pub fn insert_at_char(&mut self, idx: u32, text: &str) {
+        let pos = self.char_to_byte(idx);
+        self.insert(pos, text)
+    }
+
+    /// Removes the characters from index start to end
+    pub fn remove_chars(&mut self, start: u32, end: u32) {
+        let start = self.char_to_byte(start);
+        let end = self.char_to_byte(end);
+        self.remove(start, end)
+    }
+
+    /// Clamps pos to the buffer and moves it back to the start of the character it falls in,
+    /// FLTK would otherwise split multibyte characters
+    fn char_boundary(&self, pos: u32) -> i32 {
+        let len = self.length();
+        if pos >= len {
+            return len as i32;
+        }
+        unsafe { Fl_Text_Buffer_utf8_align(self._inner, pos as i32) }
+    }
+
+    /// Copies the buffer's bytes
+    #[cfg(test)]
+    fn bytes(&self) -> Vec<u8> {
+        unsafe {
+            let text = Fl_Text_Buffer_text(self._inner);
+            assert!(!text.is_null());
+            CString::from_raw(text as *mut raw::c_char).into_bytes()
+        }
+    }
+
+    /// Moves the buffer's gap to its end and borrows all of its bytes in place
+    #[cfg(feature = "regex")]
+    fn contiguous(&mut self) -> &[u8] {
+        unsafe {
+            let len = self.length() as usize;
+            let text = Fl_Text_Buffer_contiguous(self._inner);
+            if text.is_null() || len == 0 {
+                &[]
+            } else {
+                std::slice::from_raw_parts(text as *const u8, len)
+            }
+        }
+    }
+
+    /// Borrows the text before and after the buffer's gap in place,
+    /// valid until the buffer is modified
+    fn halves(&self) -> (&[u8], &[u8]) {
+        unsafe {
+            let (mut first, mut first_len) = (std::ptr::null(), 0);
+            let (mut second, mut second_len) = (std::ptr::null(), 0);
+            Fl_Text_Buffer_gap_halves(
+                self._inner,
+                &mut first,
+                &mut first_len,
+                &mut second,
+                &mut second_len,
+            );
+            let half = |ptr: *const raw::c_char, len: i32| {
+                if ptr.is_null() || len <= 0 {
+                    &[][..]
+                } else {
+                    std::slice::from_raw_parts(ptr as *const u8, len as usize)
+                }
+            };
+            (half(first, first_len), half(second, second_len))
+        }
+    }
+
+    /// Borrows the bytes between start and end in place, unless the gap of the buffer
+    /// splits them. The range has to be within the buffer
+    fn range_bytes(&self, start: usize, end: usize) -> Cow<[u8]> {
+        let (first, second) = self.halves();
+        let split = first.len();
+        if end <= split {
+            Cow::Borrowed(&first[start..end])
+        } else if start >= split {
+            Cow::Borrowed(&second[start - split..end - split])
+        } else {
+            let mut bytes = Vec::with_capacity(end - start);
+            bytes.extend_from_slice(&first[start..]);
+            bytes.extend_from_slice(&second[..end - split]);
+            Cow::Owned(bytes)
+        }
+    }
+
+    /// Returns the position of the first newline at or after pos
+    fn find_newline(&self, pos: usize) -> Option<usize> {
+        let (first, second) = self.halves();
+        let split = first.len();
+        if pos < split {
+            if let Some(idx) = first[pos..].iter().position(|&c| c == b'\n') {
+                return Some(pos + idx);
+            }
+        }
+        let from = pos.saturating_sub(split);
+        second[from..]
+            .iter()
+            .position(|&c| c == b'\n')
+            .map(|idx| split + from + idx)
+    }
+
+    /// Returns a lazy iterator over the lines of the buffer, yielding each line's byte range,
+    /// excluding the newline, along with its text. Lines are borrowed from the buffer
+    /// unless its gap splits them or they aren't valid UTF-8. The buffer shouldn't be
+    /// modified through another handle while the iterator or its lines are alive
+    pub fn lines(&self) -> Lines {
+        Lines { buf: self, pos: 0 }
+    }
+
+    /// Returns a lazy iterator over the words of the buffer, runs of alphanumeric characters
+    /// and underscores, yielded with their byte range. Words are borrowed like lines are,
+    /// see lines(), invalid UTF-8 ending the iteration
+    pub fn words(&self) -> Words {
+        Words {
+            lines: self.lines(),
+            line: None,
+        }
+    }
+
+    /// Searches forward from pos for a match of the regex pattern, returning its byte range.
+    /// The text is searched in place, without copying it. Requires the regex feature
+    #[cfg(feature = "regex")]
+    pub fn search_regex_forward(
+        &mut self,
+        pos: u32,
+        pattern: &str,
+    ) -> Result<Option<(u32, u32)>, FltkError> {
+        let re =
+            regex::bytes::Regex::new(pattern).map_err(|e| FltkError::Unknown(e.to_string()))?;
+        let text = self.contiguous();
+        let pos = std::cmp::min(pos as usize, text.len());
+        Ok(re
+            .find_at(&text, pos)
+            .map(|m| (m.start() as u32, m.end() as u32)))
+    }
+
+    /// Searches backward from pos for the closest match of the regex pattern starting at or
+    /// before pos, returning its byte range. The text is searched in place, without copying it.
+    /// Requires the regex feature
+    #[cfg(feature = "regex")]
+    pub fn search_regex_backward(
+        &mut self,
+        pos: u32,
+        pattern: &str,
+    ) -> Result<Option<(u32, u32)>, FltkError> {
+        let re =
+            regex::bytes::Regex::new(pattern).map_err(|e| FltkError::Unknown(e.to_string()))?;
+        let text = self.contiguous();
+        let pos = std::cmp::min(pos as usize, text.len());
+        let mut found = None;
+        let mut start = 0;
+        // Matches may overlap, so each search restarts one character after the previous match
+        while let Some(m) = re.find_at(&text, start) {
+            if m.start() > pos {
+                break;
+            }
+            found = Some((m.start() as u32, m.end() as u32));
+            start = m.start() + 1;
+            while start < text.len() && (text[start] & 0xC0) == 0x80 {
+                start += 1;
+            }
+        }
+        Ok(found)
+    }
+
     /// Calls the modify callbacks
     pub fn call_modify_callbacks(&mut self) {
         unsafe { Fl_Text_Buffer_call_modify_callbacks(self._inner) }
@@ -383,33 +886,26 @@ impl TextBuffer {
 
     /// Adds a modify callback
     pub fn add_modify_callback(&mut self, cb: Box<dyn FnMut(u32, u32, u32, u32, &str)>) {
+        self.add_removable_modify_callback(cb);
+    }
+
+    /// Adds a modify callback, returning the data to pass to remove_modify_callback_data
+    pub(crate) fn add_removable_modify_callback(
+        &mut self,
+        cb: Box<dyn FnMut(u32, u32, u32, u32, &str)>,
+    ) -> *mut raw::c_void {
+        let data = Box::into_raw(Box::new(cb)) as *mut raw::c_void;
+        unsafe { Fl_Text_Buffer_add_modify_callback(self._inner, Some(modify_shim), data) }
+        data
+    }
+
+    /// Removes a modify callback added by add_removable_modify_callback, it's dropped
+    /// once the event being handled is done since it might be the one running
+    pub(crate) fn remove_modify_callback_data(&mut self, data: *mut raw::c_void) {
+        crate::widget::register_data_deleter();
         unsafe {
-            unsafe extern "C" fn shim(
-                pos: raw::c_int,
-                inserted: raw::c_int,
-                deleted: raw::c_int,
-                restyled: raw::c_int,
-                deleted_text: *const raw::c_char,
-                data: *mut raw::c_void,
-            ) {
-                let mut temp = String::from("");
-                if !deleted_text.is_null() {
-                    temp = CStr::from_ptr(deleted_text).to_string_lossy().to_string();
-                }
-                let a: *mut Box<dyn FnMut(u32, u32, u32, u32, &str)> = mem::transmute(data);
-                let f: &mut (dyn FnMut(u32, u32, u32, u32, &str)) = &mut **a;
-                f(
-                    pos as u32,
-                    inserted as u32,
-                    deleted as u32,
-                    restyled as u32,
-                    &temp,
-                )
-            }
-            let a: *mut Box<dyn FnMut(u32, u32, u32, u32, &str)> = Box::into_raw(Box::new(cb));
-            let data: *mut raw::c_void = mem::transmute(a);
-            let callback: Fl_Text_Modify_Cb = Some(shim);
-            Fl_Text_Buffer_add_modify_callback(self._inner, callback, data);
+            Fl_Text_Buffer_remove_modify_callback(self._inner, Some(modify_shim), data);
+            fltk_sys::widget::Fl_Widget_free_data(6, data);
         }
     }
 
@@ -424,10 +920,11 @@ impl TextBuffer {
                 deleted_text: *const raw::c_char,
                 data: *mut raw::c_void,
             ) {
-                let mut temp = String::from("");
-                if !deleted_text.is_null() {
-                    temp = CStr::from_ptr(deleted_text).to_string_lossy().to_string();
-                }
+                let temp = if deleted_text.is_null() {
+                    Cow::from("")
+                } else {
+                    CStr::from_ptr(deleted_text).to_string_lossy()
+                };
                 let a: *mut Box<dyn FnMut(u32, u32, u32, u32, &str)> = mem::transmute(data);
                 let f: &mut (dyn FnMut(u32, u32, u32, u32, &str)) = &mut **a;
                 f(
@@ -446,49 +943,397 @@ impl TextBuffer {
     }
 }
 
-unsafe impl Sync for TextBuffer {}
-unsafe impl Send for TextBuffer {}
-
-impl Clone for TextBuffer {
-    fn clone(&self) -> TextBuffer {
-        let mut temp = TextBuffer::default();
-        temp.copy(self, 0, 0, self.length());
-        temp
-    }
+thread_local! {
+    static MODIFY_CALLBACKS_SUPPRESSED: std::cell::Cell<bool> = std::cell::Cell::new(false);
 }
 
-// impl Drop for TextBuffer {
-//     fn drop(&mut self) {
-//         unsafe { Fl_Text_Buffer_delete(self._inner) }
-//     }
-// }
+/// The range of a buffer changed by a group of edits, with the text it held before them
+struct EditedRange {
+    start: usize,
+    end: usize,
+    before: Vec<u8>,
+}
 
-/// Creates a non-editable text display widget
-#[derive(WidgetExt, DisplayExt, Debug)]
-pub struct TextDisplay {
-    _inner: *mut Fl_Text_Display,
+/// Tracks the range changed by the edits of TextBuffer::with_undo_group,
+/// the range is an error if the deleted text of an edit wasn't valid UTF-8
+#[derive(Default)]
+struct UndoGroup {
+    range: Option<Result<EditedRange, ()>>,
 }
 
-/// Creates an editable text display widget
-#[derive(WidgetExt, DisplayExt, Debug)]
-pub struct TextEditor {
-    _inner: *mut Fl_Text_Editor,
+impl UndoGroup {
+    fn track(
+        &mut self,
+        buf: &TextBuffer,
+        pos: usize,
+        inserted: usize,
+        deleted: usize,
+        deleted_text: &[u8],
+    ) {
+        if (inserted == 0 && deleted == 0) || matches!(self.range, Some(Err(()))) {
+            return;
+        }
+        if deleted_text.len() != deleted {
+            self.range = Some(Err(()));
+            return;
+        }
+        // The text outside of the range and of this edit is unchanged,
+        // the text following the edit having moved by its length
+        let original = |from: usize, to: usize| {
+            let mut text = Vec::with_capacity(to.saturating_sub(from));
+            let mut x = from;
+            while x < to {
+                let end = if x < pos {
+                    let end = to.min(pos);
+                    text.extend_from_slice(&buf.range_bytes(x, end));
+                    end
+                } else if x < pos + deleted {
+                    let end = to.min(pos + deleted);
+                    text.extend_from_slice(&deleted_text[x - pos..end - pos]);
+                    end
+                } else {
+                    let shift = |p: usize| p - deleted + inserted;
+                    text.extend_from_slice(&buf.range_bytes(shift(x), shift(to)));
+                    to
+                };
+                x = end;
+            }
+            text
+        };
+        let range = match self.range.take() {
+            Some(Ok(range)) => {
+                let start = range.start.min(pos);
+                let end = range.end.max(pos + deleted);
+                let mut before = original(start, range.start);
+                before.extend_from_slice(&range.before);
+                before.extend(original(range.end, end));
+                EditedRange {
+                    start,
+                    end: end - deleted + inserted,
+                    before,
+                }
+            }
+            _ => EditedRange {
+                start: pos,
+                end: pos + inserted,
+                before: deleted_text.to_vec(),
+            },
+        };
+        self.range = Some(Ok(range));
+    }
 }
 
-/// Creates an editable text display widget
-#[derive(WidgetExt, DisplayExt, Debug)]
-pub struct SimpleTerminal {
-    _inner: *mut Fl_Simple_Terminal,
+unsafe extern "C" fn modify_shim(
+    pos: raw::c_int,
+    inserted: raw::c_int,
+    deleted: raw::c_int,
+    restyled: raw::c_int,
+    deleted_text: *const raw::c_char,
+    data: *mut raw::c_void,
+) {
+    // Set while with_undo_group replays edits the callbacks already saw
+    if MODIFY_CALLBACKS_SUPPRESSED.with(|s| s.get()) {
+        return;
+    }
+    let temp = if deleted_text.is_null() {
+        Cow::from("")
+    } else {
+        CStr::from_ptr(deleted_text).to_string_lossy()
+    };
+    let a: *mut Box<dyn FnMut(u32, u32, u32, u32, &str)> = mem::transmute(data);
+    let f: &mut (dyn FnMut(u32, u32, u32, u32, &str)) = &mut **a;
+    f(
+        pos as u32,
+        inserted as u32,
+        deleted as u32,
+        restyled as u32,
+        &temp,
+    )
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct StyleTableEntry {
-    pub color: Color,
-    pub font: Font,
-    pub size: u32,
+/// Iterator over the lines of a TextBuffer, see TextBuffer::lines
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    buf: &'a TextBuffer,
+    pos: usize,
 }
 
-impl TextEditor {
+impl<'a> Lines<'a> {
+    /// Returns the range and the bytes of the next line
+    fn next_bytes(&mut self) -> Option<(usize, usize, Cow<'a, [u8]>)> {
+        let len = self.buf.length() as usize;
+        if self.pos > len {
+            return None;
+        }
+        let start = self.pos;
+        let end = match self.buf.find_newline(start) {
+            Some(end) => end,
+            None => {
+                // A trailing newline doesn't start another line
+                if start == len && start != 0 {
+                    return None;
+                }
+                len
+            }
+        };
+        self.pos = end + 1;
+        Some((start, end, self.buf.range_bytes(start, end)))
+    }
+}
+
+/// Converts bytes to text, replacing invalid UTF-8 and keeping borrowed bytes borrowed
+fn lossy(bytes: Cow<[u8]>) -> Cow<str> {
+    match bytes {
+        Cow::Borrowed(bytes) => String::from_utf8_lossy(bytes),
+        Cow::Owned(bytes) => match String::from_utf8(bytes) {
+            Ok(text) => Cow::Owned(text),
+            Err(e) => Cow::Owned(String::from_utf8_lossy(e.as_bytes()).into_owned()),
+        },
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = (std::ops::Range<u32>, Cow<'a, str>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end, bytes) = self.next_bytes()?;
+        Some((start as u32..end as u32, lossy(bytes)))
+    }
+}
+
+/// Iterator over the words of a TextBuffer, see TextBuffer::words
+#[derive(Debug, Clone)]
+pub struct Words<'a> {
+    lines: Lines<'a>,
+    // The start and the remaining valid text of the current line
+    line: Option<(usize, Cow<'a, str>)>,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = (std::ops::Range<u32>, Cow<'a, str>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_word = |c: char| c.is_alphanumeric() || c == '_';
+        loop {
+            if let Some((pos, rest)) = self.line.take() {
+                if let Some(start) = rest.find(is_word) {
+                    let len = rest[start..]
+                        .find(|c: char| !is_word(c))
+                        .unwrap_or(rest.len() - start);
+                    let end = start + len;
+                    let (word, rest) = match rest {
+                        Cow::Borrowed(rest) => {
+                            (Cow::Borrowed(&rest[start..end]), Cow::Borrowed(&rest[end..]))
+                        }
+                        Cow::Owned(rest) => (
+                            Cow::Owned(rest[start..end].to_string()),
+                            Cow::Owned(rest[end..].to_string()),
+                        ),
+                    };
+                    self.line = Some((pos + end, rest));
+                    return Some(((pos + start) as u32..(pos + end) as u32, word));
+                }
+            }
+            let (start, _, bytes) = self.lines.next_bytes()?;
+            // Invalid UTF-8 ends the iteration at the last valid character
+            let valid = match std::str::from_utf8(&bytes) {
+                Ok(_) => bytes.len(),
+                Err(e) => {
+                    self.lines.pos = usize::MAX;
+                    e.valid_up_to()
+                }
+            };
+            let text = match bytes {
+                Cow::Borrowed(bytes) => {
+                    Cow::Borrowed(std::str::from_utf8(&bytes[..valid]).unwrap_or_default())
+                }
+                Cow::Owned(mut bytes) => {
+                    bytes.truncate(valid);
+                    Cow::Owned(String::from_utf8(bytes).unwrap_or_default())
+                }
+            };
+            self.line = Some((start, text));
+        }
+    }
+}
+
+/// Defines the line endings of a text file
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LineEnding {
+    Lf = 0,
+    CrLf = 1,
+    Cr = 2,
+}
+
+/// Defines the text encodings supported when loading and saving files
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Encoding {
+    Utf8,
+    Latin1,
+    Utf16Le,
+    Utf16Be,
+}
+
+impl Encoding {
+    /// Guesses the encoding of raw file contents using the byte order mark,
+    /// falling back to Latin1 for content which isn't valid UTF-8
+    pub fn detect(bytes: &[u8]) -> Encoding {
+        if bytes.starts_with(&[0xFF, 0xFE]) {
+            Encoding::Utf16Le
+        } else if bytes.starts_with(&[0xFE, 0xFF]) {
+            Encoding::Utf16Be
+        } else if std::str::from_utf8(bytes).is_ok() {
+            Encoding::Utf8
+        } else {
+            Encoding::Latin1
+        }
+    }
+
+    /// Decodes bytes into a string, replacing invalid sequences and dropping any byte order mark
+    pub fn decode(self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Utf8 => {
+                let bytes = if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+                    &bytes[3..]
+                } else {
+                    bytes
+                };
+                String::from_utf8_lossy(bytes).to_string()
+            }
+            Encoding::Latin1 => bytes.iter().map(|&b| b as char).collect(),
+            Encoding::Utf16Le | Encoding::Utf16Be => {
+                let units: Vec<u16> = bytes
+                    .chunks(2)
+                    .filter(|c| c.len() == 2)
+                    .map(|c| match self {
+                        Encoding::Utf16Le => u16::from_le_bytes([c[0], c[1]]),
+                        _ => u16::from_be_bytes([c[0], c[1]]),
+                    })
+                    .collect();
+                let units = if units.first() == Some(&0xFEFF) {
+                    &units[1..]
+                } else {
+                    &units[..]
+                };
+                String::from_utf16_lossy(units)
+            }
+        }
+    }
+
+    /// Encodes a string, UTF-16 output starts with a byte order mark.
+    /// Returns None if the string contains characters not representable in the encoding
+    pub fn encode(self, text: &str) -> Option<Vec<u8>> {
+        match self {
+            Encoding::Utf8 => Some(text.as_bytes().to_vec()),
+            Encoding::Latin1 => text
+                .chars()
+                .map(|c| {
+                    if (c as u32) < 256 {
+                        Some(c as u8)
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+            Encoding::Utf16Le | Encoding::Utf16Be => {
+                let mut bytes = Vec::with_capacity(text.len() * 2 + 2);
+                for unit in std::iter::once(0xFEFF).chain(text.encode_utf16()) {
+                    match self {
+                        Encoding::Utf16Le => bytes.extend_from_slice(&unit.to_le_bytes()),
+                        _ => bytes.extend_from_slice(&unit.to_be_bytes()),
+                    }
+                }
+                Some(bytes)
+            }
+        }
+    }
+}
+
+/// Defines whether the previous version of a file is kept when saving atomically
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BackupPolicy {
+    /// The previous file is replaced
+    NoBackup,
+    /// The previous file is kept next to the new one with a .bak extension
+    KeepBackup,
+}
+
+unsafe impl Sync for TextBuffer {}
+unsafe impl Send for TextBuffer {}
+
+impl Clone for TextBuffer {
+    fn clone(&self) -> TextBuffer {
+        let mut temp = TextBuffer::default();
+        temp.copy(self, 0, 0, self.length());
+        temp
+    }
+}
+
+impl Drop for TextBuffer {
+    fn drop(&mut self) {
+        unsafe { self.delete() }
+    }
+}
+
+/// Creates a non-editable text display widget
+#[derive(WidgetExt, DisplayExt, Debug)]
+pub struct TextDisplay {
+    _inner: *mut Fl_Text_Display,
+}
+
+/// Creates an editable text display widget
+#[derive(WidgetExt, DisplayExt, Debug)]
+pub struct TextEditor {
+    _inner: *mut Fl_Text_Editor,
+}
+
+/// Creates an editable text display widget
+#[derive(WidgetExt, DisplayExt, Debug)]
+pub struct SimpleTerminal {
+    _inner: *mut Fl_Simple_Terminal,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StyleTableEntry {
+    pub color: Color,
+    pub font: Font,
+    pub size: u32,
+}
+
+/// Defines the text attributes of a StyleTableEntryExt
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextAttr {
+    None = 0x0000,
+    /// Uses the entry's background color
+    BgColor = 0x0001,
+    /// Uses the entry's background color, extending it to the right edge
+    BgColorExt = 0x0003,
+    Underline = 0x0004,
+    /// A squiggly underline used for grammar errors
+    Grammar = 0x0008,
+    /// A squiggly underline used for spelling errors
+    Spelling = 0x000C,
+    StrikeThrough = 0x0010,
+}
+
+impl std::ops::BitOr<TextAttr> for TextAttr {
+    type Output = TextAttr;
+    fn bitor(self, rhs: TextAttr) -> Self::Output {
+        unsafe { std::mem::transmute(self as u32 | rhs as u32) }
+    }
+}
+
+/// A style table entry supporting a background color and text attributes
+#[derive(Debug, Clone, Copy)]
+pub struct StyleTableEntryExt {
+    pub color: Color,
+    pub font: Font,
+    pub size: u32,
+    pub attr: TextAttr,
+    pub bgcolor: Color,
+}
+
+impl TextEditor {
     /// Create an new TextEditor widget
     pub fn new(x: i32, y: i32, w: i32, h: i32, buf: &mut TextBuffer) -> TextEditor {
         let temp = CString::new("").unwrap();
@@ -544,6 +1389,300 @@ impl TextEditor {
             kf_undo(self._inner);
         }
     }
+
+    /// Binds a key combination to a callback, taking precedence over the default bindings
+    /// and replacing any callback bound to it. The callback receives the pressed key
+    /// and returns whether it handled it
+    pub fn add_key_binding(
+        &mut self,
+        key: Key,
+        shortcut: Shortcut,
+        cb: Box<dyn FnMut(Key) -> bool>,
+    ) {
+        crate::widget::register_data_deleter();
+        unsafe {
+            unsafe extern "C" fn shim(
+                key: raw::c_int,
+                _e: *mut Fl_Text_Editor,
+                data: *mut raw::c_void,
+            ) -> raw::c_int {
+                let a: *mut Box<dyn FnMut(Key) -> bool> = mem::transmute(data);
+                let f: &mut (dyn FnMut(Key) -> bool) = &mut **a;
+                f(Key::from_i32(key)) as raw::c_int
+            }
+            let a: *mut Box<dyn FnMut(Key) -> bool> = Box::into_raw(Box::new(cb));
+            let data: *mut raw::c_void = mem::transmute(a);
+            let callback: Fl_Text_Editor_Key_Func = Some(shim);
+            let prev = Fl_Text_Editor_add_key_binding(
+                self._inner,
+                key.bits(),
+                shortcut.bits(),
+                callback,
+                data,
+            );
+            if !prev.is_null() {
+                let _ = Box::from_raw(prev as *mut Box<dyn FnMut(Key) -> bool>);
+            }
+        }
+    }
+
+    /// Removes a key binding added using add_key_binding, restoring FLTK's default binding
+    /// of the key if it has one
+    pub fn remove_key_binding(&mut self, key: Key, shortcut: Shortcut) {
+        unsafe {
+            let data = Fl_Text_Editor_remove_key_binding(self._inner, key.bits(), shortcut.bits());
+            if !data.is_null() {
+                let _ = Box::from_raw(data as *mut Box<dyn FnMut(Key) -> bool>);
+            }
+        }
+    }
+
+    /// Marks the text from start to end as read-only, key presses and pastes editing it are
+    /// rejected before the editor handles them, and undo is disabled while ranges are
+    /// protected. Edits made through the buffer aren't rejected.
+    /// Protected ranges follow the text as it moves and apply to the current buffer only
+    pub fn protect_range(&mut self, start: u32, end: u32) {
+        debug_assert!(
+            start <= std::i32::MAX as u32,
+            "u32 entries must be < std::i32::MAX for compatibility!"
+        );
+        debug_assert!(
+            end <= std::i32::MAX as u32,
+            "u32 entries must be < std::i32::MAX for compatibility!"
+        );
+        unsafe { Fl_Text_Editor_protect_range(self._inner, start as i32, end as i32) }
+        let ptr = self._inner;
+        crate::widget::intercept_events(
+            unsafe { self.as_widget_ptr() },
+            "protections",
+            Box::new(move |ev| match ev {
+                Event::KeyDown | Event::Paste => unsafe {
+                    Fl_Text_Editor_edits_protected(ptr, ev as i32) != 0
+                },
+                _ => false,
+            }),
+        );
+    }
+
+    /// Removes all read-only ranges set using protect_range
+    pub fn clear_protections(&mut self) {
+        unsafe {
+            Fl_Text_Editor_clear_protections(self._inner);
+            crate::widget::remove_interceptor(self.as_widget_ptr(), "protections");
+        }
+    }
+
+    /// Returns whether the editor is in insert mode, otherwise it's in overstrike mode
+    pub fn insert_mode(&self) -> bool {
+        unsafe {
+            match Fl_Text_Editor_insert_mode(self._inner) {
+                0 => false,
+                _ => true,
+            }
+        }
+    }
+
+    /// Sets the insert mode, false switches to overstrike mode
+    pub fn set_insert_mode(&mut self, b: bool) {
+        unsafe { Fl_Text_Editor_set_insert_mode(self._inner, b as i32) }
+    }
+
+    /// Sets whether Tab inserts spaces up to the next tab stop, using the buffer's tab distance,
+    /// instead of a tab character. Tab then indents the selected lines and Shift+Tab unindents them
+    pub fn set_tab_inserts_spaces(&mut self, flag: bool) {
+        self.remove_key_binding(Key::Tab, Shortcut::None);
+        self.remove_key_binding(Key::Tab, Shortcut::Shift);
+        if !flag {
+            return;
+        }
+        let mut editor = self.clone();
+        self.add_key_binding(
+            Key::Tab,
+            Shortcut::None,
+            Box::new(move |_| {
+                editor.indent();
+                true
+            }),
+        );
+        let mut editor = self.clone();
+        self.add_key_binding(
+            Key::Tab,
+            Shortcut::Shift,
+            Box::new(move |_| {
+                editor.unindent();
+                true
+            }),
+        );
+    }
+
+    /// Returns the start positions of the lines touched by the selection, or of the insert
+    /// position's line. A selection ending at the start of a line doesn't include that line
+    fn indent_lines(&self, buf: &mut TextBuffer) -> Vec<u32> {
+        let (start, end) = match buf.selection_range() {
+            Some((start, end)) if end > start && end == buf.line_start(end) => (start, end - 1),
+            Some(sel) => sel,
+            None => (self.insert_position(), self.insert_position()),
+        };
+        let mut lines = vec![buf.line_start(start)];
+        let mut line_end = buf.line_end(start);
+        while line_end < end && line_end < buf.length() {
+            lines.push(line_end + 1);
+            line_end = buf.line_end(line_end + 1);
+        }
+        lines
+    }
+
+    fn indent(&mut self) {
+        let mut buf = match self.buffer() {
+            Some(buf) => buf,
+            None => return,
+        };
+        let width = std::cmp::max(buf.tab_distance(), 1);
+        let lines = self.indent_lines(&mut buf);
+        if lines.len() > 1 {
+            let spaces = " ".repeat(width as usize);
+            for line in lines.iter().rev() {
+                buf.insert(*line, &spaces);
+            }
+            let last = buf.skip_lines(lines[0], lines.len() as u32 - 1);
+            let end = buf.line_end(last);
+            buf.select(lines[0], end);
+            return;
+        }
+        buf.remove_selection();
+        let pos = self.insert_position();
+        let line_start = buf.line_start(pos);
+        let col = buf
+            .text_range_slice(line_start, pos)
+            .map_or(0, |t| t.chars().count() as u32);
+        let count = width - col % width;
+        buf.insert(pos, &" ".repeat(count as usize));
+        self.set_insert_position(pos + count);
+        self.show_insert_position();
+    }
+
+    fn unindent(&mut self) {
+        let mut buf = match self.buffer() {
+            Some(buf) => buf,
+            None => return,
+        };
+        let width = std::cmp::max(buf.tab_distance(), 1) as usize;
+        let selected = buf.selected();
+        let lines = self.indent_lines(&mut buf);
+        let mut pos = self.insert_position();
+        for line in lines.iter().rev() {
+            let line_end = buf.line_end(*line);
+            let count = match buf.text_range_slice(*line, line_end) {
+                Some(text) if text.starts_with('\t') => 1,
+                Some(text) => text.bytes().take(width).take_while(|c| *c == b' ').count(),
+                None => 0,
+            } as u32;
+            if count > 0 {
+                buf.remove(*line, *line + count);
+                if pos > *line {
+                    pos -= std::cmp::min(count, pos - *line);
+                }
+            }
+        }
+        if selected && lines.len() > 1 {
+            let last = buf.skip_lines(lines[0], lines.len() as u32 - 1);
+            let end = buf.line_end(last);
+            buf.select(lines[0], end);
+        } else {
+            self.set_insert_position(pos);
+        }
+    }
+
+    /// Sets whether Enter copies the leading whitespace of the current line to the new line.
+    /// The indent increases by one tab distance when the line ends with one of the openers,
+    /// for example "{:", pass an empty string to only keep the indent
+    pub fn set_auto_indent(&mut self, flag: bool, openers: &str) {
+        for key in &[Key::Enter, Key::KPEnter] {
+            self.remove_key_binding(*key, Shortcut::None);
+            if !flag {
+                continue;
+            }
+            let mut editor = self.clone();
+            let openers = openers.to_string();
+            self.add_key_binding(
+                *key,
+                Shortcut::None,
+                Box::new(move |_| {
+                    editor.newline_indent(&openers);
+                    true
+                }),
+            );
+        }
+    }
+
+    fn newline_indent(&mut self, openers: &str) {
+        let mut buf = match self.buffer() {
+            Some(buf) => buf,
+            None => return,
+        };
+        buf.remove_selection();
+        let pos = self.insert_position();
+        let before = buf
+            .text_range_slice(buf.line_start(pos), pos)
+            .map(Cow::into_owned)
+            .unwrap_or_default();
+        let mut indent: String = before
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .collect();
+        if let Some(last) = before.trim_end().chars().last() {
+            if openers.contains(last) {
+                if indent.starts_with('\t') {
+                    indent.push('\t');
+                } else {
+                    indent.push_str(&" ".repeat(std::cmp::max(buf.tab_distance(), 1) as usize));
+                }
+            }
+        }
+        let text = format!("\n{}", indent);
+        buf.insert(pos, &text);
+        self.set_insert_position(pos + text.len() as u32);
+        self.show_insert_position();
+    }
+
+    /// Configures drag and drop on the editor, drag_text sets whether selected text can be
+    /// dragged. on_drop receives dropped text, like the paths of dropped files,
+    /// and returns whether it consumed it, otherwise the text is inserted
+    pub fn set_dnd(&mut self, drag_text: bool, on_drop: Option<Box<dyn FnMut(&str) -> bool>>) {
+        let mut on_drop = on_drop;
+        let mut dropped = false;
+        let mut restore_ops = None;
+        crate::widget::intercept_events(
+            unsafe { self.as_widget_ptr() },
+            "dnd",
+            Box::new(move |ev| match ev {
+                Event::Push if !drag_text => {
+                    // Dragging starts on the next drag event, so the flag is only cleared for
+                    // this press
+                    restore_ops = Some(crate::app::dnd_text_ops());
+                    crate::app::set_dnd_text_ops(false);
+                    false
+                }
+                Event::Released => {
+                    if let Some(flag) = restore_ops.take() {
+                        crate::app::set_dnd_text_ops(flag);
+                    }
+                    false
+                }
+                Event::DndRelease => {
+                    dropped = true;
+                    false
+                }
+                Event::Paste if dropped => {
+                    dropped = false;
+                    let txt = crate::app::event_text();
+                    // The editor doesn't get to insert consumed text
+                    on_drop.as_mut().map_or(false, |cb| cb(&txt))
+                }
+                _ => false,
+            }),
+        );
+    }
 }
 
 impl TextDisplay {
@@ -608,10 +1747,1612 @@ impl SimpleTerminal {
             x
         }
     }
+
+    /// Returns whether ANSI escape sequences are interpreted
+    pub fn ansi(&self) -> bool {
+        unsafe {
+            match Fl_Simple_Terminal_ansi(self._inner) {
+                0 => false,
+                _ => true,
+            }
+        }
+    }
+
+    /// Enables interpreting ANSI SGR escape sequences (like "\x1b[31m") in appended text,
+    /// changing the style of the text that follows
+    pub fn set_ansi(&mut self, val: bool) {
+        unsafe { Fl_Simple_Terminal_set_ansi(self._inner, val as i32) }
+    }
+
+    /// Appends text to the terminal, parsing escape sequences if ansi is enabled
+    pub fn append(&mut self, s: &str) {
+        let s = CString::new(s).unwrap();
+        unsafe { Fl_Simple_Terminal_append(self._inner, s.as_ptr()) }
+    }
+
+    /// Appends text to the terminal with the given color, font and size.
+    /// The terminal manages its own style table, so this shouldn't be combined with
+    /// set_style_table_entry. Text appended unstyled uses the current text color, font and size.
+    /// Fails if ansi is enabled, since escape sequences select styles from the ANSI style table
+    pub fn append_styled(&mut self, s: &str, style: StyleTableEntry) -> Result<(), FltkError> {
+        debug_assert!(
+            style.size <= std::i32::MAX as u32,
+            "u32 entries must be < std::i32::MAX for compatibility!"
+        );
+        let s = CString::new(s)?;
+        unsafe {
+            match Fl_Simple_Terminal_append_styled(
+                self._inner,
+                s.as_ptr(),
+                style.color as u32,
+                style.font as i32,
+                style.size as i32,
+            ) {
+                0 => Err(FltkError::Internal(FltkErrorKind::FailedOperation)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// Returns the maximum number of lines kept in the terminal, a negative value means no limit
+    pub fn history_lines(&self) -> i32 {
+        unsafe { Fl_Simple_Terminal_history_lines(self._inner) }
+    }
+
+    /// Sets the maximum number of lines kept in the terminal,
+    /// older lines are removed as new ones are appended. A negative value disables the limit
+    pub fn set_history_lines(&mut self, lines: i32) {
+        unsafe { Fl_Simple_Terminal_set_history_lines(self._inner, lines) }
+    }
+
+    /// Returns whether the terminal scrolls to the bottom when text is appended
+    pub fn stay_at_bottom(&self) -> bool {
+        unsafe {
+            match Fl_Simple_Terminal_stay_at_bottom(self._inner) {
+                0 => false,
+                _ => true,
+            }
+        }
+    }
+
+    /// Sets whether the terminal scrolls to the bottom when text is appended
+    pub fn set_stay_at_bottom(&mut self, val: bool) {
+        unsafe { Fl_Simple_Terminal_set_stay_at_bottom(self._inner, val as i32) }
+    }
+
+    /// Enables typing commands after a prompt on the terminal's last line.
+    /// Left, Right, Home, End, BackSpace and Delete edit the line, Up and Down navigate the history.
+    /// Enter submits the line to on_command, which can append its output to the terminal
+    pub fn set_input_mode(&mut self, prompt: &str, on_command: Box<dyn FnMut(&str)>) {
+        let state = Rc::new(RefCell::new(TerminalInput {
+            prompt: prompt.to_string(),
+            input: String::new(),
+            cursor: 0,
+            history: vec![],
+            history_idx: None,
+            on_command: Some(on_command),
+        }));
+        self.show_cursor(true);
+        TerminalInput::show_prompt(&state, self);
+        let mut term = self.clone();
+        crate::widget::intercept_events(
+            unsafe { self.as_widget_ptr() },
+            "input_mode",
+            Box::new(move |ev| match ev {
+                Event::Focus | Event::Unfocus => true,
+                Event::KeyDown => TerminalInput::handle_key(&state, &mut term),
+                _ => false,
+            }),
+        );
+    }
+}
+
+/// Coalesces text appended to a display's buffer, flushing it at most once per interval so that
+/// high-frequency updates, like tailing a log, cause a single redraw per batch.
+/// Placing the display in a DoubleWindow additionally avoids flicker while it redraws
+#[derive(Clone)]
+pub struct CoalescedAppender<D: DisplayExt + Clone + 'static> {
+    state: Rc<RefCell<AppenderState<D>>>,
+}
+
+struct AppenderState<D> {
+    display: D,
+    tracker: *mut raw::c_void,
+    interval: f64,
+    pending: String,
+    scheduled: bool,
+}
+
+impl<D> Drop for AppenderState<D> {
+    fn drop(&mut self) {
+        unsafe { fltk_sys::fl::Fl_Widget_Tracker_delete(self.tracker) }
+    }
+}
+
+impl<D: DisplayExt + Clone + 'static> CoalescedAppender<D> {
+    /// Creates an appender for the display, flushing pending text every interval seconds
+    pub fn new(display: &D, interval: f64) -> CoalescedAppender<D> {
+        let ptr = display.as_widget_ptr() as *mut fltk_sys::fl::Fl_Widget;
+        let state = AppenderState {
+            display: display.clone(),
+            tracker: unsafe { fltk_sys::fl::Fl_Widget_Tracker_new(ptr) },
+            interval,
+            pending: String::new(),
+            scheduled: false,
+        };
+        CoalescedAppender {
+            state: Rc::new(RefCell::new(state)),
+        }
+    }
+
+    /// Queues text to be appended on the next flush
+    pub fn append(&mut self, txt: &str) {
+        let mut state = self.state.borrow_mut();
+        state.pending.push_str(txt);
+        if !state.scheduled {
+            state.scheduled = true;
+            let mut appender = self.clone();
+            crate::app::add_timeout(state.interval, Box::new(move || appender.flush()));
+        }
+    }
+
+    /// Appends the pending text immediately. The display follows the appended text
+    /// if its insert position was at the end of the buffer.
+    /// Pending text is dropped if the display was deleted
+    pub fn flush(&mut self) {
+        let mut state = self.state.borrow_mut();
+        state.scheduled = false;
+        if state.pending.is_empty() {
+            return;
+        }
+        let pending = mem::replace(&mut state.pending, String::new());
+        if unsafe { fltk_sys::fl::Fl_Widget_Tracker_deleted(state.tracker) } != 0 {
+            return;
+        }
+        let mut buf = match state.display.buffer() {
+            Some(buf) => buf,
+            None => return,
+        };
+        let at_end = state.display.insert_position() == buf.length();
+        buf.append(&pending);
+        if at_end {
+            state.display.set_insert_position(buf.length());
+            state.display.show_insert_position();
+        }
+    }
+}
+
+/// Holds the line being typed into a SimpleTerminal in input mode
+struct TerminalInput {
+    prompt: String,
+    input: String,
+    cursor: usize,
+    history: Vec<String>,
+    history_idx: Option<usize>,
+    on_command: Option<Box<dyn FnMut(&str)>>,
+}
+
+impl TerminalInput {
+    fn show_prompt(state: &Rc<RefCell<TerminalInput>>, term: &mut SimpleTerminal) {
+        let buf = term.buffer().unwrap();
+        let len = buf.length();
+        // The prompt always starts on a fresh line
+        if len > 0 && buf.text_range(len - 1, len).unwrap_or_default() != "\n" {
+            term.append("\n");
+        }
+        let prompt = state.borrow().prompt.clone();
+        term.append(&prompt);
+        state.borrow().update_cursor(term);
+    }
+
+    fn update_cursor(&self, term: &mut SimpleTerminal) {
+        let len = term.buffer().unwrap().length();
+        term.set_insert_position(len - (self.input.len() - self.cursor) as u32);
+        term.show_insert_position();
+    }
+
+    /// Replaces the displayed line, whose previous input was old_len bytes long
+    fn redraw_line(&self, term: &mut SimpleTerminal, old_len: usize) {
+        let mut buf = term.buffer().unwrap();
+        let len = buf.length();
+        buf.replace(len - old_len as u32, len, &self.input);
+        self.update_cursor(term);
+    }
+
+    fn set_input(&mut self, term: &mut SimpleTerminal, input: String) {
+        let old_len = self.input.len();
+        self.input = input;
+        self.cursor = self.input.len();
+        self.redraw_line(term, old_len);
+    }
+
+    fn handle_key(state: &Rc<RefCell<TerminalInput>>, term: &mut SimpleTerminal) -> bool {
+        let key = crate::app::event_key();
+        if key == Key::Enter || key == Key::KPEnter {
+            TerminalInput::submit(state, term);
+            return true;
+        }
+        let mut s = state.borrow_mut();
+        let old_len = s.input.len();
+        let prev = s.input[..s.cursor]
+            .chars()
+            .next_back()
+            .map_or(0, |c| c.len_utf8());
+        let next = s.input[s.cursor..]
+            .chars()
+            .next()
+            .map_or(0, |c| c.len_utf8());
+        match key {
+            Key::BackSpace if prev > 0 => {
+                let cursor = s.cursor - prev;
+                s.input.remove(cursor);
+                s.cursor = cursor;
+            }
+            Key::Delete if next > 0 => {
+                let cursor = s.cursor;
+                s.input.remove(cursor);
+            }
+            Key::Left => s.cursor -= prev,
+            Key::Right => s.cursor += next,
+            Key::Home => s.cursor = 0,
+            Key::End => s.cursor = s.input.len(),
+            Key::Up => {
+                if s.history.is_empty() {
+                    return true;
+                }
+                let idx = match s.history_idx {
+                    Some(0) => 0,
+                    Some(idx) => idx - 1,
+                    None => s.history.len() - 1,
+                };
+                s.history_idx = Some(idx);
+                let entry = s.history[idx].clone();
+                s.set_input(term, entry);
+                return true;
+            }
+            Key::Down => {
+                let entry = match s.history_idx {
+                    Some(idx) if idx + 1 < s.history.len() => {
+                        s.history_idx = Some(idx + 1);
+                        s.history[idx + 1].clone()
+                    }
+                    Some(_) => {
+                        s.history_idx = None;
+                        String::new()
+                    }
+                    None => return true,
+                };
+                s.set_input(term, entry);
+                return true;
+            }
+            Key::BackSpace | Key::Delete => (),
+            _ => {
+                let txt = crate::app::event_text();
+                if txt.is_empty() || txt.chars().any(char::is_control) {
+                    return false;
+                }
+                let cursor = s.cursor;
+                s.input.insert_str(cursor, &txt);
+                s.cursor += txt.len();
+            }
+        }
+        s.redraw_line(term, old_len);
+        true
+    }
+
+    fn submit(state: &Rc<RefCell<TerminalInput>>, term: &mut SimpleTerminal) {
+        let (cmd, cb) = {
+            let mut s = state.borrow_mut();
+            let cmd = mem::replace(&mut s.input, String::new());
+            s.cursor = 0;
+            if !cmd.trim().is_empty() {
+                s.history.push(cmd.clone());
+            }
+            s.history_idx = None;
+            (cmd, s.on_command.take())
+        };
+        term.append("\n");
+        if let Some(mut cb) = cb {
+            // The callback is taken out of the state since it might append to the terminal
+            cb(&cmd);
+            state.borrow_mut().on_command = Some(cb);
+        }
+        TerminalInput::show_prompt(state, term);
+    }
+}
+
+struct ChunkedLoad {
+    file: std::fs::File,
+    buf: TextBuffer,
+    chunk: Vec<u8>,
+    pending: Vec<u8>,
+    loaded: u64,
+    total: u64,
+    ending: Option<LineEnding>,
+    progress: Box<dyn FnMut(f64)>,
+    done: Box<dyn FnOnce(Result<(), FltkError>)>,
+}
+
+impl ChunkedLoad {
+    /// Appends the next chunk to the buffer, returns false once the file is fully loaded
+    fn step(&mut self) -> io::Result<bool> {
+        use std::io::Read;
+        let n = match self.file.read(&mut self.chunk) {
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => return Ok(true),
+            Err(e) => return Err(e),
+        };
+        let eof = n == 0;
+        self.loaded += n as u64;
+        self.pending.extend_from_slice(&self.chunk[..n]);
+        let mut end = self.pending.len();
+        if !eof {
+            // Keeps a split UTF-8 sequence or CRLF for the next chunk
+            end -= ChunkedLoad::incomplete_tail(&self.pending);
+            if end > 0 && self.pending[end - 1] == b'\r' {
+                end -= 1;
+            }
+        }
+        let rest = self.pending.split_off(end);
+        let text = String::from_utf8_lossy(&self.pending).replace('\0', "");
+        self.pending = rest;
+        if self.ending.is_none() {
+            if let Some(idx) = text.find('\r') {
+                self.ending = match text[idx + 1..].starts_with('\n') {
+                    true => Some(LineEnding::CrLf),
+                    false => Some(LineEnding::Cr),
+                };
+            }
+        }
+        self.buf
+            .append(&text.replace("\r\n", "\n").replace('\r', "\n"));
+        match self.total {
+            0 => (self.progress)(1.0),
+            _ => (self.progress)(self.loaded as f64 / self.total as f64),
+        }
+        if eof {
+            self.buf
+                .set_line_ending(self.ending.unwrap_or(LineEnding::Lf));
+        }
+        Ok(!eof)
+    }
+
+    /// Returns the length of an incomplete UTF-8 sequence ending the bytes
+    fn incomplete_tail(bytes: &[u8]) -> usize {
+        for i in 1..=std::cmp::min(3, bytes.len()) {
+            let b = bytes[bytes.len() - i];
+            if b & 0xC0 == 0x80 {
+                continue;
+            }
+            let len = match b {
+                0xF0..=0xFF => 4,
+                0xE0..=0xEF => 3,
+                0xC0..=0xDF => 2,
+                _ => 1,
+            };
+            return if len > i { i } else { 0 };
+        }
+        0
+    }
+}
+
+unsafe extern "C" fn load_next_chunk(data: *mut raw::c_void) {
+    let mut load = Box::from_raw(data as *mut ChunkedLoad);
+    match load.step() {
+        Ok(true) => fltk_sys::fl::Fl_add_timeout(
+            0.0,
+            Some(load_next_chunk),
+            Box::into_raw(load) as *mut raw::c_void,
+        ),
+        Ok(false) => (load.done)(Ok(())),
+        Err(e) => (load.done)(Err(e.into())),
+    }
+}
+
+struct DocumentStateInner {
+    dirty: bool,
+    on_dirty_changed: Option<Box<dyn FnMut(bool)>>,
+    watched: Option<(TextBuffer, *mut raw::c_void)>,
+}
+
+impl Drop for DocumentStateInner {
+    fn drop(&mut self) {
+        if let Some((mut buf, data)) = self.watched.take() {
+            buf.remove_modify_callback_data(data);
+        }
+    }
+}
+
+/// Tracks whether a TextBuffer was modified since it was last saved,
+/// useful for window title markers and close confirmations
+#[derive(Clone)]
+pub struct DocumentState {
+    inner: Rc<RefCell<DocumentStateInner>>,
+}
+
+impl DocumentState {
+    /// Starts tracking the modifications of a buffer, the document starts as clean.
+    /// Tracking stops once the DocumentState and its clones are dropped
+    pub fn new(buf: &mut TextBuffer) -> DocumentState {
+        let state = DocumentState {
+            inner: Rc::new(RefCell::new(DocumentStateInner {
+                dirty: false,
+                on_dirty_changed: None,
+                watched: None,
+            })),
+        };
+        let weak = Rc::downgrade(&state.inner);
+        let data = buf.add_removable_modify_callback(Box::new(move |_, inserted, deleted, _, _| {
+            if inserted > 0 || deleted > 0 {
+                if let Some(inner) = weak.upgrade() {
+                    DocumentState { inner }.set_dirty(true);
+                }
+            }
+        }));
+        let watched = unsafe { TextBuffer::from_ptr(buf.as_ptr()) };
+        state.inner.borrow_mut().watched = Some((watched, data));
+        state
+    }
+
+    /// Returns whether the buffer was modified since the last save
+    pub fn is_dirty(&self) -> bool {
+        self.inner.borrow().dirty
+    }
+
+    /// Marks the document as saved
+    pub fn mark_saved(&mut self) {
+        self.set_dirty(false);
+    }
+
+    /// Marks the document as modified
+    pub fn mark_dirty(&mut self) {
+        self.set_dirty(true);
+    }
+
+    /// Sets a callback called with the new state whenever the dirty state changes
+    pub fn on_dirty_changed(&mut self, cb: Box<dyn FnMut(bool)>) {
+        self.inner.borrow_mut().on_dirty_changed = Some(cb);
+    }
+
+    fn set_dirty(&mut self, dirty: bool) {
+        let cb = {
+            let mut inner = self.inner.borrow_mut();
+            if inner.dirty == dirty {
+                return;
+            }
+            inner.dirty = dirty;
+            inner.on_dirty_changed.take()
+        };
+        if let Some(mut cb) = cb {
+            cb(dirty);
+            let mut inner = self.inner.borrow_mut();
+            if inner.on_dirty_changed.is_none() {
+                inner.on_dirty_changed = Some(cb);
+            }
+        }
+    }
+}
+
+/// Holds the prompt, history and hooks of a Console
+struct ConsoleState {
+    prompt: String,
+    input_start: u32,
+    history: Vec<String>,
+    history_idx: Option<usize>,
+    on_command: Option<Box<dyn FnMut(&str) -> String>>,
+    on_complete: Option<Box<dyn FnMut(&str) -> Option<String>>>,
+}
+
+/// Creates a REPL console, a read-only scrollback followed by an editable prompt line.
+/// Up and Down navigate the command history, Tab calls the completion hook
+#[derive(Clone)]
+pub struct Console {
+    editor: TextEditor,
+    state: Rc<RefCell<ConsoleState>>,
+}
+
+impl Console {
+    /// Creates a new Console widget with the given prompt
+    pub fn new(x: i32, y: i32, w: i32, h: i32, prompt: &str) -> Console {
+        let mut buf = TextBuffer::default();
+        let editor = TextEditor::new(x, y, w, h, &mut buf);
+        let state = ConsoleState {
+            prompt: prompt.to_string(),
+            input_start: 0,
+            history: vec![],
+            history_idx: None,
+            on_command: None,
+            on_complete: None,
+        };
+        let mut console = Console {
+            editor,
+            state: Rc::new(RefCell::new(state)),
+        };
+        console.show_prompt();
+        console.bind_keys();
+        // The state lives as long as the editor
+        let state = console.state.clone();
+        crate::widget::on_delete(
+            unsafe { console.editor.as_widget_ptr() },
+            Box::new(move || drop(state)),
+        );
+        console
+    }
+
+    /// Creates a default and zero initialized Console
+    pub fn default(prompt: &str) -> Console {
+        Console::new(0, 0, 0, 0, prompt)
+    }
+
+    /// Returns the underlying TextEditor, useful for positioning and styling
+    pub fn editor(&self) -> TextEditor {
+        self.editor.clone()
+    }
+
+    /// Sets the callback evaluating a submitted command, its return value is printed
+    pub fn on_command(&mut self, cb: Box<dyn FnMut(&str) -> String>) {
+        self.state.borrow_mut().on_command = Some(cb);
+    }
+
+    /// Sets the tab-completion hook, which receives the current input and returns its completion
+    pub fn on_complete(&mut self, cb: Box<dyn FnMut(&str) -> Option<String>>) {
+        self.state.borrow_mut().on_complete = Some(cb);
+    }
+
+    /// Returns the text typed after the prompt
+    pub fn input(&self) -> String {
+        let buf = self.buffer();
+        let start = self.state.borrow().input_start;
+        buf.text_range(start, buf.length()).unwrap_or_default()
+    }
+
+    /// Replaces the text typed after the prompt
+    pub fn set_input(&mut self, txt: &str) {
+        let mut buf = self.buffer();
+        let start = self.state.borrow().input_start;
+        buf.replace(start, buf.length(), txt);
+        self.editor.set_insert_position(buf.length());
+        self.editor.show_insert_position();
+    }
+
+    /// Prints text to the scrollback, above the prompt line
+    pub fn print(&mut self, txt: &str) {
+        let mut buf = self.buffer();
+        let (start, prompt_len) = {
+            let state = self.state.borrow();
+            (state.input_start, state.prompt.len() as u32)
+        };
+        self.editor.clear_protections();
+        buf.insert(start - prompt_len, txt);
+        let start = start + txt.len() as u32;
+        self.state.borrow_mut().input_start = start;
+        self.editor.protect_range(0, start);
+    }
+
+    /// Returns the submitted commands, oldest first
+    pub fn history(&self) -> Vec<String> {
+        self.state.borrow().history.clone()
+    }
+
+    /// Clears the command history
+    pub fn clear_history(&mut self) {
+        let mut state = self.state.borrow_mut();
+        state.history.clear();
+        state.history_idx = None;
+    }
+
+    fn buffer(&self) -> TextBuffer {
+        self.editor.buffer().unwrap()
+    }
+
+    fn show_prompt(&mut self) {
+        let mut buf = self.buffer();
+        let prompt = self.state.borrow().prompt.clone();
+        self.editor.clear_protections();
+        buf.append(&prompt);
+        let start = buf.length();
+        self.state.borrow_mut().input_start = start;
+        self.editor.protect_range(0, start);
+        self.editor.set_insert_position(start);
+        self.editor.show_insert_position();
+    }
+
+    fn bind_keys(&mut self) {
+        self.bind_key(Key::Enter, |c| {
+            c.submit();
+            true
+        });
+        self.bind_key(Key::Up, |c| {
+            c.history_prev();
+            true
+        });
+        self.bind_key(Key::Down, |c| {
+            c.history_next();
+            true
+        });
+        self.bind_key(Key::Tab, Console::complete);
+    }
+
+    /// The editor keeps its key bindings, so they only hold the state weakly
+    fn bind_key(&mut self, key: Key, f: fn(&mut Console) -> bool) {
+        let state = Rc::downgrade(&self.state);
+        let editor = self.editor.clone();
+        self.editor.add_key_binding(
+            key,
+            Shortcut::None,
+            Box::new(move |_| match state.upgrade() {
+                Some(state) => f(&mut Console {
+                    editor: editor.clone(),
+                    state,
+                }),
+                None => false,
+            }),
+        );
+    }
+
+    fn submit(&mut self) {
+        let cmd = self.input();
+        let mut buf = self.buffer();
+        buf.append("\n");
+        let cb = self.state.borrow_mut().on_command.take();
+        if let Some(mut cb) = cb {
+            // The callback is taken out of the state since it might call back into the console
+            let out = cb(&cmd);
+            let mut state = self.state.borrow_mut();
+            if state.on_command.is_none() {
+                state.on_command = Some(cb);
+            }
+            if !out.is_empty() {
+                buf.append(&out);
+                if !out.ends_with('\n') {
+                    buf.append("\n");
+                }
+            }
+        }
+        {
+            let mut state = self.state.borrow_mut();
+            if !cmd.trim().is_empty() {
+                state.history.push(cmd);
+            }
+            state.history_idx = None;
+        }
+        self.show_prompt();
+    }
+
+    fn history_prev(&mut self) {
+        let entry = {
+            let mut state = self.state.borrow_mut();
+            if state.history.is_empty() {
+                return;
+            }
+            let idx = match state.history_idx {
+                Some(0) => 0,
+                Some(idx) => idx - 1,
+                None => state.history.len() - 1,
+            };
+            state.history_idx = Some(idx);
+            state.history[idx].clone()
+        };
+        self.set_input(&entry);
+    }
+
+    fn history_next(&mut self) {
+        let entry = {
+            let mut state = self.state.borrow_mut();
+            match state.history_idx {
+                Some(idx) if idx + 1 < state.history.len() => {
+                    state.history_idx = Some(idx + 1);
+                    state.history[idx + 1].clone()
+                }
+                Some(_) => {
+                    state.history_idx = None;
+                    String::new()
+                }
+                None => return,
+            }
+        };
+        self.set_input(&entry);
+    }
+
+    fn complete(&mut self) -> bool {
+        let cb = self.state.borrow_mut().on_complete.take();
+        match cb {
+            Some(mut cb) => {
+                let completion = cb(&self.input());
+                let mut state = self.state.borrow_mut();
+                if state.on_complete.is_none() {
+                    state.on_complete = Some(cb);
+                }
+                drop(state);
+                if let Some(completion) = completion {
+                    self.set_input(&completion);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+struct SearchState {
+    pattern: String,
+    case_sensitive: bool,
+    whole_word: bool,
+    color: Color,
+    style_buf: TextBuffer,
+    matches: Vec<(u32, u32)>,
+    editor: TextEditor,
+    /// The buffer whose modify callback rehighlights the matches, with the callback's data
+    watched: Option<(TextBuffer, *mut raw::c_void)>,
+    /// Set while replace_all edits the buffer, which is restyled once done
+    suspended: bool,
+}
+
+impl SearchState {
+    fn unwatch(&mut self) {
+        if let Some((mut buf, data)) = self.watched.take() {
+            buf.remove_modify_callback_data(data);
+        }
+    }
+}
+
+impl Drop for SearchState {
+    fn drop(&mut self) {
+        self.unwatch();
+        // The editor holds the style buffer until it's deleted or given another style table
+        if unsafe { Fl_Text_Buffer_refcount(self.style_buf.as_ptr()) } > 1 {
+            self.editor.clear_style_table();
+        }
+    }
+}
+
+/// Finds and replaces text in a TextEditor, highlighting all the matches.
+/// The matches are highlighted using a style buffer, which replaces any style table set
+/// on the editor. The highlights are removed once the last clone is dropped
+#[derive(Clone)]
+pub struct SearchReplace {
+    editor: TextEditor,
+    state: Rc<RefCell<SearchState>>,
+}
+
+impl SearchReplace {
+    /// Creates a SearchReplace acting on the editor and its buffer
+    pub fn new(editor: &TextEditor) -> SearchReplace {
+        let state = SearchState {
+            pattern: String::new(),
+            case_sensitive: false,
+            whole_word: false,
+            color: Color::Yellow,
+            style_buf: TextBuffer::default(),
+            matches: vec![],
+            editor: editor.clone(),
+            watched: None,
+            suspended: false,
+        };
+        let mut search = SearchReplace {
+            editor: editor.clone(),
+            state: Rc::new(RefCell::new(state)),
+        };
+        search.apply_styles();
+        search.rehighlight();
+        if let Some(mut buf) = search.editor.buffer() {
+            // The callback doesn't own the state, which would then never be dropped
+            let weak = Rc::downgrade(&search.state);
+            let editor = editor.clone();
+            let data = buf.add_removable_modify_callback(Box::new(
+                move |_, inserted, deleted, _, _| {
+                    let state = match weak.upgrade() {
+                        Some(state) if inserted > 0 || deleted > 0 => state,
+                        _ => return,
+                    };
+                    if !state.borrow().suspended {
+                        let mut search = SearchReplace {
+                            editor: editor.clone(),
+                            state,
+                        };
+                        search.rehighlight();
+                    }
+                },
+            ));
+            search.state.borrow_mut().watched = Some((buf, data));
+        }
+        let weak = Rc::downgrade(&search.state);
+        crate::widget::on_delete(
+            unsafe { editor.as_widget_ptr() },
+            Box::new(move || {
+                if let Some(state) = weak.upgrade() {
+                    state.borrow_mut().unwatch();
+                }
+            }),
+        );
+        search
+    }
+
+    /// Sets the searched text, an empty pattern clears the highlights
+    pub fn set_pattern(&mut self, pattern: &str) {
+        self.state.borrow_mut().pattern = pattern.to_string();
+        self.rehighlight();
+    }
+
+    /// Returns the searched text
+    pub fn pattern(&self) -> String {
+        self.state.borrow().pattern.clone()
+    }
+
+    /// Sets whether the search is case sensitive, it isn't by default
+    pub fn set_case_sensitive(&mut self, flag: bool) {
+        self.state.borrow_mut().case_sensitive = flag;
+        self.rehighlight();
+    }
+
+    /// Sets whether only whole words match
+    pub fn set_whole_word(&mut self, flag: bool) {
+        self.state.borrow_mut().whole_word = flag;
+        self.rehighlight();
+    }
+
+    /// Sets the background color of the matches
+    pub fn set_highlight_color(&mut self, color: Color) {
+        self.state.borrow_mut().color = color;
+        self.apply_styles();
+    }
+
+    /// Returns the start and end of every match
+    pub fn matches(&self) -> Vec<(u32, u32)> {
+        self.state.borrow().matches.clone()
+    }
+
+    /// Selects the first match after the selection or the insert position,
+    /// wrapping around to the start of the text
+    pub fn find_next(&mut self) -> Option<(u32, u32)> {
+        let from = self.search_start(false);
+        let found = {
+            let state = self.state.borrow();
+            state
+                .matches
+                .iter()
+                .find(|m| m.0 >= from)
+                .or_else(|| state.matches.first())
+                .copied()
+        };
+        self.select_match(found)
+    }
+
+    /// Selects the last match before the selection or the insert position,
+    /// wrapping around to the end of the text
+    pub fn find_previous(&mut self) -> Option<(u32, u32)> {
+        let from = self.search_start(true);
+        let found = {
+            let state = self.state.borrow();
+            state
+                .matches
+                .iter()
+                .rev()
+                .find(|m| m.0 < from)
+                .or_else(|| state.matches.last())
+                .copied()
+        };
+        self.select_match(found)
+    }
+
+    /// Replaces the selected match and selects the next one.
+    /// Returns false if the selection isn't a match, then the next match is only selected
+    pub fn replace(&mut self, replacement: &str) -> bool {
+        let mut buf = match self.editor.buffer() {
+            Some(buf) => buf,
+            None => return false,
+        };
+        let selected = buf
+            .selection_range()
+            .filter(|sel| self.state.borrow().matches.contains(sel));
+        if let Some((start, end)) = selected {
+            buf.replace(start, end, replacement);
+            self.editor
+                .set_insert_position(start + replacement.len() as u32);
+        }
+        self.find_next();
+        selected.is_some()
+    }
+
+    /// Replaces all the matches as a single undoable edit, returning their count.
+    /// The matches are highlighted again once, after all the replacements
+    pub fn replace_all(&mut self, replacement: &str) -> u32 {
+        let mut buf = match self.editor.buffer() {
+            Some(buf) => buf,
+            None => return 0,
+        };
+        let matches = self.matches();
+        self.state.borrow_mut().suspended = true;
+        buf.with_undo_group(|buf| {
+            for (start, end) in matches.iter().rev() {
+                buf.replace(*start, *end, replacement);
+            }
+        });
+        self.state.borrow_mut().suspended = false;
+        self.rehighlight();
+        matches.len() as u32
+    }
+
+    /// Highlights the matches again, useful after the buffer is replaced
+    pub fn rehighlight(&mut self) {
+        let text = match self.editor.buffer() {
+            Some(buf) => buf.text(),
+            None => return,
+        };
+        let mut state = self.state.borrow_mut();
+        let state = &mut *state;
+        state.matches = find_matches(
+            &text,
+            &state.pattern,
+            state.case_sensitive,
+            state.whole_word,
+        )
+        .into_iter()
+        .map(|(start, end)| (start as u32, end as u32))
+        .collect();
+        set_range_styles(&mut state.style_buf, text.len(), &state.matches);
+        self.editor.redraw();
+    }
+
+    fn apply_styles(&mut self) {
+        let mut state = self.state.borrow_mut();
+        let state = &mut *state;
+        set_range_style_table(&mut self.editor, &mut state.style_buf, state.color);
+    }
+
+    /// Returns the position searching starts from, the selection's start when searching
+    /// backward, its end otherwise
+    fn search_start(&self, backward: bool) -> u32 {
+        let buf = match self.editor.buffer() {
+            Some(buf) => buf,
+            None => return 0,
+        };
+        match buf.selection_range() {
+            Some((start, _)) if backward => start,
+            Some((_, end)) => end,
+            None => self.editor.insert_position(),
+        }
+    }
+
+    fn select_match(&mut self, found: Option<(u32, u32)>) -> Option<(u32, u32)> {
+        let (start, end) = found?;
+        let mut buf = self.editor.buffer()?;
+        buf.select(start, end);
+        self.editor.set_insert_position(end);
+        self.editor.show_insert_position();
+        found
+    }
+}
+
+/// Finds the non-overlapping occurrences of pattern, returning their byte ranges
+fn find_matches(
+    text: &str,
+    pattern: &str,
+    case_sensitive: bool,
+    whole_word: bool,
+) -> Vec<(usize, usize)> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut matches = vec![];
+    if pattern.is_empty() {
+        return matches;
+    }
+    let mut i = 0;
+    while i < text.len() {
+        let mut rest = text[i..].char_indices();
+        let mut end = Some(i);
+        for p in pattern.chars() {
+            end = match rest.next() {
+                Some((j, c)) if c == p => Some(i + j + c.len_utf8()),
+                Some((j, c)) if !case_sensitive && c.to_lowercase().eq(p.to_lowercase()) => {
+                    Some(i + j + c.len_utf8())
+                }
+                _ => None,
+            };
+            if end.is_none() {
+                break;
+            }
+        }
+        let found = end.filter(|end| {
+            !whole_word
+                || (!text[..i].chars().last().map_or(false, is_word)
+                    && !text[*end..].chars().next().map_or(false, is_word))
+        });
+        match found {
+            Some(end) => {
+                matches.push((i, end));
+                i = end;
+            }
+            None => i += text[i..].chars().next().map_or(1, |c| c.len_utf8()),
+        }
+    }
+    matches
+}
+
+/// Returns the byte ranges of the occurrences of text, used by
+/// DisplayExt::highlight_occurrences
+pub(crate) fn find_occurrences(haystack: &str, text: &str) -> Vec<(u32, u32)> {
+    find_matches(haystack, text, true, false)
+        .into_iter()
+        .map(|(start, end)| (start as u32, end as u32))
+        .collect()
+}
+
+/// The style buffer of a display highlighting occurrences, along with the text buffer whose
+/// modify callback keeps the styles aligned with the text
+struct Occurrences {
+    style: TextBuffer,
+    watched: Option<(TextBuffer, *mut raw::c_void)>,
+}
+
+impl Occurrences {
+    fn unwatch(&mut self) {
+        if let Some((mut buf, data)) = self.watched.take() {
+            buf.remove_modify_callback_data(data);
+        }
+    }
+}
+
+thread_local! {
+    static OCCURRENCE_STYLES: RefCell<HashMap<usize, Occurrences>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Highlights the ranges of the display's text with the color, or clears them if it's None
+pub(crate) fn set_occurrence_highlights<D: DisplayExt>(
+    display: &mut D,
+    ranges: &[(u32, u32)],
+    color: Option<Color>,
+) {
+    let wid = unsafe { display.as_widget_ptr() };
+    let color = match color {
+        Some(color) => color,
+        None => {
+            OCCURRENCE_STYLES.with(|o| {
+                if let Some(occurrences) = o.borrow_mut().get_mut(&(wid as usize)) {
+                    occurrences.unwatch();
+                    // Unless another style table replaced the highlights
+                    if unsafe { Fl_Text_Buffer_refcount(occurrences.style.as_ptr()) } > 1 {
+                        display.clear_style_table();
+                    }
+                    occurrences.style.set_text("");
+                }
+            });
+            return;
+        }
+    };
+    let mut buf = match display.buffer() {
+        Some(buf) => buf,
+        None => return,
+    };
+    let mut style = OCCURRENCE_STYLES.with(|o| {
+        let mut o = o.borrow_mut();
+        let occurrences = o.entry(wid as usize).or_insert_with(|| {
+            crate::widget::on_delete(
+                wid,
+                Box::new(move || {
+                    let occurrences =
+                        OCCURRENCE_STYLES.with(|o| o.borrow_mut().remove(&(wid as usize)));
+                    if let Some(mut occurrences) = occurrences {
+                        occurrences.unwatch();
+                    }
+                }),
+            );
+            Occurrences {
+                style: TextBuffer::default(),
+                watched: None,
+            }
+        });
+        let watching = occurrences
+            .watched
+            .as_ref()
+            .map_or(false, |(watched, _)| unsafe { watched.as_ptr() == buf.as_ptr() });
+        if !watching {
+            occurrences.unwatch();
+            let mut synced = unsafe { TextBuffer::from_ptr(occurrences.style.as_ptr()) };
+            // Keeps the styles aligned with the text while it's edited
+            let data = buf.add_removable_modify_callback(Box::new(
+                move |pos, inserted, deleted, _, _| {
+                    if deleted > 0 {
+                        synced.remove(pos, pos + deleted);
+                    }
+                    if inserted > 0 {
+                        synced.insert(pos, &"A".repeat(inserted as usize));
+                    }
+                },
+            ));
+            occurrences.watched = Some((unsafe { TextBuffer::from_ptr(buf.as_ptr()) }, data));
+        }
+        unsafe { TextBuffer::from_ptr(occurrences.style.as_ptr()) }
+    });
+    set_range_styles(&mut style, buf.length() as usize, ranges);
+    set_range_style_table(display, &mut style, color);
+}
+
+/// Fills the style buffer with 'B' over the ranges and 'A' elsewhere
+fn set_range_styles(style: &mut TextBuffer, len: usize, ranges: &[(u32, u32)]) {
+    let mut styles = vec![b'A'; len];
+    for (start, end) in ranges.iter() {
+        let end = std::cmp::min(*end as usize, len);
+        if (*start as usize) < end {
+            for s in styles[*start as usize..end].iter_mut() {
+                *s = b'B';
+            }
+        }
+    }
+    let style_text: String = styles.iter().map(|&s| s as char).collect();
+    style.set_text(&style_text);
+}
+
+/// Draws 'A' styled text as is and 'B' styled text over the background color
+fn set_range_style_table<D: DisplayExt>(display: &mut D, style: &mut TextBuffer, color: Color) {
+    let plain = StyleTableEntryExt {
+        color: display.text_color(),
+        font: display.text_font(),
+        size: display.text_size(),
+        attr: TextAttr::None,
+        bgcolor: Color::White,
+    };
+    let mut found = plain;
+    found.attr = TextAttr::BgColor;
+    found.bgcolor = color;
+    display.set_style_table_entry_ext(style, &vec![plain, found]);
+    display.redraw();
 }
 
 #[cfg(test)]
 mod editor {
+    use super::*;
     #[test]
     fn buffer() {}
+    #[test]
+    fn document_state() {
+        let mut buf = TextBuffer::default();
+        let mut doc = DocumentState::new(&mut buf);
+        assert!(!doc.is_dirty());
+        buf.append("hello");
+        assert!(doc.is_dirty());
+        doc.mark_saved();
+        assert!(!doc.is_dirty());
+        let count = Rc::new(RefCell::new(0));
+        let c = count.clone();
+        doc.on_dirty_changed(Box::new(move |_| *c.borrow_mut() += 1));
+        drop(doc);
+        buf.append("!");
+        assert!(Rc::strong_count(&count) == 1);
+        assert!(*count.borrow() == 0);
+    }
+    #[test]
+    fn console_input() {
+        let mut console = Console::default("> ");
+        console.set_input("1 + 1");
+        console.print("output\n");
+        assert!(console.input() == "1 + 1");
+        console.set_input("");
+        assert!(console.input().is_empty());
+    }
+    #[test]
+    fn terminal_input() {
+        let wind = crate::window::Window::default();
+        let mut buf = TextBuffer::default();
+        let mut term = SimpleTerminal::default(&mut buf);
+        wind.end();
+        term.append("welcome");
+        term.set_input_mode("$ ", Box::new(|_| ()));
+        assert!(buf.text() == "welcome\n$ ");
+    }
+    #[test]
+    fn terminal_styled_append() {
+        let mut buf = TextBuffer::default();
+        let mut term = SimpleTerminal::default(&mut buf);
+        let style = StyleTableEntry {
+            color: Color::Red,
+            font: Font::Courrier,
+            size: 12,
+        };
+        assert!(term.append_styled("red", style).is_ok());
+        term.set_ansi(true);
+        assert!(term.append_styled("ansi", style).is_err());
+        assert!(buf.text() == "red");
+    }
+    #[test]
+    fn buffer_lifetime() {
+        let mut buf = TextBuffer::default();
+        buf.set_text("hello");
+        let editor = TextEditor::default(&mut buf);
+        drop(buf);
+        assert!(editor.buffer().unwrap().text() == "hello");
+    }
+    #[test]
+    fn style_buffer_released() {
+        let mut buf = TextBuffer::default();
+        let mut display = TextDisplay::new(0, 0, 0, 0, &mut buf);
+        let entry = StyleTableEntryExt {
+            color: Color::Black,
+            font: Font::Courrier,
+            size: 14,
+            attr: TextAttr::None,
+            bgcolor: Color::White,
+        };
+        let mut first = TextBuffer::default();
+        display.set_style_table_entry_ext(&mut first, &vec![entry]);
+        assert!(unsafe { Fl_Text_Buffer_refcount(first.as_ptr()) } == 2);
+        let mut second = TextBuffer::default();
+        display.set_style_table_entry_ext(&mut second, &vec![entry]);
+        assert!(unsafe { Fl_Text_Buffer_refcount(first.as_ptr()) } == 1);
+    }
+    #[test]
+    fn range_colors() {
+        let mut buf = TextBuffer::default();
+        let mut display = TextDisplay::new(0, 0, 0, 0, &mut buf);
+        display.set_color(Color::White);
+        assert!(display.secondary_selection_color() == Color::White);
+        display.set_highlight_color(Color::Yellow);
+        display.set_secondary_selection_color(Color::Cyan);
+        assert!(display.highlight_color() == Color::Yellow);
+        assert!(display.secondary_selection_color() == Color::Cyan);
+    }
+    #[test]
+    fn undo_group() {
+        let mut buf = TextBuffer::with_text("a b a");
+        buf.with_undo_group(|buf| {
+            buf.replace(4, 5, "c");
+            buf.replace(0, 1, "c");
+        });
+        assert!(buf.text() == "c b c");
+        buf.undo().unwrap();
+        assert!(buf.text() == "a b a");
+        let edits = Rc::new(RefCell::new(vec![]));
+        let e = edits.clone();
+        buf.add_modify_callback(Box::new(move |pos, inserted, deleted, _, _| {
+            e.borrow_mut().push((pos, inserted, deleted))
+        }));
+        buf.with_undo_group(|buf| {
+            buf.insert(5, "!");
+            buf.remove(0, 2);
+        });
+        assert!(buf.text() == "b a!");
+        assert!(*edits.borrow() == vec![(5, 1, 0), (0, 0, 2)]);
+        buf.undo().unwrap();
+        assert!(buf.text() == "a b a");
+    }
+    #[test]
+    fn line_column() {
+        let mut buf = TextBuffer::with_text("first\nse\u{e7}ond\nthird");
+        let disp = TextDisplay::new(0, 0, 0, 0, &mut buf);
+        assert!(disp.position_to_line_column(0) == (1, 1));
+        assert!(disp.position_to_line_column(11) == (2, 5));
+        assert!(disp.line_column_to_position(2, 5) == 11);
+        assert!(disp.line_column_to_position(3, 99) == buf.length());
+    }
+    #[test]
+    fn char_indices() {
+        let mut buf = TextBuffer::with_text("h\u{e9}llo");
+        assert!(buf.char_count() == 5);
+        assert!(buf.char_to_byte(2) == 3);
+        assert!(buf.byte_to_char(3) == 2);
+        assert!(buf.byte_to_char(2) == 1);
+        assert!(buf.byte_to_char(99) == 5);
+        buf.remove_chars(1, 2);
+        buf.insert_at_char(1, "e");
+        assert!(buf.text() == "hello");
+    }
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_search() {
+        let mut buf = TextBuffer::with_text("one two one");
+        assert!(buf.search_regex_forward(1, "o\\w+").unwrap() == Some((8, 11)));
+        assert!(buf.search_regex_backward(7, "one").unwrap() == Some((0, 3)));
+        assert!(buf.search_regex_backward(11, "one").unwrap() == Some((8, 11)));
+        assert!(buf.search_regex_forward(0, "(").is_err());
+        // Matches spanning the buffer's gap are found after moving it out of the way
+        buf.remove(5, 6);
+        buf.insert(5, "w");
+        assert!(buf.search_regex_forward(0, "tw\\w").unwrap() == Some((4, 7)));
+        assert!(buf.text() == "one tww one");
+    }
+    #[test]
+    fn lines_and_words() {
+        let buf = TextBuffer::with_text("fn main() {\n    let x_1 = 5;\n}\n");
+        let lines: Vec<_> = buf.lines().collect();
+        assert!(lines.len() == 3);
+        assert!(lines[1].0 == (12..28) && lines[1].1 == "    let x_1 = 5;");
+        assert!(lines[2].0 == (29..30) && lines[2].1 == "}");
+        let words: Vec<_> = buf.words().map(|(_, w)| w).collect();
+        assert!(words == vec!["fn", "main", "let", "x_1", "5"]);
+        assert!(buf.words().nth(1).unwrap().0 == (3..7));
+        assert!(TextBuffer::default().lines().count() == 1);
+        // Lines on either side of the gap are borrowed, the one it splits is copied
+        let mut buf = TextBuffer::with_text("one two\nthree\nfour");
+        buf.insert(4, "and ");
+        let lines: Vec<_> = buf.lines().map(|(_, l)| l).collect();
+        assert!(lines == vec!["one and two", "three", "four"]);
+        assert!(matches!(lines[1], Cow::Borrowed(_)));
+        let words: Vec<_> = buf.words().collect();
+        assert!(words[1].0 == (4..7) && words[1].1 == "and");
+        assert!(words[4].0 == (18..22) && words[4].1 == "four");
+    }
+    #[test]
+    fn text_slices() {
+        let mut buf = TextBuffer::with_text("first\nsecond");
+        buf.insert(0, ">");
+        assert!(buf.text_slice() == ">first\nsecond");
+        assert!(buf.line_text_slice(9) == "second");
+        assert!(buf.text_range_slice(1, 6).unwrap() == "first");
+        assert!(buf.text_range_slice(6, 99).is_none());
+        // The gap follows the insertion, so only the text after it is contiguous
+        assert!(matches!(buf.text_slice(), Cow::Owned(_)));
+        assert!(matches!(buf.line_text_slice(9), Cow::Borrowed(_)));
+        assert!(buf.text() == ">first\nsecond");
+        assert!(buf.line_text(2) == ">first");
+    }
+    #[test]
+    fn tab_spaces() {
+        let mut buf = TextBuffer::with_text("ab\ncd");
+        buf.set_tab_distance(4);
+        let mut editor = TextEditor::new(0, 0, 0, 0, &mut buf);
+        editor.set_insert_position(1);
+        editor.indent();
+        assert!(buf.text() == "a   b\ncd");
+        buf.select(0, buf.length());
+        editor.indent();
+        assert!(buf.text() == "    a   b\n    cd");
+        editor.unindent();
+        assert!(buf.text() == "a   b\ncd");
+        assert!(buf.selection_range() == Some((0, buf.length())));
+    }
+    #[test]
+    fn auto_indent() {
+        let mut buf = TextBuffer::with_text("    fn main() {");
+        buf.set_tab_distance(4);
+        let mut editor = TextEditor::new(0, 0, 0, 0, &mut buf);
+        editor.set_insert_position(buf.length());
+        editor.newline_indent("{:");
+        assert!(buf.text() == "    fn main() {\n        ");
+        assert!(editor.insert_position() == buf.length());
+        editor.insert("x");
+        editor.newline_indent("");
+        assert!(buf.text() == "    fn main() {\n        x\n        ");
+        buf.set_text("\tif x:");
+        editor.set_insert_position(buf.length());
+        editor.newline_indent("{:");
+        assert!(buf.text() == "\tif x:\n\t\t");
+    }
+    #[test]
+    fn search_replace() {
+        let mut buf = TextBuffer::with_text("Foo foo food f\u{f6}\u{f6} FOO");
+        let editor = TextEditor::new(0, 0, 0, 0, &mut buf);
+        let mut search = SearchReplace::new(&editor);
+        search.set_pattern("foo");
+        assert!(search.matches() == vec![(0, 3), (4, 7), (8, 11), (19, 22)]);
+        search.set_whole_word(true);
+        assert!(search.matches() == vec![(0, 3), (4, 7), (19, 22)]);
+        search.set_case_sensitive(true);
+        assert!(search.matches() == vec![(4, 7)]);
+        search.set_case_sensitive(false);
+        assert!(search.find_next() == Some((0, 3)));
+        assert!(search.find_next() == Some((4, 7)));
+        assert!(search.find_previous() == Some((0, 3)));
+        assert!(search.replace("bar"));
+        assert!(buf.text() == "bar foo food f\u{f6}\u{f6} FOO");
+        assert!(buf.selection_range() == Some((4, 7)));
+        assert!(search.replace_all("x") == 2);
+        assert!(buf.text() == "bar x food f\u{f6}\u{f6} x");
+        assert!(search.matches().is_empty());
+        search.set_pattern("F\u{d6}\u{d6}");
+        assert!(search.matches() == vec![(11, 16)]);
+    }
+    #[test]
+    fn search_replace_dropped() {
+        let mut buf = TextBuffer::with_text("foo");
+        let editor = TextEditor::new(0, 0, 0, 0, &mut buf);
+        let search = SearchReplace::new(&editor);
+        let style = unsafe { search.state.borrow().style_buf.as_ptr() };
+        unsafe { Fl_Text_Buffer_retain(style) };
+        assert!(unsafe { Fl_Text_Buffer_refcount(style) } == 3);
+        drop(search);
+        // Released by the search and the editor
+        assert!(unsafe { Fl_Text_Buffer_refcount(style) } == 1);
+        unsafe { Fl_Text_Buffer_delete(style) };
+        buf.append(" foo");
+        assert!(buf.text() == "foo foo");
+    }
+    #[test]
+    fn occurrence_highlights() {
+        let mut buf = TextBuffer::with_text("ab cab ab");
+        let mut display = TextDisplay::new(0, 0, 0, 0, &mut buf);
+        let styles = |display: &TextDisplay| {
+            let wid = unsafe { display.as_widget_ptr() } as usize;
+            OCCURRENCE_STYLES.with(|o| o.borrow().get(&wid).map(|o| o.style.text()))
+        };
+        display.highlight_occurrences("ab", Color::Yellow);
+        assert!(styles(&display) == Some("BBAABBABB".to_string()));
+        buf.insert(0, "xx");
+        assert!(styles(&display) == Some("AABBAABBABB".to_string()));
+        buf.remove(0, 3);
+        assert!(styles(&display) == Some("BAABBABB".to_string()));
+        display.clear_occurrence_highlights();
+        assert!(styles(&display) == Some(String::new()));
+        buf.insert(0, "x");
+        assert!(styles(&display) == Some(String::new()));
+        display.highlight_occurrences("ab", Color::Yellow);
+        assert!(styles(&display) == Some("AAAABBABB".to_string()));
+        let mut group = crate::group::Group::new(0, 0, 0, 0, "");
+        group.end();
+        group.add(&display);
+        group.clear();
+        assert!(styles(&display).is_none());
+    }
+    #[test]
+    fn selection_ranges() {
+        let mut buf = TextBuffer::with_text("hello world");
+        assert!(buf.selection_range().is_none());
+        assert!(buf.secondary_selection_range().is_none());
+        assert!(buf.highlight_range().is_none());
+        buf.select(0, 5);
+        buf.secondary_select(6, 11);
+        buf.highlight(2, 8);
+        assert!(buf.selection_range() == Some((0, 5)));
+        assert!(buf.secondary_selection_range() == Some((6, 11)));
+        assert!(buf.highlight_range() == Some((2, 8)));
+        buf.unselect();
+        buf.secondary_unselect();
+        buf.unhighlight();
+        assert!(buf.selection_range().is_none());
+        assert!(buf.secondary_selection_range().is_none());
+        assert!(buf.highlight_range().is_none());
+    }
+    #[test]
+    fn encodings() {
+        for enc in &[
+            Encoding::Utf8,
+            Encoding::Latin1,
+            Encoding::Utf16Le,
+            Encoding::Utf16Be,
+        ] {
+            let bytes = enc.encode("caf\u{e9}").unwrap();
+            assert!(Encoding::detect(&bytes) == *enc);
+            assert!(enc.decode(&bytes) == "caf\u{e9}");
+        }
+        assert!(Encoding::Latin1.encode("\u{20ac}").is_none());
+    }
+    #[test]
+    fn key_bindings_dropped() {
+        let mut win = crate::window::Window::default();
+        let mut buf = TextBuffer::default();
+        let mut editor = TextEditor::new(0, 0, 0, 0, &mut buf);
+        win.end();
+        let rc = std::rc::Rc::new(());
+        let r = rc.clone();
+        editor.add_key_binding(
+            Key::from_char('d'),
+            Shortcut::Ctrl,
+            Box::new(move |_| {
+                let _ = &r;
+                true
+            }),
+        );
+        assert!(std::rc::Rc::strong_count(&rc) == 2);
+        win.clear();
+        unsafe { fltk_sys::widget::Fl_Widget_free_pending_data() }
+        assert!(std::rc::Rc::strong_count(&rc) == 1);
+    }
+    #[test]
+    fn console_state_dropped() {
+        let mut win = crate::window::Window::default();
+        let console = Console::default("> ");
+        win.end();
+        let state = Rc::downgrade(&console.state);
+        drop(console);
+        assert!(state.upgrade().is_some());
+        win.clear();
+        unsafe { fltk_sys::widget::Fl_Widget_free_pending_data() }
+        assert!(state.upgrade().is_none());
+    }
+}
+
+#[cfg(test)]
+mod buffer_props {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[derive(Debug, Clone)]
+    enum Op {
+        Insert(u32, String),
+        Remove(u32, u32),
+        Replace(u32, u32, String),
+        Select(u32, u32),
+        Unselect,
+        Undo,
+    }
+
+    fn op() -> impl Strategy<Value = Op> {
+        let text = "[a-z \\n\u{e9}\u{4e2d}\u{1f600}]{0,8}";
+        prop_oneof![
+            (0..64u32, text).prop_map(|(pos, s)| Op::Insert(pos, s)),
+            (0..64u32, 0..64u32).prop_map(|(start, end)| Op::Remove(start, end)),
+            (0..64u32, 0..64u32, text).prop_map(|(start, end, s)| Op::Replace(start, end, s)),
+            (0..64u32, 0..64u32).prop_map(|(start, end)| Op::Select(start, end)),
+            Just(Op::Unselect),
+            Just(Op::Undo),
+        ]
+    }
+
+    /// Mirrors TextBuffer's clamping of positions to the text and its character boundaries
+    fn align(text: &str, pos: u32) -> usize {
+        let mut pos = std::cmp::min(pos as usize, text.len());
+        while !text.is_char_boundary(pos) {
+            pos -= 1;
+        }
+        pos
+    }
+
+    fn ordered(text: &str, start: u32, end: u32) -> (usize, usize) {
+        let (start, end) = (align(text, start), align(text, end));
+        (std::cmp::min(start, end), std::cmp::max(start, end))
+    }
+
+    fn check_invariants(buf: &TextBuffer, model: &str) -> Result<(), TestCaseError> {
+        prop_assert!(std::str::from_utf8(&buf.bytes()).is_ok());
+        prop_assert_eq!(buf.text(), model);
+        prop_assert_eq!(buf.length() as usize, model.len());
+        prop_assert_eq!(buf.char_count() as usize, model.chars().count());
+        if let Some((start, end)) = buf.selection_range() {
+            prop_assert!(start <= end && end <= buf.length());
+            prop_assert!(model.is_char_boundary(start as usize));
+            prop_assert!(model.is_char_boundary(end as usize));
+            prop_assert_eq!(buf.selection_text(), &model[start as usize..end as usize]);
+        } else {
+            prop_assert_eq!(buf.selection_text(), "");
+        }
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn random_edits(
+            initial in "[a-z\u{e9}\u{4e2d}\n]{0,16}",
+            ops in prop::collection::vec(op(), 0..32),
+        ) {
+            let mut buf = TextBuffer::with_text(&initial);
+            let mut model = initial.clone();
+            check_invariants(&buf, &model)?;
+            for op in ops {
+                match op {
+                    Op::Insert(pos, s) => {
+                        model.insert_str(align(&model, pos), &s);
+                        buf.insert(pos, &s);
+                    }
+                    Op::Remove(start, end) => {
+                        let (start_, end_) = ordered(&model, start, end);
+                        model.replace_range(start_..end_, "");
+                        buf.remove(start, end);
+                    }
+                    Op::Replace(start, end, s) => {
+                        let (start_, end_) = ordered(&model, start, end);
+                        model.replace_range(start_..end_, &s);
+                        buf.replace(start, end, &s);
+                    }
+                    Op::Select(start, end) => {
+                        let (start_, end_) = ordered(&model, start, end);
+                        buf.select(start, end);
+                        if start_ != end_ {
+                            let expected = Some((start_ as u32, end_ as u32));
+                            prop_assert_eq!(buf.selection_range(), expected);
+                        }
+                    }
+                    Op::Unselect => {
+                        buf.unselect();
+                        prop_assert!(buf.selection_range().is_none());
+                    }
+                    Op::Undo => {
+                        let _ = buf.undo();
+                        // Undo isn't modeled, the buffer's text only has to stay valid
+                        prop_assert!(std::str::from_utf8(&buf.bytes()).is_ok());
+                        model = buf.text();
+                    }
+                }
+                check_invariants(&buf, &model)?;
+            }
+        }
+    }
 }
@@ -274,7 +274,7 @@ impl Tree {
             TreeItem::from_raw(Fl_Tree_next_visible_item(
                 self._inner,
                 start._inner,
-                direction_key as i32,
+                direction_key.bits(),
             ))
         }
     }
@@ -298,7 +298,7 @@ impl Tree {
             TreeItem::from_raw(Fl_Tree_next_item(
                 self._inner,
                 item._inner,
-                direction_key as i32,
+                direction_key.bits(),
                 visible as i32,
             ))
         }
@@ -313,7 +313,7 @@ impl Tree {
             TreeItem::from_raw(Fl_Tree_next_selected_item(
                 self._inner,
                 item._inner,
-                direction_key as i32,
+                direction_key.bits(),
             ))
         }
     }
@@ -498,7 +498,7 @@ impl Tree {
                 self._inner,
                 from._inner,
                 to._inner,
-                direction_key as i32,
+                direction_key.bits(),
                 val as i32,
                 visible as i32,
             ) {
@@ -0,0 +1,25 @@
+use crate::prelude::GroupExt;
+
+/// Wraps a widget declared by ui!, so that end() is only called on groups.
+/// Groups begin when created, so every group node is ended even without children
+pub struct UiNode<'a, W>(pub &'a mut W);
+
+/// Ends group nodes, picked over EndWidget since it doesn't need an autoref
+pub trait EndGroup {
+    fn end_node(&mut self);
+}
+
+impl<'a, W: GroupExt> EndGroup for UiNode<'a, W> {
+    fn end_node(&mut self) {
+        self.0.end()
+    }
+}
+
+/// Leaves other widgets as they are
+pub trait EndWidget {
+    fn end_node(&mut self);
+}
+
+impl<'a, 'b, W> EndWidget for &'b mut UiNode<'a, W> {
+    fn end_node(&mut self) {}
+}
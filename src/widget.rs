@@ -4,6 +4,7 @@ use fltk_sys::widget::*;
 use std::mem;
 use std::ffi::{CString, CStr};
 use std::os::raw;
+use std::{cell::RefCell, collections::HashMap};
 
 /// An abstract type, shouldn't be instantiated in user code
 #[derive(WidgetExt, Debug)]
@@ -19,6 +20,36 @@ pub struct Widget {
 //     }
 // }
 
+/// Defines a filter applied to the pixels of a widget once it's drawn
+pub enum Filter {
+    /// Blends the widget with FLTK's default gray, as FLTK draws inactive widgets
+    Dimmed,
+    /// Draws the widget in shades of gray
+    Grayscale,
+    /// Maps every rgb pixel of the widget through the closure
+    Custom(Box<dyn FnMut((u8, u8, u8)) -> (u8, u8, u8)>),
+    /// Removes a previously set filter
+    NoFilter,
+}
+
+impl Filter {
+    /// Returns the pixel mapping of the filter
+    pub(crate) fn into_fn(self) -> Option<Box<dyn FnMut((u8, u8, u8)) -> (u8, u8, u8)>> {
+        match self {
+            Filter::Dimmed => Some(Box::new(|(r, g, b)| {
+                let dim = |c: u8| (c as f32 * 0.33 + 192.0 * 0.67) as u8;
+                (dim(r), dim(g), dim(b))
+            })),
+            Filter::Grayscale => Some(Box::new(|(r, g, b)| {
+                let l = (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32) as u8;
+                (l, l, l)
+            })),
+            Filter::Custom(f) => Some(f),
+            Filter::NoFilter => None,
+        }
+    }
+}
+
 impl Widget {
     /// Initialize a Widget base from a pointer
     pub unsafe fn from_raw(ptr: *mut Fl_Widget) -> Self {
@@ -36,3 +67,199 @@ impl Widget {
         unsafe { W::from_widget_ptr(self._inner) }
     }
 }
+
+/// Calls the closure set by WidgetExt::set_callback, passed as the widget's user data
+pub(crate) unsafe extern "C" fn callback_shim(_wid: *mut Fl_Widget, data: *mut raw::c_void) {
+    let a: *mut Box<dyn FnMut()> = mem::transmute(data);
+    let f: &mut (dyn FnMut()) = &mut **a;
+    f();
+}
+
+/// Drops the data the crate passed to a deleted widget, FLTK calls it once the event
+/// being handled is done since the data can be the running callback
+unsafe extern "C" fn data_deleter(kind: raw::c_int, data: *mut raw::c_void) {
+    match kind {
+        // The callback and the drawer
+        0 | 2 => {
+            let _ = Box::from_raw(data as *mut Box<dyn FnMut()>);
+        }
+        1 => {
+            let _ = Box::from_raw(data as *mut Box<dyn FnMut(Event) -> bool>);
+        }
+        3 => {
+            let _ = Box::from_raw(data as *mut Box<dyn FnMut((u8, u8, u8)) -> (u8, u8, u8)>);
+        }
+        4 => {
+            let _ = Box::from_raw(data as *mut Box<dyn FnMut(i32, i32, i32, i32)>);
+        }
+        // The state callback of a window
+        5 => {
+            let _ = Box::from_raw(data as *mut Box<dyn FnMut(crate::window::WindowState)>);
+        }
+        // A modify callback removed from a text buffer
+        6 => {
+            let _ = Box::from_raw(data as *mut Box<dyn FnMut(u32, u32, u32, u32, &str)>);
+        }
+        // The key bindings of a deleted text editor
+        7 => {
+            let _ = Box::from_raw(data as *mut Box<dyn FnMut(Key) -> bool>);
+        }
+        _ => (),
+    }
+}
+
+/// Makes the destructors of widgets drop the closures set on them,
+/// called before the crate passes its first closure to a widget
+pub(crate) fn register_data_deleter() {
+    static REGISTER: std::sync::Once = std::sync::Once::new();
+    REGISTER.call_once(|| unsafe { Fl_Widget_set_data_deleter(Some(data_deleter)) });
+}
+
+thread_local! {
+    static DELETE_HOOKS: RefCell<HashMap<usize, Vec<Box<dyn FnOnce()>>>> =
+        RefCell::new(HashMap::new());
+}
+
+unsafe extern "C" fn delete_observer(wid: *mut Fl_Widget) {
+    let hooks = DELETE_HOOKS
+        .try_with(|h| h.borrow_mut().remove(&(wid as usize)))
+        .ok()
+        .flatten();
+    for hook in hooks.into_iter().flatten() {
+        hook();
+    }
+}
+
+/// Runs f when the widget is deleted, used to drop what the crate keeps about a widget
+/// since a new widget can reuse its address
+pub(crate) fn on_delete(wid: *mut Fl_Widget, f: Box<dyn FnOnce()>) {
+    static REGISTER: std::sync::Once = std::sync::Once::new();
+    REGISTER.call_once(|| unsafe {
+        Fl_Widget_add_delete_observer(Some(delete_observer));
+    });
+    DELETE_HOOKS.with(|h| {
+        h.borrow_mut()
+            .entry(wid as usize)
+            .or_insert_with(Vec::new)
+            .push(f)
+    });
+}
+
+thread_local! {
+    static RESIZE_HOOKS: RefCell<HashMap<usize, Vec<Box<dyn FnMut()>>>> =
+        RefCell::new(HashMap::new());
+}
+
+unsafe extern "C" fn resize_observer(wid: *mut Fl_Widget) {
+    // The hooks are taken out since they resize widgets themselves
+    let hooks = RESIZE_HOOKS
+        .try_with(|h| h.borrow_mut().remove(&(wid as usize)))
+        .ok()
+        .flatten();
+    if let Some(mut hooks) = hooks {
+        for hook in hooks.iter_mut() {
+            hook();
+        }
+        RESIZE_HOOKS.with(|h| {
+            let mut h = h.borrow_mut();
+            let added = h.remove(&(wid as usize)).unwrap_or_default();
+            hooks.extend(added);
+            h.insert(wid as usize, hooks);
+        });
+    }
+}
+
+/// Runs f each time the widget is resized, after its children were resized,
+/// used to lay out the children of containers. The hooks are dropped with the widget
+pub(crate) fn on_resize(wid: *mut Fl_Widget, f: Box<dyn FnMut()>) {
+    static REGISTER: std::sync::Once = std::sync::Once::new();
+    REGISTER.call_once(|| unsafe {
+        Fl_Widget_add_resize_observer(Some(resize_observer));
+    });
+    let first = RESIZE_HOOKS.with(|h| {
+        let mut h = h.borrow_mut();
+        let hooks = h.entry(wid as usize).or_insert_with(Vec::new);
+        hooks.push(f);
+        hooks.len() == 1
+    });
+    if first {
+        on_delete(
+            wid,
+            Box::new(move || {
+                let _ = RESIZE_HOOKS.try_with(|h| h.borrow_mut().remove(&(wid as usize)));
+            }),
+        );
+    }
+}
+
+type Interceptor = (&'static str, Box<dyn FnMut(Event) -> bool>);
+
+thread_local! {
+    static EVENT_INTERCEPTORS: RefCell<HashMap<usize, Vec<Interceptor>>> =
+        RefCell::new(HashMap::new());
+}
+
+unsafe extern "C" fn event_interceptor(wid: *mut Fl_Widget, ev: raw::c_int) -> raw::c_int {
+    // The interceptors are taken out since they can run an event loop, like popup menus do,
+    // an empty list staying in their place until the widget is deleted
+    let interceptors = EVENT_INTERCEPTORS
+        .try_with(|i| i.borrow_mut().get_mut(&(wid as usize)).map(mem::take))
+        .ok()
+        .flatten();
+    let mut interceptors = match interceptors {
+        Some(interceptors) => interceptors,
+        None => return 0,
+    };
+    let ev: Event = mem::transmute(ev);
+    let taken = interceptors.iter_mut().any(|(_, f)| f(ev));
+    let _ = EVENT_INTERCEPTORS.try_with(|i| {
+        // Interceptors set while running replace the ones of the same name
+        if let Some(added) = i.borrow_mut().get_mut(&(wid as usize)) {
+            interceptors.retain(|(name, _)| !added.iter().any(|(n, _)| n == name));
+            interceptors.append(added);
+            *added = interceptors;
+        }
+    });
+    taken as raw::c_int
+}
+
+/// Runs f with each event of the widget before the widget handles it, f returning true
+/// when it takes the event. The interceptors of a widget run in the order they were set,
+/// one replacing the interceptor of the same name. They're dropped with the widget
+pub(crate) fn intercept_events(
+    wid: *mut Fl_Widget,
+    name: &'static str,
+    f: Box<dyn FnMut(Event) -> bool>,
+) {
+    static REGISTER: std::sync::Once = std::sync::Once::new();
+    REGISTER.call_once(|| unsafe {
+        Fl_Widget_add_event_interceptor(Some(event_interceptor));
+    });
+    let first = EVENT_INTERCEPTORS.with(|i| {
+        let mut i = i.borrow_mut();
+        let first = !i.contains_key(&(wid as usize));
+        let interceptors = i.entry(wid as usize).or_insert_with(Vec::new);
+        match interceptors.iter_mut().find(|(n, _)| *n == name) {
+            Some(slot) => slot.1 = f,
+            None => interceptors.push((name, f)),
+        }
+        first
+    });
+    if first {
+        on_delete(
+            wid,
+            Box::new(move || {
+                let _ = EVENT_INTERCEPTORS.try_with(|i| i.borrow_mut().remove(&(wid as usize)));
+            }),
+        );
+    }
+}
+
+/// Removes the interceptor of the given name set on the widget by intercept_events
+pub(crate) fn remove_interceptor(wid: *mut Fl_Widget, name: &'static str) {
+    let _ = EVENT_INTERCEPTORS.try_with(|i| {
+        if let Some(interceptors) = i.borrow_mut().get_mut(&(wid as usize)) {
+            interceptors.retain(|(n, _)| *n != name);
+        }
+    });
+}
@@ -23,6 +23,18 @@ pub enum WindowType {
     DoubleWindow = 241,
 }
 
+/// Defines the state changes reported by WindowExt::set_state_callback
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum WindowState {
+    Minimized = 0,
+    Restored = 1,
+    Maximized = 2,
+    Unmaximized = 3,
+    FocusGained = 4,
+    FocusLost = 5,
+}
+
 /// Creates a double window widget
 #[derive(WidgetExt, GroupExt, WindowExt, Debug)]
 pub struct DoubleWindow {